@@ -0,0 +1,237 @@
+use std::fmt;
+
+use crate::field::*;
+
+mod ops;
+
+/// The complex number `re + im * i`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T
+}
+
+impl<T> Complex<T>
+{
+    #[inline]
+    pub const fn new(re: T, im: T) -> Self 
+    {
+        Complex {
+            re,
+            im
+        }
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: AdditiveIdentity<Output=T> + MultiplicativeIdentity<Output=T>
+{
+    /// The imaginary unit.
+    pub fn i() -> Self
+    {
+        Complex::new(T::additive_identity(), T::multiplicative_identity())
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Copy + std::ops::Add<T, Output=T> + std::ops::Mul<T, Output=T>
+{
+    /// `|z|^2`, which avoids the square root of the modulus.
+    pub fn norm_sqr(&self) -> T
+    {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl<T> From<T> for Complex<T>
+where
+    T: AdditiveIdentity<Output=T>
+{
+    fn from(re: T) -> Self
+    {
+        Complex::new(re, T::additive_identity())
+    }
+}
+
+impl<T> fmt::Display for Complex<T>
+where
+    T: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}{:+}i", self.re, self.im)
+    }
+}
+
+impl<T> Conjugate for Complex<T>
+where
+    T: Copy + AdditiveInverse<Output=T>
+{
+    type Output = Complex<T>;
+
+    fn conj(&self) -> Self::Output
+    {
+        Complex::new(self.re, self.im.additive_inv())
+    }
+}
+
+/// Compares the real and imaginary parts separately.
+impl<T> ApproxEq for Complex<T>
+where
+    T: ApproxEq
+{
+    type Epsilon = T::Epsilon;
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: &Self::Epsilon) -> bool
+    {
+        self.re.abs_diff_eq(&other.re, epsilon) && self.im.abs_diff_eq(&other.im, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: &Self::Epsilon, max_relative: &Self::Epsilon) -> bool
+    {
+        self.re.relative_eq(&other.re, epsilon, max_relative) 
+            && self.im.relative_eq(&other.im, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: &Self::Epsilon, max_ulps: u32) -> bool
+    {
+        self.re.ulps_eq(&other.re, epsilon, max_ulps) && self.im.ulps_eq(&other.im, epsilon, max_ulps)
+    }
+}
+
+/// The squared modulus, which orders elements like the modulus does.
+impl<T> Magnitude for Complex<T>
+where
+    T: Copy + PartialOrd + std::ops::Add<T, Output=T> + std::ops::Mul<T, Output=T>
+{
+    type Output = T;
+
+    fn magnitude(&self) -> Self::Output
+    {
+        self.norm_sqr()
+    }
+}
+
+impl<T> AdditiveIdentity for Complex<T>
+where
+    T: AdditiveIdentity<Output=T>
+{
+    type Output = Complex<T>;
+
+    fn additive_identity() -> Self::Output
+    {
+        Complex::new(T::additive_identity(), T::additive_identity())
+    }
+}
+
+impl<T> AdditiveIdentity for &Complex<T>
+where
+    T: AdditiveIdentity<Output=T>
+{
+    type Output = Complex<T>;
+
+    fn additive_identity() -> Self::Output
+    {
+        Complex::<T>::additive_identity()
+    }
+}
+
+impl<T> AdditiveIdentity for &mut Complex<T>
+where
+    T: AdditiveIdentity<Output=T>
+{
+    type Output = Complex<T>;
+
+    fn additive_identity() -> Self::Output
+    {
+        Complex::<T>::additive_identity()
+    }
+}
+
+impl<T> MultiplicativeIdentity for Complex<T>
+where
+    T: AdditiveIdentity<Output=T> + MultiplicativeIdentity<Output=T>
+{
+    type Output = Complex<T>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Complex::new(T::multiplicative_identity(), T::additive_identity())
+    }
+}
+
+impl<T> MultiplicativeIdentity for &Complex<T>
+where
+    T: AdditiveIdentity<Output=T> + MultiplicativeIdentity<Output=T>
+{
+    type Output = Complex<T>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Complex::<T>::multiplicative_identity()
+    }
+}
+
+impl<T> MultiplicativeIdentity for &mut Complex<T>
+where
+    T: AdditiveIdentity<Output=T> + MultiplicativeIdentity<Output=T>
+{
+    type Output = Complex<T>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Complex::<T>::multiplicative_identity()
+    }
+}
+
+impl<T> AdditiveInverse for Complex<T>
+where
+    T: AdditiveInverse<Output=T>
+{
+    type Output = Complex<T>;
+
+    fn additive_inv(&self) -> Self::Output
+    {
+        Complex::new(self.re.additive_inv(), self.im.additive_inv())
+    }
+}
+
+/// `1 / z` by the scaled division of `Div`.
+impl<T> MultiplicativeInverse for Complex<T>
+where
+    T: Field + Magnitude + Copy
+{
+    type Output = Complex<T>;
+
+    fn multiplicative_inv(&self) -> Self::Output
+    {
+        Complex::new(T::multiplicative_identity(), T::additive_identity()) / self
+    }
+}
+
+impl<T> Group for Complex<T>
+where
+    T: Group + Copy
+{ }
+
+impl<T> AbelianGroup for Complex<T>
+where
+    T: AbelianGroup + Copy
+{ }
+
+impl<T> Ring for Complex<T>
+where
+    T: CommutativeRing + Copy
+{ }
+
+impl<T> CommutativeRing for Complex<T>
+where
+    T: CommutativeRing + Copy
+{ }
+
+impl<T> Field for Complex<T>
+where
+    T: Field + Magnitude + Copy
+{ }
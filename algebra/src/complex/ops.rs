@@ -0,0 +1,147 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+use crate::field::Magnitude;
+
+use super::Complex;
+
+/// Implements `$op` and `$op_assign` for every combination of owned and borrowed 
+/// operands, computing the owned result from `$lhs` and `$rhs`.
+macro_rules! binop {
+    (
+        $Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, 
+        |$lhs:ident, $rhs:ident| $body:expr, 
+        $($bound:tt)+
+    ) => {
+        impl<T> $Op<Complex<T>> for Complex<T>
+        where
+            T: Copy + $($bound)+
+        {
+            type Output = Complex<T>;
+
+            fn $op(self, rhs: Complex<T>) -> Self::Output
+            {
+                let ($lhs, $rhs) = (self, rhs);
+                $body
+            }
+        }
+
+        impl<'a, T> $Op<&'a Complex<T>> for Complex<T>
+        where
+            T: Copy + $($bound)+
+        {
+            type Output = Complex<T>;
+
+            fn $op(self, rhs: &'a Complex<T>) -> Self::Output
+            {
+                $Op::$op(self, *rhs)
+            }
+        }
+
+        impl<'a, T> $Op<Complex<T>> for &'a Complex<T>
+        where
+            T: Copy + $($bound)+
+        {
+            type Output = Complex<T>;
+
+            fn $op(self, rhs: Complex<T>) -> Self::Output
+            {
+                $Op::$op(*self, rhs)
+            }
+        }
+
+        impl<'a, 'b, T> $Op<&'b Complex<T>> for &'a Complex<T>
+        where
+            T: Copy + $($bound)+
+        {
+            type Output = Complex<T>;
+
+            fn $op(self, rhs: &'b Complex<T>) -> Self::Output
+            {
+                $Op::$op(*self, *rhs)
+            }
+        }
+
+        impl<T> $OpAssign<Complex<T>> for Complex<T>
+        where
+            T: Copy + $($bound)+
+        {
+            fn $op_assign(&mut self, rhs: Complex<T>)
+            {
+                *self = $Op::$op(*self, rhs);
+            }
+        }
+
+        impl<'a, T> $OpAssign<&'a Complex<T>> for Complex<T>
+        where
+            T: Copy + $($bound)+
+        {
+            fn $op_assign(&mut self, rhs: &'a Complex<T>)
+            {
+                *self = $Op::$op(*self, *rhs);
+            }
+        }
+    };
+}
+
+binop!(
+    Add, add, AddAssign, add_assign,
+    |lhs, rhs| Complex::new(lhs.re + rhs.re, lhs.im + rhs.im),
+    Add<T, Output=T>
+);
+
+binop!(
+    Sub, sub, SubAssign, sub_assign,
+    |lhs, rhs| Complex::new(lhs.re - rhs.re, lhs.im - rhs.im),
+    Sub<T, Output=T>
+);
+
+binop!(
+    Mul, mul, MulAssign, mul_assign,
+    |lhs, rhs| Complex::new(
+        lhs.re * rhs.re - lhs.im * rhs.im, 
+        lhs.re * rhs.im + lhs.im * rhs.re
+    ),
+    Add<T, Output=T> + Sub<T, Output=T> + Mul<T, Output=T>
+);
+
+binop!(
+    Div, div, DivAssign, div_assign,
+    |lhs, rhs| {
+        // Smith's algorithm, which divides through by the larger part of `rhs` so that 
+        // `|rhs|^2` is never formed and cannot overflow or underflow.
+        if rhs.re.magnitude() > rhs.im.magnitude() {
+            let r: T = rhs.im / rhs.re;
+            let d: T = rhs.re + r * rhs.im;
+            Complex::new((lhs.re + r * lhs.im) / d, (lhs.im - r * lhs.re) / d)
+        } else {
+            let r: T = rhs.re / rhs.im;
+            let d: T = rhs.im + r * rhs.re;
+            Complex::new((r * lhs.re + lhs.im) / d, (r * lhs.im - lhs.re) / d)
+        }
+    },
+    Add<T, Output=T> + Sub<T, Output=T> + Mul<T, Output=T> + Div<T, Output=T> + Magnitude
+);
+
+impl<T> Neg for Complex<T>
+where
+    T: Neg<Output=T>
+{
+    type Output = Complex<T>;
+
+    fn neg(self) -> Self::Output
+    {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<T> Neg for &Complex<T>
+where
+    T: Copy + Neg<Output=T>
+{
+    type Output = Complex<T>;
+
+    fn neg(self) -> Self::Output
+    {
+        Complex::new(-self.re, -self.im)
+    }
+}
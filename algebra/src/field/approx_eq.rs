@@ -0,0 +1,62 @@
+/// Approximate equality for scalars that carry rounding error.
+/// 
+/// Each comparison also accepts values within the absolute tolerance `epsilon`, 
+/// which the relative and ULP tests cannot handle near zero.
+pub trait ApproxEq
+{
+    type Epsilon;
+
+    /// `|self - other| <= epsilon`.
+    fn abs_diff_eq(&self, other: &Self, epsilon: &Self::Epsilon) -> bool;
+
+    /// `|self - other|` is at most `epsilon` or `max_relative` times the larger magnitude.
+    fn relative_eq(&self, other: &Self, epsilon: &Self::Epsilon, max_relative: &Self::Epsilon) -> bool;
+
+    /// `|self - other|` is at most `epsilon`, or at most `max_ulps` representable values 
+    /// separate `self` and `other`.
+    fn ulps_eq(&self, other: &Self, epsilon: &Self::Epsilon, max_ulps: u32) -> bool;
+}
+
+macro_rules! primitives {
+    (
+        @float $($primitive:ty => $bits:ty),*
+    ) => {
+        $(
+            impl ApproxEq for $primitive
+            {
+                type Epsilon = $primitive;
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: &Self::Epsilon) -> bool
+                {
+                    self == other || (self - other).abs() <= *epsilon
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: &Self::Epsilon, max_relative: &Self::Epsilon) -> bool
+                {
+                    if self.abs_diff_eq(other, epsilon) {
+                        return true
+                    }
+                    if !self.is_finite() || !other.is_finite() {
+                        return false
+                    }
+                    let largest: $primitive = self.abs().max(other.abs());
+                    (self - other).abs() <= largest * max_relative
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: &Self::Epsilon, max_ulps: u32) -> bool
+                {
+                    if self.abs_diff_eq(other, epsilon) {
+                        return true
+                    }
+                    if self.is_nan() || other.is_nan() || self.is_sign_positive() != other.is_sign_positive() {
+                        return false
+                    }
+                    let distance: $bits = (self.to_bits() as $bits - other.to_bits() as $bits).abs();
+                    distance <= max_ulps as $bits
+                }
+            }
+        )*
+    };
+}
+
+primitives!{@float f32 => i64, f64 => i128}
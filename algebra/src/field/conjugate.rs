@@ -0,0 +1,31 @@
+/// The complex conjugate, which is the identity on real scalars.
+pub trait Conjugate
+{
+    type Output;
+
+    fn conj(&self) -> Self::Output;
+}
+
+macro_rules! primitives {
+    (
+        $($primitive:ty),*
+    ) => {
+        $(
+            impl Conjugate for $primitive
+            {
+                type Output = $primitive;
+
+                fn conj(&self) -> Self::Output
+                {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+primitives!{
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64
+}
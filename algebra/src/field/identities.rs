@@ -5,7 +5,13 @@ pub trait AdditiveIdentity
     fn additive_identity() -> Self::Output;
 }
 
-#[macro_use]
+pub trait MultiplicativeIdentity
+{
+    type Output;
+
+    fn multiplicative_identity() -> Self::Output;
+}
+
 macro_rules! primitives {
     (
         $($primitive:ty),*
@@ -40,6 +46,36 @@ macro_rules! primitives {
                     0
                 }
             } 
+
+            impl MultiplicativeIdentity for $primitive
+            {
+                type Output = $primitive;
+
+                fn multiplicative_identity() -> Self::Output
+                {
+                    1
+                }
+            }   
+
+            impl<'a> MultiplicativeIdentity for &'a $primitive
+            {
+                type Output = $primitive;
+
+                fn multiplicative_identity() -> Self::Output
+                {
+                    1
+                }
+            } 
+
+            impl<'a> MultiplicativeIdentity for &'a mut $primitive
+            {
+                type Output = $primitive;
+
+                fn multiplicative_identity() -> Self::Output
+                {
+                    1
+                }
+            } 
         )*
     };
 
@@ -76,6 +112,36 @@ macro_rules! primitives {
                     0.0
                 }
             } 
+
+            impl MultiplicativeIdentity for $primitive
+            {
+                type Output = $primitive;
+
+                fn multiplicative_identity() -> Self::Output
+                {
+                    1.0
+                }
+            }   
+
+            impl<'a> MultiplicativeIdentity for &'a $primitive
+            {
+                type Output = $primitive;
+
+                fn multiplicative_identity() -> Self::Output
+                {
+                    1.0
+                }
+            } 
+
+            impl<'a> MultiplicativeIdentity for &'a mut $primitive
+            {
+                type Output = $primitive;
+
+                fn multiplicative_identity() -> Self::Output
+                {
+                    1.0
+                }
+            } 
         )*
     };
 }
@@ -0,0 +1,68 @@
+pub trait AdditiveInverse
+{
+    type Output;
+
+    fn additive_inv(&self) -> Self::Output;
+}
+
+pub trait MultiplicativeInverse
+{
+    type Output;
+
+    fn multiplicative_inv(&self) -> Self::Output;
+}
+
+macro_rules! primitives {
+    (
+        $($primitive:ty),*
+    ) => {
+        $(
+            /// The wrapping negation, `2^BITS - x` for an unsigned `x`. This is an inverse 
+            /// only modulo `2^BITS`: `x + x.additive_inv()` overflows, and so panics in 
+            /// debug builds, for every nonzero unsigned `x`.
+            impl AdditiveInverse for $primitive
+            {
+                type Output = $primitive;
+
+                fn additive_inv(&self) -> Self::Output
+                {
+                    self.wrapping_neg()
+                }
+            }
+        )*
+    };
+
+    (
+        @float $($primitive:ty),*
+    ) => {
+        $(
+            impl AdditiveInverse for $primitive
+            {
+                type Output = $primitive;
+
+                fn additive_inv(&self) -> Self::Output
+                {
+                    -self
+                }
+            }
+
+            /// The reciprocal, which is infinite for zero as IEEE 754 requires.
+            impl MultiplicativeInverse for $primitive
+            {
+                type Output = $primitive;
+
+                fn multiplicative_inv(&self) -> Self::Output
+                {
+                    self.recip()
+                }
+            }
+        )*
+    };
+}
+
+primitives!{
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+}
+
+primitives!{@float f32, f64}
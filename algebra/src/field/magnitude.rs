@@ -0,0 +1,32 @@
+/// A measure of size used to choose pivots.
+/// 
+/// The magnitude is the additive identity exactly for zero, and a larger magnitude 
+/// marks an element that is safer to divide by. For the reals this is the absolute 
+/// value; for exact fields where every nonzero element is as good as another it can 
+/// be as coarse as whether the element is zero.
+pub trait Magnitude
+{
+    type Output: PartialOrd;
+
+    fn magnitude(&self) -> Self::Output;
+}
+
+macro_rules! primitives {
+    (
+        @float $($primitive:ty),*
+    ) => {
+        $(
+            impl Magnitude for $primitive
+            {
+                type Output = $primitive;
+
+                fn magnitude(&self) -> Self::Output
+                {
+                    self.abs()
+                }
+            }
+        )*
+    };
+}
+
+primitives!{@float f32, f64}
@@ -1,2 +1,17 @@
 mod identities;
 pub use identities::*;
+
+mod inverses;
+pub use inverses::*;
+
+mod structures;
+pub use structures::*;
+
+mod conjugate;
+pub use conjugate::*;
+
+mod approx_eq;
+pub use approx_eq::*;
+
+mod magnitude;
+pub use magnitude::*;
@@ -0,0 +1,87 @@
+//! Marker traits composing the scalar operations into algebraic structures.
+//! 
+//! The operators can only express the shape of each structure; the axioms 
+//! (associativity, commutativity, distributivity) are promised by the implementor.
+//! The group operation is written additively throughout.
+//! 
+//! The primitive integers are implemented as though their arithmetic were exact, so 
+//! the axioms only hold while no operation overflows. Overflow is outside the structure: 
+//! `Add`, `Sub` and `Mul` panic on it in debug builds and wrap in release builds. In 
+//! particular an unsigned integer only has an additive inverse in the wrapping sense, 
+//! so for the unsigned types `Group` and `Ring` promise no more than arithmetic modulo 
+//! `2^BITS` with overflow checks turned off.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use super::{AdditiveIdentity, AdditiveInverse, MultiplicativeIdentity, MultiplicativeInverse};
+
+pub trait Group: Sized 
+    + Clone
+    + Add<Self, Output=Self>
+    + AddAssign<Self>
+    + Sub<Self, Output=Self>
+    + SubAssign<Self>
+    + AdditiveIdentity<Output=Self>
+    + AdditiveInverse<Output=Self>
+{
+    // Supertrait.
+}
+
+pub trait AbelianGroup: Group
+{
+    // Supertrait.
+}
+
+pub trait Ring: AbelianGroup
+    + Mul<Self, Output=Self>
+    + MulAssign<Self>
+    + MultiplicativeIdentity<Output=Self>
+{
+    // Supertrait.
+}
+
+pub trait CommutativeRing: Ring
+{
+    // Supertrait.
+}
+
+pub trait Field: CommutativeRing
+    + Div<Self, Output=Self>
+    + DivAssign<Self>
+    + MultiplicativeInverse<Output=Self>
+{
+    // Supertrait.
+}
+
+macro_rules! primitives {
+    (
+        $($primitive:ty),*
+    ) => {
+        $(
+            impl Group for $primitive { }
+
+            impl AbelianGroup for $primitive { }
+
+            impl Ring for $primitive { }
+
+            impl CommutativeRing for $primitive { }
+        )*
+    };
+
+    (
+        @float $($primitive:ty),*
+    ) => {
+        $(
+            primitives!{ $primitive }
+
+            impl Field for $primitive { }
+        )*
+    };
+}
+
+primitives!{
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+}
+
+primitives!{@float f32, f64}
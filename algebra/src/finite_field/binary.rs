@@ -0,0 +1,312 @@
+use std::{fmt, ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg, BitXor}};
+
+use crate::field::*;
+
+/// An element of the binary extension field `GF(2^N)`, as a polynomial over `GF(2)` 
+/// whose coefficients are the bits of its value, reduced modulo `POLY`.
+/// 
+/// `POLY` is the reducing polynomial including its `x^N` term, e.g. `0x11D` for 
+/// `x^8 + x^4 + x^3 + x^2 + 1`. It must be irreducible for the inverses to exist; 
+/// only its degree is checked. `N` is at most 32, so products fit in a `u64` before 
+/// reduction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GF2n<const N: u32, const POLY: u64>(u64);
+
+/// `GF(2)`, whose addition is XOR and multiplication is AND.
+pub type GF2 = GF2n<1, 0b11>;
+
+/// `GF(2^8)` with the Reed–Solomon polynomial `x^8 + x^4 + x^3 + x^2 + 1`.
+pub type GF256 = GF2n<8, 0x11D>;
+
+/// `GF(2^16)` with the polynomial `x^16 + x^12 + x^3 + x + 1`.
+pub type GF65536 = GF2n<16, 0x1100B>;
+
+impl<const N: u32, const POLY: u64> GF2n<N, POLY>
+{
+    const VALID: () = assert!(
+        N >= 1 && N <= 32 && POLY >> N == 1, 
+        "`GF2n` needs 1 <= N <= 32 and a reducing polynomial of degree N"
+    );
+
+    /// The number of elements in the field.
+    pub const ORDER: u64 = 1 << N;
+
+    /// The residue of the polynomial `value` modulo `POLY`.
+    #[inline]
+    pub fn new(value: u64) -> Self 
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        GF2n(Self::reduce(value))
+    }
+
+    #[inline]
+    pub fn value(&self) -> u64
+    {
+        self.0
+    }
+
+    /// Clears every bit from the top down to `N` by XOR-ing in shifted copies of `POLY`.
+    fn reduce(mut value: u64) -> u64
+    {
+        for bit in (N..u64::BITS).rev() {
+            if value >> bit & 1 == 1 {
+                value ^= POLY << (bit - N);
+            }
+        }
+        value
+    }
+
+    /// The carry-less product of two polynomials of degree below 32.
+    fn clmul(lhs: u64, rhs: u64) -> u64
+    {
+        (0..N)
+            .filter(|bit| rhs >> bit & 1 == 1)
+            .fold(0, |acc, bit| acc ^ (lhs << bit))
+    }
+
+    /// `self^exponent` by square-and-multiply.
+    pub fn pow(&self, mut exponent: u64) -> Self
+    {
+        let mut base: Self = *self;
+        let mut acc: Self = Self::new(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                acc *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        acc
+    }
+
+    /// `self^(2^N - 2)`, the inverse in the multiplicative group, or `None` for zero.
+    pub fn checked_inv(&self) -> Option<Self>
+    {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.pow(Self::ORDER - 2))
+        }
+    }
+}
+
+impl From<bool> for GF2
+{
+    fn from(bit: bool) -> Self
+    {
+        GF2n(bit as u64)
+    }
+}
+
+impl<const N: u32, const POLY: u64> fmt::Display for GF2n<N, POLY>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+/// Implements `$op` and `$op_assign` for every combination of owned and borrowed 
+/// operands, computing the owned result from `$lhs` and `$rhs`.
+macro_rules! binop {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, |$lhs:ident, $rhs:ident| $body:expr) => {
+        impl<const N: u32, const POLY: u64> $Op<GF2n<N, POLY>> for GF2n<N, POLY>
+        {
+            type Output = GF2n<N, POLY>;
+
+            fn $op(self, rhs: GF2n<N, POLY>) -> Self::Output
+            {
+                let ($lhs, $rhs) = (self, rhs);
+                $body
+            }
+        }
+
+        impl<'a, const N: u32, const POLY: u64> $Op<&'a GF2n<N, POLY>> for GF2n<N, POLY>
+        {
+            type Output = GF2n<N, POLY>;
+
+            fn $op(self, rhs: &'a GF2n<N, POLY>) -> Self::Output
+            {
+                $Op::$op(self, *rhs)
+            }
+        }
+
+        impl<'a, const N: u32, const POLY: u64> $Op<GF2n<N, POLY>> for &'a GF2n<N, POLY>
+        {
+            type Output = GF2n<N, POLY>;
+
+            fn $op(self, rhs: GF2n<N, POLY>) -> Self::Output
+            {
+                $Op::$op(*self, rhs)
+            }
+        }
+
+        impl<'a, 'b, const N: u32, const POLY: u64> $Op<&'b GF2n<N, POLY>> for &'a GF2n<N, POLY>
+        {
+            type Output = GF2n<N, POLY>;
+
+            fn $op(self, rhs: &'b GF2n<N, POLY>) -> Self::Output
+            {
+                $Op::$op(*self, *rhs)
+            }
+        }
+
+        impl<const N: u32, const POLY: u64> $OpAssign<GF2n<N, POLY>> for GF2n<N, POLY>
+        {
+            fn $op_assign(&mut self, rhs: GF2n<N, POLY>)
+            {
+                *self = $Op::$op(*self, rhs);
+            }
+        }
+
+        impl<'a, const N: u32, const POLY: u64> $OpAssign<&'a GF2n<N, POLY>> for GF2n<N, POLY>
+        {
+            fn $op_assign(&mut self, rhs: &'a GF2n<N, POLY>)
+            {
+                *self = $Op::$op(*self, *rhs);
+            }
+        }
+    };
+}
+
+binop!(Add, add, AddAssign, add_assign, |lhs, rhs| GF2n(lhs.0.bitxor(rhs.0)));
+
+binop!(Sub, sub, SubAssign, sub_assign, |lhs, rhs| GF2n(lhs.0.bitxor(rhs.0)));
+
+binop!(Mul, mul, MulAssign, mul_assign, |lhs, rhs| GF2n(Self::reduce(Self::clmul(lhs.0, rhs.0))));
+
+binop!(Div, div, DivAssign, div_assign, |lhs, rhs| Mul::mul(lhs, rhs.multiplicative_inv()));
+
+/// Every element is its own additive inverse.
+impl<const N: u32, const POLY: u64> Neg for GF2n<N, POLY>
+{
+    type Output = GF2n<N, POLY>;
+
+    fn neg(self) -> Self::Output
+    {
+        self
+    }
+}
+
+impl<const N: u32, const POLY: u64> Neg for &GF2n<N, POLY>
+{
+    type Output = GF2n<N, POLY>;
+
+    fn neg(self) -> Self::Output
+    {
+        *self
+    }
+}
+
+impl<const N: u32, const POLY: u64> AdditiveIdentity for GF2n<N, POLY>
+{
+    type Output = GF2n<N, POLY>;
+
+    fn additive_identity() -> Self::Output
+    {
+        GF2n::new(0)
+    }
+}
+
+impl<const N: u32, const POLY: u64> AdditiveIdentity for &GF2n<N, POLY>
+{
+    type Output = GF2n<N, POLY>;
+
+    fn additive_identity() -> Self::Output
+    {
+        GF2n::new(0)
+    }
+}
+
+impl<const N: u32, const POLY: u64> AdditiveIdentity for &mut GF2n<N, POLY>
+{
+    type Output = GF2n<N, POLY>;
+
+    fn additive_identity() -> Self::Output
+    {
+        GF2n::new(0)
+    }
+}
+
+impl<const N: u32, const POLY: u64> MultiplicativeIdentity for GF2n<N, POLY>
+{
+    type Output = GF2n<N, POLY>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        GF2n::new(1)
+    }
+}
+
+impl<const N: u32, const POLY: u64> MultiplicativeIdentity for &GF2n<N, POLY>
+{
+    type Output = GF2n<N, POLY>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        GF2n::new(1)
+    }
+}
+
+impl<const N: u32, const POLY: u64> MultiplicativeIdentity for &mut GF2n<N, POLY>
+{
+    type Output = GF2n<N, POLY>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        GF2n::new(1)
+    }
+}
+
+impl<const N: u32, const POLY: u64> AdditiveInverse for GF2n<N, POLY>
+{
+    type Output = GF2n<N, POLY>;
+
+    fn additive_inv(&self) -> Self::Output
+    {
+        *self
+    }
+}
+
+impl<const N: u32, const POLY: u64> MultiplicativeInverse for GF2n<N, POLY>
+{
+    type Output = GF2n<N, POLY>;
+
+    fn multiplicative_inv(&self) -> Self::Output
+    {
+        self.checked_inv()
+            .unwrap_or_else(|| panic!("attempt to invert zero in GF(2^{})", N))
+    }
+}
+
+/// Whether the element is nonzero, as every unit is an exact pivot.
+impl<const N: u32, const POLY: u64> Magnitude for GF2n<N, POLY>
+{
+    type Output = bool;
+
+    fn magnitude(&self) -> Self::Output
+    {
+        self.0 != 0
+    }
+}
+
+impl<const N: u32, const POLY: u64> Conjugate for GF2n<N, POLY>
+{
+    type Output = GF2n<N, POLY>;
+
+    fn conj(&self) -> Self::Output
+    {
+        *self
+    }
+}
+
+impl<const N: u32, const POLY: u64> Group for GF2n<N, POLY> { }
+
+impl<const N: u32, const POLY: u64> AbelianGroup for GF2n<N, POLY> { }
+
+impl<const N: u32, const POLY: u64> Ring for GF2n<N, POLY> { }
+
+impl<const N: u32, const POLY: u64> CommutativeRing for GF2n<N, POLY> { }
+
+impl<const N: u32, const POLY: u64> Field for GF2n<N, POLY> { }
@@ -0,0 +1,5 @@
+mod prime;
+pub use prime::*;
+
+mod binary;
+pub use binary::*;
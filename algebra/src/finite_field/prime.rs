@@ -0,0 +1,291 @@
+use std::{fmt, ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg}};
+
+use crate::field::*;
+
+/// An element of the prime field `GF(P)`, stored as its least non-negative residue.
+/// 
+/// `P` must be prime for the inverses to exist; this is not checked beyond `P >= 2`, 
+/// and inverting a non-unit of a composite modulus panics.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fp<const P: u64>(u64);
+
+impl<const P: u64> Fp<P>
+{
+    const VALID: () = assert!(P >= 2, "the modulus of `Fp` must be at least 2");
+
+    pub const MODULUS: u64 = P;
+
+    /// The residue of `value` modulo `P`.
+    #[inline]
+    pub fn new(value: u64) -> Self 
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        Fp(value % P)
+    }
+
+    #[inline]
+    pub fn value(&self) -> u64
+    {
+        self.0
+    }
+
+    /// `self^exponent` by square-and-multiply.
+    pub fn pow(&self, mut exponent: u64) -> Self
+    {
+        let mut base: Self = *self;
+        let mut acc: Self = Self::new(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                acc *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        acc
+    }
+
+    /// The inverse by the extended Euclidean algorithm, or `None` when `self` is not a unit.
+    pub fn checked_inv(&self) -> Option<Self>
+    {
+        let (mut r0, mut r1) = (P as i128, self.0 as i128);
+        let (mut t0, mut t1) = (0i128, 1i128);
+        while r1 != 0 {
+            let q: i128 = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, t0 - q * t1);
+        }
+        if r0 == 1 {
+            Some(Fp(t0.rem_euclid(P as i128) as u64))
+        } else {
+            None
+        }
+    }
+}
+
+impl<const P: u64> From<u64> for Fp<P>
+{
+    fn from(value: u64) -> Self
+    {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> From<i64> for Fp<P>
+{
+    fn from(value: i64) -> Self
+    {
+        Self::new((value as i128).rem_euclid(P as i128) as u64)
+    }
+}
+
+impl<const P: u64> fmt::Display for Fp<P>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Implements `$op` and `$op_assign` for every combination of owned and borrowed 
+/// operands, computing the owned result from `$lhs` and `$rhs`.
+macro_rules! binop {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, |$lhs:ident, $rhs:ident| $body:expr) => {
+        impl<const P: u64> $Op<Fp<P>> for Fp<P>
+        {
+            type Output = Fp<P>;
+
+            fn $op(self, rhs: Fp<P>) -> Self::Output
+            {
+                let ($lhs, $rhs) = (self, rhs);
+                $body
+            }
+        }
+
+        impl<'a, const P: u64> $Op<&'a Fp<P>> for Fp<P>
+        {
+            type Output = Fp<P>;
+
+            fn $op(self, rhs: &'a Fp<P>) -> Self::Output
+            {
+                $Op::$op(self, *rhs)
+            }
+        }
+
+        impl<'a, const P: u64> $Op<Fp<P>> for &'a Fp<P>
+        {
+            type Output = Fp<P>;
+
+            fn $op(self, rhs: Fp<P>) -> Self::Output
+            {
+                $Op::$op(*self, rhs)
+            }
+        }
+
+        impl<'a, 'b, const P: u64> $Op<&'b Fp<P>> for &'a Fp<P>
+        {
+            type Output = Fp<P>;
+
+            fn $op(self, rhs: &'b Fp<P>) -> Self::Output
+            {
+                $Op::$op(*self, *rhs)
+            }
+        }
+
+        impl<const P: u64> $OpAssign<Fp<P>> for Fp<P>
+        {
+            fn $op_assign(&mut self, rhs: Fp<P>)
+            {
+                *self = $Op::$op(*self, rhs);
+            }
+        }
+
+        impl<'a, const P: u64> $OpAssign<&'a Fp<P>> for Fp<P>
+        {
+            fn $op_assign(&mut self, rhs: &'a Fp<P>)
+            {
+                *self = $Op::$op(*self, *rhs);
+            }
+        }
+    };
+}
+
+binop!(Add, add, AddAssign, add_assign, |lhs, rhs| Fp(((lhs.0 as u128 + rhs.0 as u128) % P as u128) as u64));
+
+binop!(Sub, sub, SubAssign, sub_assign, |lhs, rhs| Fp(((lhs.0 as u128 + (P - rhs.0) as u128) % P as u128) as u64));
+
+binop!(Mul, mul, MulAssign, mul_assign, |lhs, rhs| Fp(((lhs.0 as u128 * rhs.0 as u128) % P as u128) as u64));
+
+binop!(Div, div, DivAssign, div_assign, |lhs, rhs| Mul::mul(lhs, rhs.multiplicative_inv()));
+
+impl<const P: u64> Neg for Fp<P>
+{
+    type Output = Fp<P>;
+
+    fn neg(self) -> Self::Output
+    {
+        self.additive_inv()
+    }
+}
+
+impl<const P: u64> Neg for &Fp<P>
+{
+    type Output = Fp<P>;
+
+    fn neg(self) -> Self::Output
+    {
+        self.additive_inv()
+    }
+}
+
+impl<const P: u64> AdditiveIdentity for Fp<P>
+{
+    type Output = Fp<P>;
+
+    fn additive_identity() -> Self::Output
+    {
+        Fp::new(0)
+    }
+}
+
+impl<const P: u64> AdditiveIdentity for &Fp<P>
+{
+    type Output = Fp<P>;
+
+    fn additive_identity() -> Self::Output
+    {
+        Fp::new(0)
+    }
+}
+
+impl<const P: u64> AdditiveIdentity for &mut Fp<P>
+{
+    type Output = Fp<P>;
+
+    fn additive_identity() -> Self::Output
+    {
+        Fp::new(0)
+    }
+}
+
+impl<const P: u64> MultiplicativeIdentity for Fp<P>
+{
+    type Output = Fp<P>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Fp::new(1)
+    }
+}
+
+impl<const P: u64> MultiplicativeIdentity for &Fp<P>
+{
+    type Output = Fp<P>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Fp::new(1)
+    }
+}
+
+impl<const P: u64> MultiplicativeIdentity for &mut Fp<P>
+{
+    type Output = Fp<P>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Fp::new(1)
+    }
+}
+
+impl<const P: u64> AdditiveInverse for Fp<P>
+{
+    type Output = Fp<P>;
+
+    fn additive_inv(&self) -> Self::Output
+    {
+        Fp((P - self.0) % P)
+    }
+}
+
+impl<const P: u64> MultiplicativeInverse for Fp<P>
+{
+    type Output = Fp<P>;
+
+    fn multiplicative_inv(&self) -> Self::Output
+    {
+        self.checked_inv()
+            .unwrap_or_else(|| panic!("{} has no inverse modulo {}", self.0, P))
+    }
+}
+
+/// Whether the element is nonzero, as every unit is an exact pivot.
+impl<const P: u64> Magnitude for Fp<P>
+{
+    type Output = bool;
+
+    fn magnitude(&self) -> Self::Output
+    {
+        self.0 != 0
+    }
+}
+
+impl<const P: u64> Conjugate for Fp<P>
+{
+    type Output = Fp<P>;
+
+    fn conj(&self) -> Self::Output
+    {
+        *self
+    }
+}
+
+impl<const P: u64> Group for Fp<P> { }
+
+impl<const P: u64> AbelianGroup for Fp<P> { }
+
+impl<const P: u64> Ring for Fp<P> { }
+
+impl<const P: u64> CommutativeRing for Fp<P> { }
+
+impl<const P: u64> Field for Fp<P> { }
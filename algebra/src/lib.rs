@@ -1,5 +1,17 @@
 mod vector_space;
 pub use vector_space::*;
 
+mod matrix_space;
+pub use matrix_space::*;
+
 mod field;
 pub use field::*;
+
+mod complex;
+pub use complex::*;
+
+mod rational;
+pub use rational::*;
+
+mod finite_field;
+pub use finite_field::*;
@@ -0,0 +1,59 @@
+/// The order in which eigenvalues, and their eigenvectors, are returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EigenOrder {
+    Ascending,
+    Descending
+}
+
+/// Eigenvalues and orthonormal eigenvectors of a real symmetric matrix, `A = V * L * V^T`, 
+/// with the eigenvalues collected into a `V`.
+pub trait SymmetricEigenDecomposition<V>
+{
+    type Matrix;
+
+    type Output;
+
+    type Error;
+
+    /// Reduces `A` to tridiagonal form and iterates with the implicit QL algorithm.
+    fn eigh(&self, a: &Self::Matrix, order: EigenOrder) -> Result<Self::Output, Self::Error>;
+
+    /// Iterates with the cyclic Jacobi method, which is slower but computes small 
+    /// eigenvalues to high relative accuracy.
+    fn eigh_jacobi(&self, a: &Self::Matrix, order: EigenOrder) -> Result<Self::Output, Self::Error>;
+}
+
+/// The Hessenberg decomposition `A = Q * H * Q^T`, where `H` is zero below its subdiagonal.
+pub trait HessenbergDecomposition
+{
+    type Matrix;
+
+    type Output;
+
+    fn hessenberg(&self, a: &Self::Matrix) -> Self::Output;
+}
+
+/// The real Schur decomposition `A = Z * T * Z^T`, where `T` is quasi upper triangular.
+pub trait SchurDecomposition
+{
+    type Matrix;
+
+    type Output;
+
+    type Error;
+
+    fn schur(&self, a: &Self::Matrix) -> Result<Self::Output, Self::Error>;
+}
+
+/// Eigenvalues and eigenvectors of a general real matrix, which may be complex, with the 
+/// eigenvalues collected into a `V`.
+pub trait EigenDecomposition<V>
+{
+    type Matrix;
+
+    type Output;
+
+    type Error;
+
+    fn eig(&self, a: &Self::Matrix) -> Result<Self::Output, Self::Error>;
+}
@@ -0,0 +1,51 @@
+/// LU decomposition with partial pivoting, `P * A = L * U`.
+pub trait LUDecomposition
+{
+    type Matrix;
+
+    type Output;
+
+    type Error;
+
+    fn lu(&self, a: &Self::Matrix) -> Result<Self::Output, Self::Error>;
+}
+
+/// Solves `A * x = b` for a square `A`.
+pub trait Solve<X>
+{
+    type Matrix;
+
+    type Error;
+
+    fn solve(&self, a: &Self::Matrix, b: &X) -> Result<X, Self::Error>;
+}
+
+/// Solves `A * x = b` for a square `A`, overwriting `b` with `x`.
+pub trait SolveMut<X>
+{
+    type Matrix;
+
+    type Error;
+
+    fn solve_mut(&self, a: &Self::Matrix, b: &mut X) -> Result<(), Self::Error>;
+}
+
+/// The determinant of a square matrix.
+pub trait Determinant
+{
+    type Matrix;
+
+    type Scalar;
+
+    fn determinant(&self, a: &Self::Matrix) -> Self::Scalar;
+}
+
+/// The inverse of a square matrix.
+pub trait Inverse
+{
+    type Matrix;
+
+    type Error;
+
+    fn inverse(&self, a: &Self::Matrix) -> Result<Self::Matrix, Self::Error>;
+}
@@ -0,0 +1,18 @@
+mod lu;
+pub use lu::*;
+
+mod qr;
+pub use qr::*;
+
+mod symmetric;
+pub use symmetric::*;
+
+mod eigen;
+pub use eigen::*;
+
+pub trait MatrixSpace
+{
+    type Scalar;
+
+    type Matrix;
+}
@@ -0,0 +1,22 @@
+/// QR decomposition, `A = Q * R`, or `A * P = Q * R` with column pivoting.
+pub trait QRDecomposition
+{
+    type Matrix;
+
+    type Output;
+
+    fn qr(&self, a: &Self::Matrix) -> Self::Output;
+
+    /// The rank-revealing decomposition, whose `R` has a non-increasing diagonal.
+    fn qr_pivoted(&self, a: &Self::Matrix) -> Self::Output;
+}
+
+/// The `x` minimising `||A * x - b||_2`, for `A` with at least as many rows as columns.
+pub trait LeastSquares<X, Y = X>
+{
+    type Matrix;
+
+    type Error;
+
+    fn lstsq(&self, a: &Self::Matrix, b: &X) -> Result<Y, Self::Error>;
+}
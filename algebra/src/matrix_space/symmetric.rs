@@ -0,0 +1,22 @@
+/// Cholesky decomposition of a symmetric positive definite matrix, `A = L * L^T`.
+pub trait CholeskyDecomposition
+{
+    type Matrix;
+
+    type Output;
+
+    type Error;
+
+    fn cholesky(&self, a: &Self::Matrix) -> Result<Self::Output, Self::Error>;
+}
+
+/// LDL^T decomposition of a symmetric, possibly indefinite, matrix with symmetric 
+/// pivoting, `P * A * P^T = L * D * L^T`.
+pub trait LDLTDecomposition
+{
+    type Matrix;
+
+    type Output;
+
+    fn ldlt(&self, a: &Self::Matrix) -> Self::Output;
+}
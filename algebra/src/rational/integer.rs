@@ -0,0 +1,103 @@
+use std::ops::{Div, Rem};
+
+use crate::field::{AdditiveIdentity, MultiplicativeIdentity};
+
+/// The integer primitives a `Rational` can be built over.
+pub trait Integer: Copy 
+    + Ord
+    + Div<Self, Output=Self>
+    + Rem<Self, Output=Self>
+    + AdditiveIdentity<Output=Self>
+    + MultiplicativeIdentity<Output=Self>
+{
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn checked_neg(self) -> Option<Self>;
+
+    /// The greatest common divisor of `a` and `b`, which is only negative when it 
+    /// is `MIN` and so cannot be negated.
+    fn gcd(a: Self, b: Self) -> Self;
+}
+
+macro_rules! primitives {
+    (
+        $($primitive:ty),*
+    ) => {
+        $(
+            impl Integer for $primitive
+            {
+                integer!{ $primitive }
+
+                fn gcd(mut a: Self, mut b: Self) -> Self
+                {
+                    while b != 0 {
+                        let r: Self = a % b;
+                        a = b;
+                        b = r;
+                    }
+                    a
+                }
+            }
+        )*
+    };
+
+    (
+        @signed $($primitive:ty),*
+    ) => {
+        $(
+            impl Integer for $primitive
+            {
+                integer!{ $primitive }
+
+                fn gcd(mut a: Self, mut b: Self) -> Self
+                {
+                    while b != 0 {
+                        let r: Self = a.wrapping_rem(b);
+                        a = b;
+                        b = r;
+                    }
+                    a.wrapping_abs()
+                }
+            }
+        )*
+    };
+}
+
+/// Forwards the checked arithmetic to the inherent methods of `$primitive`.
+macro_rules! integer {
+    ($primitive:ty) => {
+        #[inline]
+        fn checked_add(self, rhs: Self) -> Option<Self>
+        {
+            <$primitive>::checked_add(self, rhs)
+        }
+
+        #[inline]
+        fn checked_sub(self, rhs: Self) -> Option<Self>
+        {
+            <$primitive>::checked_sub(self, rhs)
+        }
+
+        #[inline]
+        fn checked_mul(self, rhs: Self) -> Option<Self>
+        {
+            <$primitive>::checked_mul(self, rhs)
+        }
+
+        #[inline]
+        fn checked_neg(self) -> Option<Self>
+        {
+            <$primitive>::checked_neg(self)
+        }
+    };
+}
+
+primitives!{
+    u8, u16, u32, u64, u128, usize
+}
+
+primitives!{@signed i8, i16, i32, i64, i128, isize}
@@ -0,0 +1,336 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::field::*;
+
+mod integer;
+pub use integer::*;
+
+mod ops;
+
+/// The exact fraction `numer / denom` over the integer primitive `I`.
+/// 
+/// Values are kept normalised, with the fraction in lowest terms and a positive 
+/// denominator, so the derived equality is equality of the rationals. Arithmetic 
+/// that overflows `I` panics in every build; the `checked_*` methods return `None` 
+/// instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational<I> {
+    numer: I,
+    denom: I
+}
+
+impl<I: Integer> Rational<I>
+{
+    /// The normalised `numer / denom`, panicking when `denom` is zero or the 
+    /// normalised value does not fit in `I`.
+    pub fn new(numer: I, denom: I) -> Self 
+    {
+        assert!(denom != I::additive_identity(), "rational with a zero denominator");
+        Self::checked_new(numer, denom)
+            .unwrap_or_else(|| panic!("rational overflow"))
+    }
+
+    /// The normalised `numer / denom`, or `None` when `denom` is zero or the 
+    /// normalised value does not fit in `I`.
+    pub fn checked_new(numer: I, denom: I) -> Option<Self>
+    {
+        let zero: I = I::additive_identity();
+        if denom == zero {
+            return None
+        }
+        if numer == zero {
+            return Some(Self::from_integer(zero))
+        }
+        if numer == denom {
+            return Some(Self::from_integer(I::multiplicative_identity()))
+        }
+
+        let mut gcd: I = I::gcd(numer, denom);
+        if gcd < zero {
+            gcd = gcd.checked_neg()?;
+        }
+        let (mut numer, mut denom) = (numer / gcd, denom / gcd);
+        if denom < zero {
+            numer = numer.checked_neg()?;
+            denom = denom.checked_neg()?;
+        }
+        Some(Rational { numer, denom })
+    }
+
+    #[inline]
+    pub fn from_integer(integer: I) -> Self
+    {
+        Rational {
+            numer: integer,
+            denom: I::multiplicative_identity()
+        }
+    }
+
+    #[inline]
+    pub fn numer(&self) -> I
+    {
+        self.numer
+    }
+
+    #[inline]
+    pub fn denom(&self) -> I
+    {
+        self.denom
+    }
+
+    #[inline]
+    pub fn is_integer(&self) -> bool
+    {
+        self.denom == I::multiplicative_identity()
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self>
+    {
+        let gcd: I = I::gcd(self.denom, rhs.denom);
+        let (lhs_factor, rhs_factor) = (rhs.denom / gcd, self.denom / gcd);
+        let numer: I = self.numer
+            .checked_mul(lhs_factor)?
+            .checked_add(rhs.numer.checked_mul(rhs_factor)?)?;
+        Self::checked_new(numer, self.denom.checked_mul(lhs_factor)?)
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self>
+    {
+        let gcd: I = I::gcd(self.denom, rhs.denom);
+        let (lhs_factor, rhs_factor) = (rhs.denom / gcd, self.denom / gcd);
+        let numer: I = self.numer
+            .checked_mul(lhs_factor)?
+            .checked_sub(rhs.numer.checked_mul(rhs_factor)?)?;
+        Self::checked_new(numer, self.denom.checked_mul(lhs_factor)?)
+    }
+
+    /// Cross-cancels before multiplying, so only a product that does not fit 
+    /// in lowest terms overflows.
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self>
+    {
+        let zero: I = I::additive_identity();
+        if self.numer == zero || rhs.numer == zero {
+            return Some(Self::from_integer(zero))
+        }
+        let lhs_gcd: I = I::gcd(self.numer, rhs.denom);
+        let rhs_gcd: I = I::gcd(rhs.numer, self.denom);
+        let numer: I = (self.numer / lhs_gcd).checked_mul(rhs.numer / rhs_gcd)?;
+        let denom: I = (self.denom / rhs_gcd).checked_mul(rhs.denom / lhs_gcd)?;
+        Self::checked_new(numer, denom)
+    }
+
+    /// `None` when `rhs` is zero or the quotient overflows.
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self>
+    {
+        self.checked_mul(&rhs.checked_recip()?)
+    }
+
+    pub fn checked_neg(&self) -> Option<Self>
+    {
+        Some(Rational {
+            numer: self.numer.checked_neg()?,
+            denom: self.denom
+        })
+    }
+
+    /// `None` when `self` is zero.
+    pub fn checked_recip(&self) -> Option<Self>
+    {
+        Self::checked_new(self.denom, self.numer)
+    }
+
+    /// `numer / denom` rounded towards negative infinity, with its non-negative remainder.
+    fn div_rem_floor(&self) -> (I, I)
+    {
+        let (quot, rem) = (self.numer / self.denom, self.numer % self.denom);
+        if rem < I::additive_identity() {
+            (quot.checked_sub(I::multiplicative_identity()).unwrap(), rem.checked_add(self.denom).unwrap())
+        } else {
+            (quot, rem)
+        }
+    }
+}
+
+impl<I: Integer> From<I> for Rational<I>
+{
+    fn from(integer: I) -> Self
+    {
+        Self::from_integer(integer)
+    }
+}
+
+impl<I: Integer> PartialOrd for Rational<I>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the continued fraction expansions, which never overflows.
+impl<I: Integer> Ord for Rational<I>
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        let (lhs_quot, lhs_rem) = self.div_rem_floor();
+        let (rhs_quot, rhs_rem) = other.div_rem_floor();
+        let zero: I = I::additive_identity();
+
+        match lhs_quot.cmp(&rhs_quot) {
+            Ordering::Equal => match (lhs_rem == zero, rhs_rem == zero) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => {
+                    let lhs = Rational { numer: self.denom, denom: lhs_rem };
+                    let rhs = Rational { numer: other.denom, denom: rhs_rem };
+                    rhs.cmp(&lhs)
+                }
+            },
+            ordering => ordering
+        }
+    }
+}
+
+impl<I> fmt::Display for Rational<I>
+where
+    I: Integer + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl<I: Integer> AdditiveIdentity for Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn additive_identity() -> Self::Output
+    {
+        Rational::from_integer(I::additive_identity())
+    }
+}
+
+impl<I: Integer> AdditiveIdentity for &Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn additive_identity() -> Self::Output
+    {
+        Rational::<I>::additive_identity()
+    }
+}
+
+impl<I: Integer> AdditiveIdentity for &mut Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn additive_identity() -> Self::Output
+    {
+        Rational::<I>::additive_identity()
+    }
+}
+
+impl<I: Integer> MultiplicativeIdentity for Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Rational::from_integer(I::multiplicative_identity())
+    }
+}
+
+impl<I: Integer> MultiplicativeIdentity for &Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Rational::<I>::multiplicative_identity()
+    }
+}
+
+impl<I: Integer> MultiplicativeIdentity for &mut Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Rational::<I>::multiplicative_identity()
+    }
+}
+
+impl<I: Integer> AdditiveInverse for Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn additive_inv(&self) -> Self::Output
+    {
+        self.checked_neg()
+            .unwrap_or_else(|| panic!("attempt to negate with overflow"))
+    }
+}
+
+impl<I: Integer> MultiplicativeInverse for Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn multiplicative_inv(&self) -> Self::Output
+    {
+        self.checked_recip()
+            .unwrap_or_else(|| panic!("attempt to divide by zero"))
+    }
+}
+
+impl<I: Integer> Magnitude for Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn magnitude(&self) -> Self::Output
+    {
+        if *self < Self::additive_identity() {
+            self.additive_inv()
+        } else {
+            *self
+        }
+    }
+}
+
+impl<I: Integer> Conjugate for Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn conj(&self) -> Self::Output
+    {
+        *self
+    }
+}
+
+/// Only the signed primitives give a group under addition.
+macro_rules! primitives {
+    (
+        $($primitive:ty),*
+    ) => {
+        $(
+            impl Group for Rational<$primitive> { }
+
+            impl AbelianGroup for Rational<$primitive> { }
+
+            impl Ring for Rational<$primitive> { }
+
+            impl CommutativeRing for Rational<$primitive> { }
+
+            impl Field for Rational<$primitive> { }
+        )*
+    };
+}
+
+primitives!{
+    i8, i16, i32, i64, i128, isize
+}
@@ -0,0 +1,95 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+use super::{Integer, Rational};
+
+/// Implements `$op` and `$op_assign` for every combination of owned and borrowed 
+/// operands through `$checked`, panicking on overflow like the integer primitives.
+macro_rules! binop {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $checked:ident, $message:expr) => {
+        impl<I: Integer> $Op<Rational<I>> for Rational<I>
+        {
+            type Output = Rational<I>;
+
+            fn $op(self, rhs: Rational<I>) -> Self::Output
+            {
+                self.$checked(&rhs)
+                    .unwrap_or_else(|| panic!($message))
+            }
+        }
+
+        impl<'a, I: Integer> $Op<&'a Rational<I>> for Rational<I>
+        {
+            type Output = Rational<I>;
+
+            fn $op(self, rhs: &'a Rational<I>) -> Self::Output
+            {
+                $Op::$op(self, *rhs)
+            }
+        }
+
+        impl<'a, I: Integer> $Op<Rational<I>> for &'a Rational<I>
+        {
+            type Output = Rational<I>;
+
+            fn $op(self, rhs: Rational<I>) -> Self::Output
+            {
+                $Op::$op(*self, rhs)
+            }
+        }
+
+        impl<'a, 'b, I: Integer> $Op<&'b Rational<I>> for &'a Rational<I>
+        {
+            type Output = Rational<I>;
+
+            fn $op(self, rhs: &'b Rational<I>) -> Self::Output
+            {
+                $Op::$op(*self, *rhs)
+            }
+        }
+
+        impl<I: Integer> $OpAssign<Rational<I>> for Rational<I>
+        {
+            fn $op_assign(&mut self, rhs: Rational<I>)
+            {
+                *self = $Op::$op(*self, rhs);
+            }
+        }
+
+        impl<'a, I: Integer> $OpAssign<&'a Rational<I>> for Rational<I>
+        {
+            fn $op_assign(&mut self, rhs: &'a Rational<I>)
+            {
+                *self = $Op::$op(*self, *rhs);
+            }
+        }
+    };
+}
+
+binop!(Add, add, AddAssign, add_assign, checked_add, "attempt to add with overflow");
+
+binop!(Sub, sub, SubAssign, sub_assign, checked_sub, "attempt to subtract with overflow");
+
+binop!(Mul, mul, MulAssign, mul_assign, checked_mul, "attempt to multiply with overflow");
+
+binop!(Div, div, DivAssign, div_assign, checked_div, "attempt to divide by zero or with overflow");
+
+impl<I: Integer> Neg for Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn neg(self) -> Self::Output
+    {
+        self.checked_neg()
+            .unwrap_or_else(|| panic!("attempt to negate with overflow"))
+    }
+}
+
+impl<I: Integer> Neg for &Rational<I>
+{
+    type Output = Rational<I>;
+
+    fn neg(self) -> Self::Output
+    {
+        -*self
+    }
+}
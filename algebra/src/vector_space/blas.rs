@@ -37,3 +37,187 @@ pub trait DotVMut
 
     fn dotv_mut(&self, x: &Self::Vector, y: &Self::Vector, output: &mut Self::Scalar);
 }
+
+/// The `dotc` BLAS routine, the sum of `conj(x[i]) * y[i]`.
+pub trait DotC
+{
+    type Vector;
+
+    type Scalar;
+
+    fn dotc(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar;
+}
+
+/// The `dotc` BLAS routine, the sum of `conj(x[i]) * y[i]`.
+pub trait DotCMut
+{
+    type Vector;
+
+    type Scalar;
+
+    fn dotc_mut(&self, x: &Self::Vector, y: &Self::Vector, output: &mut Self::Scalar);
+}
+
+/// The `asum` BLAS routine, the sum of the absolute values of `x`.
+pub trait ASumV
+{
+    type Vector;
+
+    type Scalar;
+
+    fn asumv(&self, x: &Self::Vector) -> Self::Scalar;
+}
+
+/// The `nrm2` BLAS routine, the Euclidean norm of `x`.
+pub trait Nrm2V
+{
+    type Vector;
+
+    type Scalar;
+
+    fn nrm2v(&self, x: &Self::Vector) -> Self::Scalar;
+}
+
+/// The `iamax` BLAS routine, the index of the first element of `x` with the largest absolute value.
+pub trait IAMaxV
+{
+    type Vector;
+
+    fn iamaxv(&self, x: &Self::Vector) -> Option<usize>;
+}
+
+/// The `swap` BLAS routine.
+pub trait SwapV
+{
+    type Vector;
+
+    fn swapv(&self, x: &mut Self::Vector, y: &mut Self::Vector);
+}
+
+/// The `copy` BLAS routine, `y = x`.
+pub trait CopyV
+{
+    type Vector;
+
+    fn copyv(&self, x: &Self::Vector, y: &mut Self::Vector);
+}
+
+/// The `scal` BLAS routine, scaling every `incx`-th element of `x` by `alpha`.
+pub trait ScalV
+{
+    type Vector;
+
+    type Scalar;
+
+    fn scalv(&self, alpha: &Self::Scalar, x: &mut Self::Vector, incx: usize);
+}
+
+/// The `rot` BLAS routine, applying the Givens rotation `(c, s)` to the points `(x[i], y[i])`.
+pub trait RotV
+{
+    type Vector;
+
+    type Scalar;
+
+    fn rotv(&self, x: &mut Self::Vector, y: &mut Self::Vector, c: &Self::Scalar, s: &Self::Scalar);
+}
+
+/// The `rotg` BLAS routine, constructing the Givens rotation `(c, s)` that zeroes `b`.
+/// 
+/// On return `a` holds `r` and `b` holds the reconstruction value `z`.
+pub trait RotG
+{
+    type Scalar;
+
+    fn rotg(&self, a: &mut Self::Scalar, b: &mut Self::Scalar) -> (Self::Scalar, Self::Scalar);
+}
+
+/// The `rotm` BLAS routine, applying the modified Givens rotation described by `param`.
+/// 
+/// `param` holds `[flag, h11, h21, h12, h22]` as produced by `RotMG`.
+pub trait RotMV
+{
+    type Vector;
+
+    type Scalar;
+
+    fn rotmv(&self, x: &mut Self::Vector, y: &mut Self::Vector, param: &[Self::Scalar; 5]);
+}
+
+/// The `rotmg` BLAS routine, constructing the modified Givens rotation that zeroes 
+/// the second component of `(sqrt(d1) * x1, sqrt(d2) * y1)`.
+pub trait RotMG
+{
+    type Scalar;
+
+    fn rotmg(&self, d1: &mut Self::Scalar, d2: &mut Self::Scalar, x1: &mut Self::Scalar, y1: &Self::Scalar) -> [Self::Scalar; 5];
+}
+
+/// Selects whether a BLAS routine uses a matrix or its transpose.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transpose {
+    NoTrans,
+    Trans
+}
+
+/// The `gemv` BLAS routine, `alpha * op(A) * x + beta * y`.
+pub trait GEMV<X, Y = X>
+{
+    type Matrix;
+
+    type Scalar;
+
+    fn gemv(&self, trans: Transpose, alpha: &Self::Scalar, a: &Self::Matrix, x: &X, beta: &Self::Scalar, y: &Y) -> Y;
+}
+
+/// The `gemv` BLAS routine, `y = alpha * op(A) * x + beta * y`.
+pub trait GEMVMut<X, Y = X>
+{
+    type Matrix;
+
+    type Scalar;
+
+    fn gemv_mut(&self, trans: Transpose, alpha: &Self::Scalar, a: &Self::Matrix, x: &X, beta: &Self::Scalar, y: &mut Y);
+}
+
+/// The `ger` BLAS routine, `A + alpha * x * y^T`.
+pub trait GER<X, Y = X>
+{
+    type Matrix;
+
+    type Scalar;
+
+    fn ger(&self, alpha: &Self::Scalar, x: &X, y: &Y, a: &Self::Matrix) -> Self::Matrix;
+}
+
+/// The `ger` BLAS routine, `A = A + alpha * x * y^T`.
+pub trait GERMut<X, Y = X>
+{
+    type Matrix;
+
+    type Scalar;
+
+    fn ger_mut(&self, alpha: &Self::Scalar, x: &X, y: &Y, a: &mut Self::Matrix);
+}
+
+/// The `gemm` BLAS routine, `alpha * op(A) * op(B) + beta * C`.
+pub trait GEMM<A, B = A>
+{
+    type Matrix;
+
+    type Scalar;
+
+    #[allow(clippy::too_many_arguments)]
+    fn gemm(&self, transa: Transpose, transb: Transpose, alpha: &Self::Scalar, a: &A, b: &B, beta: &Self::Scalar, c: &Self::Matrix) -> Self::Matrix;
+}
+
+/// The `gemm` BLAS routine, `C = alpha * op(A) * op(B) + beta * C`.
+pub trait GEMMMut<A, B = A>
+{
+    type Matrix;
+
+    type Scalar;
+
+    #[allow(clippy::too_many_arguments)]
+    fn gemm_mut(&self, transa: Transpose, transb: Transpose, alpha: &Self::Scalar, a: &A, b: &B, beta: &Self::Scalar, c: &mut Self::Matrix);
+}
@@ -0,0 +1,65 @@
+pub trait CheckedVAdd
+{
+    type Vector;
+
+    type Error;
+
+    fn checked_vadd(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Result<Self::Vector, Self::Error>;
+}
+
+pub trait CheckedVAddMut
+{
+    type Vector;
+
+    type Error;
+
+    fn checked_vadd_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector) -> Result<(), Self::Error>;
+}
+
+/// The `axpyv` BLAS routine, failing on operands of different dimensions.
+pub trait CheckedVAXPY
+{
+    type Vector;
+
+    type Scalar;
+
+    type Error;
+
+    fn checked_vaxpy(&self, alpha: &Self::Scalar, x: &Self::Vector, y: &Self::Vector) -> Result<Self::Vector, Self::Error>;
+}
+
+/// The `axpyv` BLAS routine, failing on operands of different dimensions.
+pub trait CheckedVAXPYMut
+{
+    type Vector;
+
+    type Scalar;
+
+    type Error;
+
+    fn checked_vaxpy_mut(&self, alpha: &Self::Scalar, x: &Self::Vector, y: &mut Self::Vector) -> Result<(), Self::Error>;
+}
+
+/// The `dotv` BLAS routine, failing on operands of different dimensions.
+pub trait CheckedDotV
+{
+    type Vector;
+
+    type Scalar;
+
+    type Error;
+
+    fn checked_dotv(&self, x: &Self::Vector, y: &Self::Vector) -> Result<Self::Scalar, Self::Error>;
+}
+
+/// The `dotv` BLAS routine, failing on operands of different dimensions.
+pub trait CheckedDotVMut
+{
+    type Vector;
+
+    type Scalar;
+
+    type Error;
+
+    fn checked_dotv_mut(&self, x: &Self::Vector, y: &Self::Vector, output: &mut Self::Scalar) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,53 @@
+use super::DotV;
+
+/// The cross product `lhs x rhs` of three-dimensional vectors.
+pub trait VCross
+{
+    type Vector;
+
+    fn vcross(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector;
+}
+
+/// The cross product `lhs x rhs` of three-dimensional vectors, written to `lhs`.
+pub trait VCrossMut
+{
+    type Vector;
+
+    fn vcross_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector);
+}
+
+/// The scalar and vector triple products, for any space with both a cross and a dot product.
+pub trait VTripleProduct: VCross + DotV<Vector=<Self as VCross>::Vector>
+{
+    /// `a . (b x c)`, the signed volume of the parallelepiped spanned by `a`, `b` and `c`.
+    fn scalar_triple(&self, a: &<Self as VCross>::Vector, b: &<Self as VCross>::Vector, c: &<Self as VCross>::Vector) -> <Self as DotV>::Scalar
+    {
+        self.dotv(a, &self.vcross(b, c))
+    }
+
+    /// `a x (b x c)`.
+    fn vector_triple(&self, a: &<Self as VCross>::Vector, b: &<Self as VCross>::Vector, c: &<Self as VCross>::Vector) -> <Self as VCross>::Vector
+    {
+        self.vcross(a, &self.vcross(b, c))
+    }
+}
+
+impl<S> VTripleProduct for S
+where
+    S: VCross + DotV<Vector=<S as VCross>::Vector>
+{
+}
+
+/// The wedge product `lhs ^ rhs` of two vectors in any dimension.
+/// 
+/// The bivector holds the `n(n-1)/2` coefficients `lhs[i] * rhs[j] - lhs[j] * rhs[i]` 
+/// for `i < j`, in lexicographic order of `(i, j)`. In three dimensions these are the
+/// components of `lhs x rhs` up to order and sign: `(e12, e13, e23) = (z, -y, x)`.
+pub trait VWedge
+{
+    type Vector;
+
+    type Bivector;
+
+    fn vwedge(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Bivector;
+}
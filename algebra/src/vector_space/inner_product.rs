@@ -0,0 +1,24 @@
+use super::NormedSpace;
+
+/// The Hermitian inner product `<x, y> = x^H y`.
+/// 
+/// `x` enters through its conjugate transpose, so the product is conjugate-linear in 
+/// `x`, linear in `y`, and `<y, x>` is the conjugate of `<x, y>`. Over real scalars 
+/// it coincides with `DotV`.
+pub trait HermitianInnerProduct
+{
+    type Vector;
+
+    type Scalar;
+
+    fn inner(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar;
+}
+
+/// A real inner product space, normed by `||x|| = sqrt(<x, x>)`.
+pub trait InnerProductSpace: NormedSpace
+{
+    fn inner_product(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar;
+
+    /// The angle between `x` and `y` in radians, which is NaN when either is zero.
+    fn angle(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar;
+}
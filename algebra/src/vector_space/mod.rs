@@ -1,6 +1,18 @@
 mod blas;
 pub use blas::*;
 
+mod checked;
+pub use checked::*;
+
+mod inner_product;
+pub use inner_product::*;
+
+mod norm;
+pub use norm::*;
+
+mod exterior;
+pub use exterior::*;
+
 pub trait VectorSpace
 {
     type Scalar;
@@ -22,6 +34,20 @@ pub trait VAddMut
     fn vadd_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector);
 }
 
+pub trait VSub
+{
+    type Vector;
+
+    fn vsub(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector;
+}
+
+pub trait VSubMut
+{
+    type Vector;
+
+    fn vsub_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector);
+}
+
 pub trait VScale 
 {
     type Scalar;
@@ -40,6 +66,37 @@ pub trait VScaleMut
     fn vscale_mut(&self, vector: &mut Self::Vector, scalar: &Self::Scalar);    
 }
 
+/// The element-wise product `lhs[i] * rhs[i]`.
+pub trait VHadamard
+{
+    type Vector;
+
+    fn vhadamard(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector;
+}
+
+/// The element-wise product `lhs[i] * rhs[i]`.
+pub trait VHadamardMut
+{
+    type Vector;
+
+    fn vhadamard_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector);
+}
+
+/// The element-wise quotient `lhs[i] / rhs[i]`.
+pub trait VHadamardDiv
+{
+    type Vector;
+
+    fn vhadamard_div(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector;
+}
+
+/// The element-wise quotient `lhs[i] / rhs[i]`.
+pub trait VHadamardDivMut
+{
+    type Vector;
+
+    fn vhadamard_div_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector);
+}
 
 pub trait VIdentity: VMultiplicativeIdentity + VAdditiveIdentity
 {
@@ -84,6 +141,20 @@ pub trait VPartialEq
     fn eq(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> bool;
 }
 
+/// Element-wise `ApproxEq`, false for vectors of different dimensions.
+pub trait VApproxEq
+{
+    type Vector;
+
+    type Epsilon;
+
+    fn abs_diff_eq(&self, lhs: &Self::Vector, rhs: &Self::Vector, epsilon: &Self::Epsilon) -> bool;
+
+    fn relative_eq(&self, lhs: &Self::Vector, rhs: &Self::Vector, epsilon: &Self::Epsilon, max_relative: &Self::Epsilon) -> bool;
+
+    fn ulps_eq(&self, lhs: &Self::Vector, rhs: &Self::Vector, epsilon: &Self::Epsilon, max_ulps: u32) -> bool;
+}
+
 
 #[macro_export]
 macro_rules! vadd 
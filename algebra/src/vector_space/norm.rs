@@ -0,0 +1,35 @@
+/// The vector norms, `||x||_p = (sum |x[i]|^p)^(1/p)` and its limit `max |x[i]|`.
+pub trait Norm
+{
+    type Vector;
+
+    type Scalar;
+
+    fn norm_l1(&self, x: &Self::Vector) -> Self::Scalar;
+
+    fn norm_l2(&self, x: &Self::Vector) -> Self::Scalar;
+
+    fn norm_inf(&self, x: &Self::Vector) -> Self::Scalar;
+
+    /// The `p`-norm for `p >= 1`, where an infinite `p` gives `norm_inf`.
+    fn norm_p(&self, x: &Self::Vector, p: &Self::Scalar) -> Self::Scalar;
+}
+
+/// A vector space with a distinguished norm.
+pub trait NormedSpace
+{
+    type Vector;
+
+    type Scalar;
+
+    fn norm(&self, x: &Self::Vector) -> Self::Scalar;
+
+    /// `x / ||x||`, leaving the zero vector unchanged.
+    fn normalize(&self, x: &Self::Vector) -> Self::Vector;
+
+    /// `x / ||x||`, leaving the zero vector unchanged.
+    fn normalize_mut(&self, x: &mut Self::Vector);
+
+    /// `||x - y||`.
+    fn distance(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar;
+}
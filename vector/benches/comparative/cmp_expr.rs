@@ -0,0 +1,91 @@
+macro_rules! benchmark
+{
+    (
+       $(
+           ($uid:ident, $vec_length:expr)
+        ),+
+    ) => {     
+        $(        
+            pub use $uid::$uid;
+
+            mod $uid
+            {    
+                use criterion::{criterion_group, Criterion};
+                use vector::vspace;             
+                use algebra::*;   
+
+                use rand::{thread_rng, Rng};
+
+                vspace! {
+                    Space {
+                        vector: Vector,
+                        dimension: $vec_length,
+                        using: Vec<T>,
+                        Implements::BinOps::VAdd,
+                        Implements::BinOps::VAddMut,
+                        Implements::BinOps::VScale,
+                        Implements::BinOps::VScaleMut,
+                        Implements::ExprOps::Expr
+                    }
+                }
+
+                fn random() -> Vector<f64> {
+                    let mut inner = vec![0.0; $vec_length];
+                    for element in inner.iter_mut() {
+                        *element = thread_rng().gen()
+                    }
+                    Vector::new(inner)
+                }
+
+                /// `x + 2y + z`, one pass per operand against a single fused pass.
+                fn bench_expr(bench: &mut Criterion)
+                {
+                    let mut group = bench.benchmark_group(
+                        concat!( stringify!($uid), "-vector-expression" )
+                    );
+
+                    {
+                        let vector_space = Space::new();
+                        let x: Vector<f64> = random();
+                        let y: Vector<f64> = random();
+                        let z: Vector<f64> = random();
+
+                        group.bench_function("vadd-vscale", move |bencher| {
+                            bencher.iter(|| {
+                                let mut w: Vector<f64> = x.clone();
+                                let mut s: Vector<f64> = y.clone();
+                                let s: Vector<f64> = vscale!(vector_space, s, &2.0);
+                                vadd!(vector_space, w, &s, &z)
+                            })
+                        });
+                    }
+
+                    {
+                        let vector_space = Space::new();
+                        let x: Vector<f64> = random();
+                        let y: Vector<f64> = random();
+                        let z: Vector<f64> = random();
+                        let mut w: Vector<f64> = random();
+
+                        group.bench_function("expr", move |bencher| {
+                            bencher.iter(|| {
+                                vector_space.eval(vector_space.expr(&x) + 2.0 * &y + &z, &mut w)
+                            })
+                        });
+                    }
+                }
+                
+                criterion_group!(
+                    $uid,
+                    bench_expr
+                );   
+            }
+        )+
+    };
+}
+
+benchmark!{
+    (expr_smallvec, 32),
+    (expr_medvec, 1024),
+    (expr_bigvec, 1000000)
+}
@@ -0,0 +1,138 @@
+macro_rules! benchmark
+{
+    (
+       $(
+           ($uid:ident, $dimension:expr $(, $array_dimension:expr)?)
+        ),+
+    ) => {     
+        $(        
+            pub use $uid::$uid;
+
+            mod $uid
+            {    
+                use criterion::{criterion_group, measurement::WallTime, BenchmarkGroup, Criterion};
+                use vector::mspace;             
+                use algebra::*;   
+
+                use rand::{thread_rng, Rng};
+
+                const DIMENSION: usize = $dimension;
+
+                mspace! {
+                    Space {
+                        matrix: Matrix,
+                        rows: $dimension,
+                        columns: $dimension
+                    }
+                }
+
+                benchmark!(@array $( $array_dimension )?);
+
+                fn random() -> Matrix<f64> {
+                    let mut inner = vec![0.0; $dimension * $dimension];
+                    for element in inner.iter_mut() {
+                        *element = thread_rng().gen()
+                    }
+                    Matrix::new(inner)
+                }
+
+                fn bench_gemm(bench: &mut Criterion)
+                {
+                    let mut group = bench.benchmark_group(
+                        concat!( stringify!($uid), "-matrix-gemm-mutable" )
+                    );
+
+                    {
+                        let matrix_space = Space::new();
+                        let a: Matrix<f64> = random();
+                        let b: Matrix<f64> = random();
+                        let mut c: Matrix<f64> = random();
+
+                        group.bench_function("stdvec", move |bencher| {
+                            bencher.iter(|| {
+                                matrix_space.gemm_mut(Transpose::NoTrans, Transpose::NoTrans, &1.0, &a, &b, &0.0, &mut c)
+                            })
+                        });
+                    }
+
+                    bench_gemm_array(&mut group);
+
+                    {
+                        let a: nalgebra::DMatrix<f64> = nalgebra::DMatrix::new_random(DIMENSION, DIMENSION);
+                        let b: nalgebra::DMatrix<f64> = nalgebra::DMatrix::new_random(DIMENSION, DIMENSION);
+                        let mut c: nalgebra::DMatrix<f64> = nalgebra::DMatrix::new_random(DIMENSION, DIMENSION);
+
+                        group.bench_function("nalgebra", move |bencher| {
+                            bencher.iter(|| {
+                                c.gemm(1.0, &a, &b, 0.0)
+                            })
+                        });
+                    }
+
+                    {
+                        let random_ndarray = || ndarray::Array2::<f64>::from_shape_fn((DIMENSION, DIMENSION), |_| thread_rng().gen());
+                        let a: ndarray::Array2<f64> = random_ndarray();
+                        let b: ndarray::Array2<f64> = random_ndarray();
+                        let mut c: ndarray::Array2<f64> = random_ndarray();
+
+                        group.bench_function("ndarray", move |bencher| {
+                            bencher.iter(|| {
+                                ndarray::linalg::general_mat_mul(1.0, &a, &b, 0.0, &mut c)
+                            })
+                        });
+                    }
+                }
+                
+                criterion_group!(
+                    $uid,
+                    bench_gemm
+                );   
+            }
+        )+
+    };
+
+    (@array $array_dimension:expr) => {
+        mspace! {
+            ArraySpace {
+                matrix: ArrayMatrix,
+                rows: $array_dimension,
+                columns: $array_dimension,
+                layout: RowMajor,
+                using: [T; $array_dimension * $array_dimension]
+            }
+        }
+
+        fn random_array() -> ArrayMatrix<f64> {
+            let mut inner = [0.0; $array_dimension * $array_dimension];
+            for element in inner.iter_mut() {
+                *element = thread_rng().gen()
+            }
+            ArrayMatrix::new(inner)
+        }
+
+        fn bench_gemm_array(group: &mut BenchmarkGroup<'_, WallTime>)
+        {
+            let matrix_space = ArraySpace::new();
+            let a: ArrayMatrix<f64> = random_array();
+            let b: ArrayMatrix<f64> = random_array();
+            let mut c: ArrayMatrix<f64> = random_array();
+
+            group.bench_function("array", move |bencher| {
+                bencher.iter(|| {
+                    matrix_space.gemm_mut(Transpose::NoTrans, Transpose::NoTrans, &1.0, &a, &b, &0.0, &mut c)
+                })
+            });
+        }
+    };
+
+    // Matrices too large for the stack have no array variant.
+    (@array) => {
+        fn bench_gemm_array(_group: &mut BenchmarkGroup<'_, WallTime>) { }
+    };
+}
+
+benchmark!{
+    (gemm_smallmat, 8, 8),
+    (gemm_medmat, 64, 64),
+    (gemm_bigmat, 512)
+}
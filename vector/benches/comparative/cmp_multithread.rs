@@ -0,0 +1,104 @@
+macro_rules! crossover
+{
+    (
+        Lengths: $lengths:expr,
+        Benches: {
+            $(
+                $function_name:ident {
+                    GroupIdentifier: $group_name:expr,
+                    Serial: $serial:expr,
+                    Parallel: $parallel:expr
+                }
+            ),*
+        }
+    ) => {
+        use criterion::{criterion_group, BenchmarkId, Criterion};
+        use rand::{thread_rng, Rng};
+
+        #[allow(unused_imports)]
+        use vector::kernel::{self, serial};
+
+        fn random(length: usize) -> Vec<isize> {
+            let mut inner = vec![0; length];
+            for element in inner.iter_mut() {
+                *element = thread_rng().gen()
+            }
+            inner
+        }
+
+        $(
+            fn $function_name(bench: &mut Criterion)
+            {
+                let mut group = bench.benchmark_group(
+                    concat!( "multithread", $group_name )
+                );
+
+                for length in $lengths.iter() {
+                    group.bench_with_input(BenchmarkId::new("serial", length), length, |c, &length| {
+                        let mut x: Vec<isize> = random(length);
+                        let y: Vec<isize> = random(length);
+
+                        c.iter(|| {
+                            ($serial)(&mut x, &y)
+                        })
+                    });
+
+                    #[cfg(feature = "multithread")]
+                    group.bench_with_input(BenchmarkId::new("parallel", length), length, |c, &length| {
+                        let mut x: Vec<isize> = random(length);
+                        let y: Vec<isize> = random(length);
+
+                        c.iter(|| {
+                            ($parallel)(&mut x, &y)
+                        })
+                    });
+                }
+            }
+        )*
+
+        criterion_group!(
+            multithread_crossover,
+            $($function_name),*
+        );
+    };
+}
+
+crossover!{
+    Lengths: [ 1 << 10, 1 << 12, 1 << 14, 1 << 15, 1 << 16, 1 << 18, 1 << 20 ],
+    Benches: {
+        bench_addition_mut_crossover
+        {
+            GroupIdentifier: "-vector-addition-mutable",
+            Serial: |x: &mut [isize], y: &[isize]| serial::add_assign(x, y),
+            Parallel: |x: &mut [isize], y: &[isize]| kernel::parallel::add_assign(x, y)
+        },
+
+        bench_multiplication_mut_crossover
+        {
+            GroupIdentifier: "-vector-multiplication-mutable",
+            Serial: |x: &mut [isize], _: &[isize]| serial::mul_assign(x, &125),
+            Parallel: |x: &mut [isize], _: &[isize]| kernel::parallel::mul_assign(x, &125)
+        },
+
+        bench_additive_inverse_mut_crossover
+        {
+            GroupIdentifier: "-vector-additive-inverse-mutable",
+            Serial: |x: &mut [isize], _: &[isize]| serial::neg_assign::<isize>(x),
+            Parallel: |x: &mut [isize], _: &[isize]| kernel::parallel::neg_assign::<isize>(x)
+        },
+
+        bench_vaxpy_mut_crossover
+        {
+            GroupIdentifier: "-vector-vaxpy-mutable",
+            Serial: |x: &mut [isize], y: &[isize]| serial::axpy(&125, y, x),
+            Parallel: |x: &mut [isize], y: &[isize]| kernel::parallel::axpy(&125, y, x)
+        },
+
+        bench_dotv_crossover
+        {
+            GroupIdentifier: "-vector-dotv-reference",
+            Serial: |x: &mut [isize], y: &[isize]| serial::dot(x, y, 0),
+            Parallel: |x: &mut [isize], y: &[isize]| kernel::parallel::dot(x, y, || 0)
+        }
+    }
+}
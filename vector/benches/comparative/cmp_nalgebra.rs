@@ -1,5 +1,3 @@
-
-#[macro_use]
 macro_rules! benchmark
 {
     (
@@ -1,2 +1,7 @@
+#[allow(clippy::let_with_type_underscore)]
 pub mod cmp_nalgebra;
+#[allow(clippy::let_with_type_underscore)]
 pub mod cmp_ndarray;
+pub mod cmp_multithread;
+pub mod cmp_gemm;
+pub mod cmp_expr;
@@ -10,4 +10,14 @@ criterion_main!(
     comparative::cmp_ndarray::ndarray_smallvec,
     comparative::cmp_ndarray::ndarray_medvec,
     comparative::cmp_ndarray::ndarray_bigvec,
+
+    comparative::cmp_multithread::multithread_crossover,
+
+    comparative::cmp_gemm::gemm_smallmat,
+    comparative::cmp_gemm::gemm_medmat,
+    comparative::cmp_gemm::gemm_bigmat,
+
+    comparative::cmp_expr::expr_smallvec,
+    comparative::cmp_expr::expr_medvec,
+    comparative::cmp_expr::expr_bigvec,
 );
@@ -6,17 +6,53 @@ macro_rules! vector_base {
 
         impl<$T> $name<$T>
         {
+            pub const DIMENSION: usize = $length;
+
+            /// Wraps `inner`, panicking if it does not have `DIMENSION` elements.
             pub fn new(inner: $inner) -> Self 
             {   
                 assert!(inner.len() == $length);
                 $name(inner)
             }
+
+            pub fn try_new(inner: $inner) -> Result<Self, DimensionMismatch>
+            {
+                DimensionMismatch::check($length, inner.len())?;
+                Ok($name(inner))
+            }
+
+            pub fn try_from_iter<I>(iterator: I) -> Result<Self, DimensionMismatch>
+            where
+                I: IntoIterator<Item=$T>,
+                $inner: FromIterator<$T>
+            {
+                let buf: $inner = iterator
+                    .into_iter()
+                    .collect();
+                Self::try_new(buf)
+            }
         }        
 
-        impl<$T> From<$inner> for $name<$T>
+        impl<$T> TryFrom<$inner> for $name<$T>
+        {
+            type Error = DimensionMismatch;
+
+            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
+                Self::try_new(inner)
+            }
+        }
+
+        impl<'a,$T> TryFrom<&'a [$T]> for $name<$T>
+        where
+            $inner: TryFrom<&'a [$T]>
         {
-            fn from(inner: $inner) -> Self {
-                Self::new( inner )
+            type Error = DimensionMismatch;
+
+            fn try_from(slice: &'a [$T]) -> Result<Self, Self::Error> {
+                DimensionMismatch::check($length, slice.len())?;
+                let inner: $inner = <$inner>::try_from(slice)
+                    .map_err(|_| DimensionMismatch::new($length, slice.len()))?;
+                Self::try_new(inner)
             }
         }
 
@@ -61,6 +97,22 @@ macro_rules! vector_base {
             }
         }
 
+        impl<$T> AsRef<[$T]> for $name<$T>
+        {
+            fn as_ref(&self) -> &[$T]
+            {
+                &self.0[..]
+            }
+        }
+
+        impl<$T> AsMut<[$T]> for $name<$T>
+        {
+            fn as_mut(&mut self) -> &mut [$T]
+            {
+                &mut self.0[..]
+            }
+        }
+
         impl<$T> Index<usize> for $name<$T>
         {
             type Output = $T;
@@ -0,0 +1,5 @@
+mod structural;
+pub use structural::*;
+
+mod space;
+pub use space::*;
@@ -0,0 +1,76 @@
+use std::{array, marker::PhantomData, ops::{self, AddAssign, Add, MulAssign, Mul, Neg}};
+
+use algebra::*;
+
+use crate::DimensionMismatch;
+use super::ArrayVector;
+
+/// The `N`-dimensional vector space over `T`.
+/// 
+/// Unlike the spaces generated by `vspace!`, every `ArraySpace<T, N>` is the same type 
+/// wherever it is named, so downstream code can be generic over the dimension. The 
+/// checked operations always succeed, since the dimension is part of the vector type.
+pub struct ArraySpace<T, const N: usize> {
+    _phantom: PhantomData<T>
+}
+
+impl<T, const N: usize> ArraySpace<T, N>
+{
+    pub const DIMENSION: usize = N;
+
+    #[inline]
+    pub fn new() -> Self 
+    {
+        ArraySpace {
+            _phantom: PhantomData
+        }
+    }
+
+    /// The number of elements of every vector in the space, `N`.
+    #[inline]
+    pub fn dimension(&self) -> usize
+    {
+        N
+    }
+
+    /// Checks the length of an operand in debug builds or with the `strict` feature; an 
+    /// `ArrayVector<T, N>` cannot hold anything but `N` elements.
+    #[inline]
+    #[track_caller]
+    fn verify(&self, found: usize)
+    {
+        crate::kernel::verify(N, found)
+    }
+}
+
+impl<T, const N: usize> Default for ArraySpace<T, N>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> VectorSpace for ArraySpace<T, N>
+{
+    type Scalar = T;
+
+    type Vector = ArrayVector<T, N>;
+}
+
+impl<T, const N: usize> VAdditiveIdentity for ArraySpace<T, N>
+where
+    T: AdditiveIdentity<Output=T>
+{
+    type Output = ArrayVector<T, N>;
+
+    fn additive_identity(&self) -> Self::Output
+    {
+        ArrayVector(array::from_fn(|_| T::additive_identity()))
+    }
+}
+
+crate::vectorspace!(@Defaults [T, const N: usize] ArraySpace<T, N>, ArrayVector<T, N>);
+
+crate::ExteriorOps!(@VCross [T] ArraySpace<T, 3>, ArrayVector<T, 3>);
+crate::ExteriorOps!(@VCrossMut [T] ArraySpace<T, 3>, ArrayVector<T, 3>);
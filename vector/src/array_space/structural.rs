@@ -0,0 +1,136 @@
+use std::{array, convert::TryFrom, fmt, ops::{Index, IndexMut}};
+
+use crate::DimensionMismatch;
+
+/// A vector of an `ArraySpace`, backed by an array of `N` elements.
+pub struct ArrayVector<T, const N: usize>(pub(crate) [T; N]);
+
+impl<T, const N: usize> ArrayVector<T, N>
+{
+    pub const DIMENSION: usize = N;
+
+    #[inline]
+    pub fn new(inner: [T; N]) -> Self 
+    {
+        ArrayVector(inner)
+    }
+
+    pub fn try_from_iter<I>(iterator: I) -> Result<Self, DimensionMismatch>
+    where
+        I: IntoIterator<Item=T>
+    {
+        let buf: Vec<T> = iterator
+            .into_iter()
+            .collect();
+        let found: usize = buf.len();
+        <[T; N]>::try_from(buf)
+            .map(ArrayVector)
+            .map_err(|_| DimensionMismatch::new(N, found))
+    }
+
+    pub fn into_inner(self) -> [T; N]
+    {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Clone for ArrayVector<T, N>
+where
+    T: Clone
+{
+    fn clone(&self) -> Self
+    {
+        ArrayVector(self.0.clone())
+    }
+}
+
+impl<T, const N: usize> Copy for ArrayVector<T, N>
+where
+    T: Copy
+{ }
+
+impl<T, const N: usize> From<[T; N]> for ArrayVector<T, N>
+{
+    fn from(inner: [T; N]) -> Self {
+        Self::new( inner )
+    }
+}
+
+impl<'a, T, const N: usize> TryFrom<&'a [T]> for ArrayVector<T, N>
+where
+    T: Copy
+{
+    type Error = DimensionMismatch;
+
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        <[T; N]>::try_from(slice)
+            .map(ArrayVector)
+            .map_err(|_| DimensionMismatch::new(N, slice.len()))
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVector<T, N>
+{
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a,T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.0.iter()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayVector<T, N>
+{
+    type Item = T;
+    type IntoIter = array::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        IntoIterator::into_iter(self.0)
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for ArrayVector<T, N>
+{
+    fn as_ref(&self) -> &[T]
+    {
+        &self.0[..]
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for ArrayVector<T, N>
+{
+    fn as_mut(&mut self) -> &mut [T]
+    {
+        &mut self.0[..]
+    }
+}
+
+impl<T, const N: usize> Index<usize> for ArrayVector<T, N>
+{
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &Self::Output 
+    {
+        &self.0[idx]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for ArrayVector<T, N>
+{
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output 
+    {
+        &mut self.0[idx]
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for ArrayVector<T, N>
+where
+    T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{:?}", self.0)
+    }
+}
@@ -0,0 +1,2 @@
+mod structural;
+mod space;
@@ -0,0 +1,211 @@
+#[macro_export]
+macro_rules! bitpacked_space {
+    ($length:expr, $name:ident, $space:ident) => {
+        pub struct $space;
+
+        impl $space
+        {
+            #[inline]
+            pub fn new() -> Self 
+            {
+                $space
+            }
+        }
+
+        impl VectorSpace for $space
+        {
+            type Scalar = GF2;
+
+            type Vector = $name;
+        }
+
+        impl VPartialEq for $space
+        {
+            type Vector = $name;
+
+            fn eq(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> bool
+            {
+                lhs.0 == rhs.0
+            }
+        }
+
+        impl VAdditiveIdentity for $space
+        {
+            type Output = $name;
+
+            fn additive_identity(&self) -> Self::Output
+            {
+                $name::zeros()
+            }
+        }
+
+        impl VAdd for $space
+        {
+            type Vector = $name;
+
+            fn vadd(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector
+            {
+                let mut buf: Self::Vector = lhs.clone();
+                self.vadd_mut(&mut buf, rhs);
+                buf
+            }
+        }
+
+        impl VAddMut for $space
+        {
+            type Vector = $name;
+
+            fn vadd_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector)
+            {
+                $crate::kernel::xor_assign(&mut lhs.0[..], &rhs.0[..]);
+            }
+        }
+
+        impl CheckedVAdd for $space
+        {
+            type Vector = $name;
+
+            type Error = DimensionMismatch;
+
+            fn checked_vadd(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Result<Self::Vector, Self::Error>
+            {
+                let mut buf: Self::Vector = lhs.clone();
+                self.checked_vadd_mut(&mut buf, rhs)?;
+                Ok(buf)
+            }
+        }
+
+        impl CheckedVAddMut for $space
+        {
+            type Vector = $name;
+
+            type Error = DimensionMismatch;
+
+            fn checked_vadd_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector) -> Result<(), Self::Error>
+            {
+                DimensionMismatch::check($name::WORDS, lhs.0.len())?;
+                DimensionMismatch::check($name::WORDS, rhs.0.len())?;
+                $crate::kernel::xor_assign(&mut lhs.0[..], &rhs.0[..]);
+                Ok(())
+            }
+        }
+
+        impl VScale for $space
+        {
+            type Vector = $name;
+
+            type Scalar = GF2;
+
+            fn vscale(&self, vector: &Self::Vector, scalar: &Self::Scalar) -> Self::Vector
+            {
+                let mut buf: Self::Vector = vector.clone();
+                self.vscale_mut(&mut buf, scalar);
+                buf
+            }
+        }
+
+        impl VScaleMut for $space
+        {
+            type Vector = $name;
+
+            type Scalar = GF2;
+
+            fn vscale_mut(&self, vector: &mut Self::Vector, scalar: &Self::Scalar)
+            {
+                if scalar.value() == 0 {
+                    vector.0.iter_mut().for_each(|word| *word = 0);
+                }
+            }
+        }
+
+        /// Every vector is its own additive inverse.
+        impl VAdditiveInverse for $space
+        {
+            type Vector = $name;
+
+            fn additive_inv(&self, vector: &Self::Vector) -> Self::Vector
+            {
+                vector.clone()
+            }
+        }
+
+        impl VAdditiveInverseMut for $space
+        {
+            type Vector = $name;
+
+            fn additive_inv_mut(&self, _vector: &mut Self::Vector) { }
+        }
+
+        impl VAXPY for $space
+        {
+            type Vector = $name;
+
+            type Scalar = GF2;
+
+            fn vaxpy(&self, alpha: &Self::Scalar, x: &Self::Vector, y: &Self::Vector) -> Self::Vector
+            {
+                let mut output: Self::Vector = y.clone();
+                self.vaxpy_mut(alpha, x, &mut output);
+                output
+            }
+        }
+
+        impl VAXPYMut for $space
+        {
+            type Vector = $name;
+
+            type Scalar = GF2;
+
+            fn vaxpy_mut(&self, alpha: &Self::Scalar, x: &Self::Vector, y: &mut Self::Vector)
+            {
+                if alpha.value() == 1 {
+                    $crate::kernel::xor_assign(&mut y.0[..], &x.0[..]);
+                }
+            }
+        }
+
+        impl DotV for $space
+        {
+            type Vector = $name;
+
+            type Scalar = GF2;
+
+            fn dotv(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar
+            {
+                GF2::from($crate::kernel::and_parity(&x.0[..], &y.0[..]))
+            }
+        }
+
+        impl DotVMut for $space
+        {
+            type Vector = $name;
+
+            type Scalar = GF2;
+
+            fn dotv_mut(&self, x: &Self::Vector, y: &Self::Vector, output: &mut Self::Scalar)
+            {
+                *output += self.dotv(x, y);
+            }
+        }
+
+        impl SwapV for $space
+        {
+            type Vector = $name;
+
+            fn swapv(&self, x: &mut Self::Vector, y: &mut Self::Vector)
+            {
+                x.0[..].swap_with_slice(&mut y.0[..]);
+            }
+        }
+
+        impl CopyV for $space
+        {
+            type Vector = $name;
+
+            fn copyv(&self, x: &Self::Vector, y: &mut Self::Vector)
+            {
+                y.0[..].copy_from_slice(&x.0[..]);
+            }
+        }
+    };
+}
@@ -0,0 +1,101 @@
+#[macro_export]
+macro_rules! bitpacked_base {
+    ($length:expr, $name:ident) => {
+        /// A vector over `GF(2)` with its elements packed 64 to a `u64` word.
+        /// 
+        /// The bits past `DIMENSION` in the last word are always clear.
+        #[derive(Clone, PartialEq, Eq, Hash)]
+        pub struct $name(Vec<u64>);
+
+        impl $name
+        {
+            pub const DIMENSION: usize = $length;
+
+            pub const WORDS: usize = $crate::kernel::words($length);
+
+            /// The zero vector.
+            pub fn zeros() -> Self
+            {
+                $name(vec![0; Self::WORDS])
+            }
+
+            /// Wraps `words`, panicking if there are not `WORDS` of them or a bit past 
+            /// `DIMENSION` is set.
+            pub fn from_words(words: Vec<u64>) -> Self
+            {
+                assert!(words.len() == Self::WORDS);
+                assert!(words.last().map_or(true, |w| w & !$crate::kernel::tail_mask($length) == 0));
+                $name(words)
+            }
+
+            pub fn try_from_iter<I>(iterator: I) -> Result<Self, DimensionMismatch>
+            where
+                I: IntoIterator<Item=GF2>
+            {
+                let mut buf: Self = Self::zeros();
+                let mut found: usize = 0;
+                for element in iterator {
+                    if found < $length {
+                        buf.set(found, element);
+                    }
+                    found += 1;
+                }
+                DimensionMismatch::check($length, found)?;
+                Ok(buf)
+            }
+
+            /// The packed words, least significant bit first.
+            pub fn words(&self) -> &[u64]
+            {
+                &self.0[..]
+            }
+
+            pub fn get(&self, idx: usize) -> GF2
+            {
+                assert!(idx < $length, "index {} out of range for dimension {}", idx, $length);
+                GF2::from($crate::kernel::get_bit(&self.0[..], idx))
+            }
+
+            pub fn set(&mut self, idx: usize, value: GF2)
+            {
+                assert!(idx < $length, "index {} out of range for dimension {}", idx, $length);
+                $crate::kernel::set_bit(&mut self.0[..], idx, value.value() == 1);
+            }
+
+            pub fn iter(&self) -> impl Iterator<Item=GF2> + '_
+            {
+                (0..$length).map(move |idx| self.get(idx))
+            }
+        }
+
+        impl FromIterator<GF2> for $name
+        {
+            fn from_iter<I>(iterator: I) -> Self
+            where
+                I: IntoIterator<Item=GF2>
+            {
+                Self::try_from_iter(iterator)
+                    .unwrap_or_else(|error| panic!("{}", error))
+            }
+        }
+
+        impl<'a> TryFrom<&'a [GF2]> for $name
+        {
+            type Error = DimensionMismatch;
+
+            fn try_from(slice: &'a [GF2]) -> Result<Self, Self::Error> {
+                Self::try_from_iter(slice.iter().copied())
+            }
+        }
+
+        impl Debug for $name
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+            {
+                f.debug_list()
+                    .entries(self.iter().map(|element| element.value()))
+                    .finish()
+            }
+        }
+    };
+}
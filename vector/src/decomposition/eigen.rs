@@ -0,0 +1,108 @@
+use std::{cmp::Ordering, iter::FromIterator};
+
+use algebra::EigenOrder;
+use num_traits::Float;
+
+use crate::{kernel, Dense, NoConvergence};
+
+/// The eigendecomposition `A = V * diag(eigenvalues) * V^T` of a real symmetric matrix.
+/// 
+/// The eigenvalues are collected into a `V`, such as a vector from `vspace!`, and the 
+/// orthonormal eigenvectors are the columns of a matrix of the same type as `A`, in the 
+/// same order. Only the lower triangle of `A` is read.
+#[derive(Clone, Debug)]
+pub struct SymmetricEigen<M, V> {
+    eigenvalues: V,
+    eigenvectors: M
+}
+
+impl<M, V> SymmetricEigen<M, V>
+{
+    /// Reduces `a` to tridiagonal form and iterates with the implicit QL algorithm.
+    pub fn new<T>(a: M, order: EigenOrder) -> Result<Self, NoConvergence>
+    where
+        M: Dense + AsRef<[T]> + AsMut<[T]> + Clone,
+        V: FromIterator<T>,
+        T: Float
+    {
+        let n: usize = Self::dimension(&a);
+        let mut z: M = a;
+        let mut d: Vec<T> = vec![T::zero(); n];
+        let mut e: Vec<T> = vec![T::zero(); n];
+        kernel::sytrd::<T>(z.layout(), n, z.as_mut(), &mut d, &mut e);
+        kernel::steqr::<T>(z.layout(), n, &mut d, &mut e, z.as_mut())?;
+        Ok(Self::sorted(d, z, order))
+    }
+
+    /// Iterates with the cyclic Jacobi method, which computes small eigenvalues to high 
+    /// relative accuracy at a higher cost, and so suits small matrices.
+    pub fn new_jacobi<T>(mut a: M, order: EigenOrder) -> Result<Self, NoConvergence>
+    where
+        M: Dense + AsRef<[T]> + AsMut<[T]> + Clone,
+        V: FromIterator<T>,
+        T: Float
+    {
+        let n: usize = Self::dimension(&a);
+        let mut v: M = a.clone();
+        let mut w: Vec<T> = vec![T::zero(); n];
+        kernel::syevj::<T>(a.layout(), n, a.as_mut(), &mut w, v.as_mut())?;
+        Ok(Self::sorted(w, v, order))
+    }
+
+    fn dimension(a: &M) -> usize
+    where
+        M: Dense
+    {
+        let (n, columns) = a.shape();
+        assert!(n == columns, "a symmetric eigendecomposition needs a square matrix, not {}x{}", n, columns);
+        n
+    }
+
+    /// Permutes the columns of `vectors` into the order of `values`.
+    fn sorted<T>(values: Vec<T>, vectors: M, order: EigenOrder) -> Self
+    where
+        M: Dense + AsRef<[T]> + AsMut<[T]> + Clone,
+        V: FromIterator<T>,
+        T: Float
+    {
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.sort_by(|&i, &j| {
+            let ordering: Ordering = values[i].partial_cmp(&values[j]).unwrap_or(Ordering::Equal);
+            match order {
+                EigenOrder::Ascending => ordering,
+                EigenOrder::Descending => ordering.reverse()
+            }
+        });
+
+        let (rs, cs) = vectors.layout().strides(vectors.shape());
+        let mut eigenvectors: M = vectors.clone();
+        for (j, &p) in indices.iter().enumerate() {
+            for i in 0..values.len() {
+                eigenvectors.as_mut()[i * rs + j * cs] = vectors.as_ref()[i * rs + p * cs];
+            }
+        }
+        SymmetricEigen {
+            eigenvalues: indices.iter().map(|&i| values[i]).collect(),
+            eigenvectors
+        }
+    }
+
+    /// The eigenvalues in the requested order.
+    pub fn eigenvalues(&self) -> &V
+    {
+        &self.eigenvalues
+    }
+
+    /// The orthonormal eigenvectors as columns, where column `j` belongs to 
+    /// `eigenvalues()[j]`.
+    pub fn eigenvectors(&self) -> &M
+    {
+        &self.eigenvectors
+    }
+
+    /// The eigenvalues and eigenvectors.
+    pub fn into_parts(self) -> (V, M)
+    {
+        (self.eigenvalues, self.eigenvectors)
+    }
+}
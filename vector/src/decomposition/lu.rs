@@ -0,0 +1,181 @@
+use std::marker::PhantomData;
+
+use algebra::{Field, Magnitude};
+
+use crate::{kernel, Dense, Singular};
+
+/// The LU decomposition of a square matrix with partial pivoting, `P * A = L * U`.
+/// 
+/// The unit lower triangular `L` and upper triangular `U` share the storage of a single 
+/// matrix of the same type as `A`, and `P` is kept as the row swaps that produced it.
+#[derive(Clone, Debug)]
+pub struct LU<M, T> {
+    factors: M,
+    pivots: Vec<usize>,
+    odd: bool,
+    _phantom: PhantomData<T>
+}
+
+impl<M, T> LU<M, T>
+where
+    M: Dense + AsRef<[T]> + AsMut<[T]>,
+    T: Field
+{
+    /// Factors `a`, failing if it is singular.
+    pub fn new(mut a: M) -> Result<Self, Singular>
+    where
+        T: Magnitude
+    {
+        let (n, columns) = a.shape();
+        assert!(n == columns, "the LU decomposition needs a square matrix, not {}x{}", n, columns);
+
+        let mut pivots: Vec<usize> = vec![0; n];
+        let odd: bool = kernel::getrf::<T>(a.layout(), n, a.as_mut(), &mut pivots)?;
+        Ok(LU {
+            factors: a,
+            pivots,
+            odd,
+            _phantom: PhantomData
+        })
+    }
+
+    /// `det(a)`, which is zero (or NaN) rather than an error when `a` is singular.
+    pub fn determinant_of(mut a: M) -> T
+    where
+        T: Magnitude
+    {
+        let (n, columns) = a.shape();
+        assert!(n == columns, "the determinant needs a square matrix, not {}x{}", n, columns);
+
+        let mut pivots: Vec<usize> = vec![0; n];
+        // A failed factorization leaves an exact zero or NaN on the diagonal, so the sign 
+        // of the permutation no longer matters.
+        let odd: bool = kernel::getrf::<T>(a.layout(), n, a.as_mut(), &mut pivots).unwrap_or(false);
+        kernel::getdet::<T>(a.layout(), n, a.as_ref(), odd)
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize
+    {
+        self.factors.shape().0
+    }
+
+    #[inline]
+    fn offset(&self, row: usize, column: usize) -> usize
+    {
+        let (rs, cs) = self.factors.layout().strides(self.factors.shape());
+        row * rs + column * cs
+    }
+
+    /// The unit lower triangular factor `L`.
+    pub fn l(&self) -> M
+    where
+        M: Clone
+    {
+        let mut l: M = self.factors.clone();
+        for i in 0..self.dimension() {
+            for j in i..self.dimension() {
+                let offset: usize = self.offset(i, j);
+                l.as_mut()[offset] = if i == j { T::multiplicative_identity() } else { T::additive_identity() };
+            }
+        }
+        l
+    }
+
+    /// The upper triangular factor `U`.
+    pub fn u(&self) -> M
+    where
+        M: Clone
+    {
+        let mut u: M = self.factors.clone();
+        for i in 0..self.dimension() {
+            for j in 0..i {
+                let offset: usize = self.offset(i, j);
+                u.as_mut()[offset] = T::additive_identity();
+            }
+        }
+        u
+    }
+
+    /// The row swaps making up `P`: row `k` was swapped with row `pivots()[k]` at step `k`.
+    pub fn pivots(&self) -> &[usize]
+    {
+        &self.pivots
+    }
+
+    /// `P` as a permutation, where row `i` of `P * A` is row `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> Vec<usize>
+    {
+        let mut permutation: Vec<usize> = (0..self.dimension()).collect();
+        for (k, &p) in self.pivots.iter().enumerate() {
+            permutation.swap(k, p);
+        }
+        permutation
+    }
+
+    /// `det(A)`, the product of the diagonal of `U` with the sign of `P`.
+    pub fn determinant(&self) -> T
+    {
+        kernel::getdet::<T>(self.factors.layout(), self.dimension(), self.factors.as_ref(), self.odd)
+    }
+
+    /// The condition number of `A` in the 1-norm, `|A| * |A^-1|`, given the matrix `a` that 
+    /// was factored. Tiny pivots do not make the factorization fail, so this is how to tell 
+    /// whether a solution can be trusted.
+    pub fn condition(&self, a: &M) -> T
+    where
+        M: Clone,
+        T: Magnitude<Output=T> + PartialOrd
+    {
+        self.norm1(a) * self.norm1(&self.inverse())
+    }
+
+    fn norm1(&self, a: &M) -> T
+    where
+        T: Magnitude<Output=T> + PartialOrd
+    {
+        let n: usize = self.dimension();
+        (0..n)
+            .map(|j| (0..n).fold(T::additive_identity(), |acc, i| acc + a.as_ref()[self.offset(i, j)].magnitude()))
+            .fold(T::additive_identity(), |max, column| if column > max { column } else { max })
+    }
+
+    /// Solves `A * x = b`, overwriting `b` with `x`.
+    pub fn solve_mut<X>(&self, b: &mut X)
+    where
+        X: AsMut<[T]> + ?Sized
+    {
+        kernel::getrs::<T>(self.factors.layout(), self.dimension(), self.factors.as_ref(), &self.pivots, b.as_mut());
+    }
+
+    /// Solves `A * x = b`.
+    pub fn solve<X>(&self, b: &X) -> X
+    where
+        X: Clone + AsMut<[T]>
+    {
+        let mut x: X = b.clone();
+        self.solve_mut(&mut x);
+        x
+    }
+
+    /// `A^-1`, solved one column of the identity at a time.
+    pub fn inverse(&self) -> M
+    where
+        M: Clone
+    {
+        let n: usize = self.dimension();
+        let mut inverse: M = self.factors.clone();
+        let mut column: Vec<T> = vec![T::additive_identity(); n];
+        for j in 0..n {
+            for (i, c) in column.iter_mut().enumerate() {
+                *c = if i == j { T::multiplicative_identity() } else { T::additive_identity() };
+            }
+            self.solve_mut(&mut column);
+            for (i, c) in column.iter().enumerate() {
+                let offset: usize = self.offset(i, j);
+                inverse.as_mut()[offset] = c.clone();
+            }
+        }
+        inverse
+    }
+}
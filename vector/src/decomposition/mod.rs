@@ -0,0 +1,14 @@
+mod lu;
+pub use lu::*;
+
+mod qr;
+pub use qr::*;
+
+mod symmetric;
+pub use symmetric::*;
+
+mod eigen;
+pub use eigen::*;
+
+mod schur;
+pub use schur::*;
@@ -0,0 +1,155 @@
+use std::{cmp::Ordering, iter::FromIterator};
+
+use num_traits::Float;
+
+use crate::{kernel, Dense, Singular};
+
+/// The Householder QR decomposition `A = Q * R`, or `A * P = Q * R` with column pivoting, 
+/// of a matrix with any shape.
+/// 
+/// `R` and the reflectors whose product is `Q` share the storage of a single matrix of 
+/// the same type as `A`, in LAPACK's compact form. `Q` is applied through `q_mul_mut` and 
+/// `qt_mul_mut` rather than formed.
+#[derive(Clone, Debug)]
+pub struct QR<M, T> {
+    factors: M,
+    tau: Vec<T>,
+    permutation: Option<Vec<usize>>
+}
+
+impl<M, T> QR<M, T>
+where
+    M: Dense + AsRef<[T]> + AsMut<[T]>,
+    T: Float
+{
+    /// Factors `a` without pivoting.
+    pub fn new(a: M) -> Self
+    {
+        Self::factor(a, false)
+    }
+
+    /// Factors `a` with column pivoting, which orders the diagonal of `R` by decreasing 
+    /// magnitude so that the decomposition reveals the rank of `a`.
+    pub fn new_pivoted(a: M) -> Self
+    {
+        Self::factor(a, true)
+    }
+
+    fn factor(mut a: M, pivoting: bool) -> Self
+    {
+        let (rows, columns) = a.shape();
+        let mut tau: Vec<T> = vec![T::zero(); rows.min(columns)];
+        let mut permutation: Option<Vec<usize>> = if pivoting { Some(vec![0; columns]) } else { None };
+        kernel::geqrf::<T>(a.layout(), (rows, columns), a.as_mut(), &mut tau, permutation.as_deref_mut());
+        QR {
+            factors: a,
+            tau,
+            permutation
+        }
+    }
+
+    #[inline]
+    fn offset(&self, row: usize, column: usize) -> usize
+    {
+        let (rs, cs) = self.factors.layout().strides(self.factors.shape());
+        row * rs + column * cs
+    }
+
+    /// The upper triangular (or trapezoidal) factor `R`, with the shape of `A`.
+    pub fn r(&self) -> M
+    where
+        M: Clone
+    {
+        let (rows, columns) = self.factors.shape();
+        let mut r: M = self.factors.clone();
+        for i in 0..rows {
+            for j in 0..i.min(columns) {
+                let offset: usize = self.offset(i, j);
+                r.as_mut()[offset] = T::zero();
+            }
+        }
+        r
+    }
+
+    /// The column permutation `P` of a pivoted decomposition, where column `j` of `A * P` 
+    /// is column `permutation()[j]` of `A`.
+    pub fn permutation(&self) -> Option<&[usize]>
+    {
+        self.permutation.as_deref()
+    }
+
+    /// `x = Q * x`, where `x` has as many elements as `A` has rows.
+    pub fn q_mul_mut<X>(&self, x: &mut X)
+    where
+        X: AsMut<[T]> + ?Sized
+    {
+        kernel::ormqr::<T>(self.factors.layout(), self.factors.shape(), self.factors.as_ref(), &self.tau, x.as_mut(), false);
+    }
+
+    /// `x = Q^T * x`, where `x` has as many elements as `A` has rows.
+    pub fn qt_mul_mut<X>(&self, x: &mut X)
+    where
+        X: AsMut<[T]> + ?Sized
+    {
+        kernel::ormqr::<T>(self.factors.layout(), self.factors.shape(), self.factors.as_ref(), &self.tau, x.as_mut(), true);
+    }
+
+    /// `|R[k][k]|`.
+    #[inline]
+    fn diagonal(&self, k: usize) -> T
+    {
+        self.factors.as_ref()[self.offset(k, k)].abs()
+    }
+
+    /// The threshold at or below which a diagonal element of `R` counts as zero, 
+    /// `epsilon * max(rows, columns) * max |R[k][k]|`.
+    fn tolerance(&self) -> T
+    {
+        let (rows, columns) = self.factors.shape();
+        let largest: T = (0..self.tau.len()).fold(T::zero(), |acc, k| acc.max(self.diagonal(k)));
+        T::epsilon() * T::from(rows.max(columns)).unwrap() * largest
+    }
+
+    /// The numerical rank of `A`: the number of diagonal elements of `R` above the 
+    /// tolerance, which is only meaningful for a pivoted decomposition.
+    pub fn rank(&self) -> usize
+    {
+        let tolerance: T = self.tolerance();
+        (0..self.tau.len())
+            .take_while(|&k| self.diagonal(k) > tolerance)
+            .count()
+    }
+
+    /// The `x` minimising `||A * x - b||_2` for `A` with at least as many rows as columns, 
+    /// failing at the first diagonal element of `R` below the tolerance.
+    pub fn solve<X, Y>(&self, b: &X) -> Result<Y, Singular>
+    where
+        X: AsRef<[T]> + ?Sized,
+        Y: FromIterator<T>
+    {
+        let (rows, columns) = self.factors.shape();
+        assert!(rows >= columns, "a least-squares solve needs at least as many rows as columns, not {}x{}", rows, columns);
+
+        let tolerance: T = self.tolerance();
+        let singular = |k: &usize| self.diagonal(*k).partial_cmp(&tolerance) != Some(Ordering::Greater);
+        if let Some(pivot) = (0..columns).find(singular) {
+            return Err(Singular { pivot })
+        }
+
+        let mut z: Vec<T> = b.as_ref().to_vec();
+        self.qt_mul_mut(&mut z[..]);
+        kernel::trsv_upper::<T>(self.factors.layout(), (rows, columns), self.factors.as_ref(), columns, &mut z);
+
+        let x: Vec<T> = match &self.permutation {
+            Some(permutation) => {
+                let mut x: Vec<T> = vec![T::zero(); columns];
+                for (j, &p) in permutation.iter().enumerate() {
+                    x[p] = z[j];
+                }
+                x
+            },
+            None => z.into_iter().take(columns).collect()
+        };
+        Ok(x.into_iter().collect())
+    }
+}
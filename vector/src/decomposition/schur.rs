@@ -0,0 +1,215 @@
+use std::{iter::FromIterator, marker::PhantomData};
+
+use algebra::{Complex, Magnitude};
+use num_traits::Float;
+
+use crate::{kernel, Dense, NoConvergence};
+
+/// Checks that `a` is square and returns its dimension.
+fn dimension<M: Dense>(a: &M, name: &str) -> usize
+{
+    let (n, columns) = a.shape();
+    assert!(n == columns, "the {} needs a square matrix, not {}x{}", name, n, columns);
+    n
+}
+
+/// The Hessenberg decomposition `A = Q * H * Q^T` of a square matrix, where `H` is zero 
+/// below its subdiagonal and `Q` is orthogonal.
+#[derive(Clone, Debug)]
+pub struct Hessenberg<M, T> {
+    h: M,
+    q: M,
+    _phantom: PhantomData<T>
+}
+
+impl<M, T> Hessenberg<M, T>
+where
+    M: Dense + AsRef<[T]> + AsMut<[T]> + Clone,
+    T: Float
+{
+    /// Reduces `a` with Householder reflections.
+    pub fn new(mut a: M) -> Self
+    {
+        let n: usize = dimension(&a, "Hessenberg decomposition");
+        let mut q: M = a.clone();
+        kernel::gehrd::<T>(a.layout(), n, a.as_mut(), q.as_mut());
+        Hessenberg {
+            h: a,
+            q,
+            _phantom: PhantomData
+        }
+    }
+
+    /// The upper Hessenberg factor `H`.
+    pub fn h(&self) -> &M
+    {
+        &self.h
+    }
+
+    /// The orthogonal factor `Q`.
+    pub fn q(&self) -> &M
+    {
+        &self.q
+    }
+
+    /// `H` and `Q`.
+    pub fn into_parts(self) -> (M, M)
+    {
+        (self.h, self.q)
+    }
+}
+
+/// The real Schur decomposition `A = Z * T * Z^T` of a square matrix, where `Z` is 
+/// orthogonal and `T` is quasi upper triangular, with a 1x1 diagonal block for each real 
+/// eigenvalue and a 2x2 block for each complex conjugate pair.
+#[derive(Clone, Debug)]
+pub struct Schur<M, T> {
+    t: M,
+    z: M,
+    wr: Vec<T>,
+    wi: Vec<T>
+}
+
+impl<M, T> Schur<M, T>
+where
+    M: Dense + AsRef<[T]> + AsMut<[T]> + Clone,
+    T: Float
+{
+    /// Reduces `a` to Hessenberg form and iterates with the Francis double-shift QR 
+    /// algorithm, failing if an eigenvalue does not converge.
+    pub fn new(a: M) -> Result<Self, NoConvergence>
+    {
+        let n: usize = dimension(&a, "Schur decomposition");
+        let (mut t, mut z) = Hessenberg::<M, T>::new(a).into_parts();
+        let mut wr: Vec<T> = vec![T::zero(); n];
+        let mut wi: Vec<T> = vec![T::zero(); n];
+        kernel::hseqr::<T>(t.layout(), n, t.as_mut(), z.as_mut(), &mut wr, &mut wi)?;
+        Ok(Schur {
+            t,
+            z,
+            wr,
+            wi
+        })
+    }
+
+    /// The quasi upper triangular factor `T`.
+    pub fn t(&self) -> &M
+    {
+        &self.t
+    }
+
+    /// The orthogonal factor `Z`, whose columns are the Schur vectors.
+    pub fn z(&self) -> &M
+    {
+        &self.z
+    }
+
+    /// The eigenvalues in the order of the diagonal blocks of `T`, where each complex 
+    /// conjugate pair has the positive imaginary part first.
+    pub fn eigenvalues<V>(&self) -> V
+    where
+        V: FromIterator<Complex<T>>
+    {
+        self.wr
+            .iter()
+            .zip(&self.wi)
+            .map(|(&re, &im)| Complex::new(re, im))
+            .collect()
+    }
+}
+
+/// The eigendecomposition `A * X = X * diag(eigenvalues)` of a real square matrix, whose 
+/// eigenvalues and eigenvectors may be complex.
+/// 
+/// The eigenvalues are collected into a `V`, such as a vector from `vspace!` over 
+/// `Complex<T>`, in the order given by `Schur::eigenvalues`, and the eigenvectors are the 
+/// columns of a complex matrix `C` in the same order, each with unit norm and its largest 
+/// element real and positive.
+#[derive(Clone, Debug)]
+pub struct Eigen<C, V> {
+    eigenvalues: V,
+    eigenvectors: C
+}
+
+impl<C, V> Eigen<C, V>
+{
+    /// Computes the real Schur form of `a` and solves for the eigenvectors by back 
+    /// substitution.
+    pub fn new<M, T>(a: M) -> Result<Self, NoConvergence>
+    where
+        M: Dense + AsRef<[T]> + AsMut<[T]> + Clone,
+        C: Dense + AsMut<[Complex<T>]> + FromIterator<Complex<T>>,
+        V: FromIterator<Complex<T>>,
+        T: Float + Magnitude
+    {
+        let n: usize = dimension(&a, "eigendecomposition");
+        let schur: Schur<M, T> = Schur::new(a)?;
+        let (mut t, mut x) = (schur.t.clone(), schur.z.clone());
+        kernel::trevc::<T>(x.layout(), n, t.as_mut(), x.as_mut(), &schur.wr, &schur.wi);
+
+        let (rs, cs) = x.layout().strides((n, n));
+        let real = |i: usize, j: usize| x.as_ref()[i * rs + j * cs];
+        let mut eigenvectors: C = vec![Complex::new(T::zero(), T::zero()); n * n].into_iter().collect();
+        let (crs, ccs) = eigenvectors.layout().strides((n, n));
+
+        let mut column: Vec<Complex<T>> = vec![Complex::new(T::zero(), T::zero()); n];
+        for j in 0..n {
+            let im: T = schur.wi[j];
+            for (i, c) in column.iter_mut().enumerate() {
+                *c = if im > T::zero() {
+                    Complex::new(real(i, j), real(i, j + 1))
+                } else if im < T::zero() {
+                    Complex::new(real(i, j - 1), -real(i, j))
+                } else {
+                    Complex::new(real(i, j), T::zero())
+                };
+            }
+            normalise(&mut column);
+            for (i, c) in column.iter().enumerate() {
+                eigenvectors.as_mut()[i * crs + j * ccs] = *c;
+            }
+        }
+
+        Ok(Eigen {
+            eigenvalues: schur.eigenvalues(),
+            eigenvectors
+        })
+    }
+
+    /// The eigenvalues.
+    pub fn eigenvalues(&self) -> &V
+    {
+        &self.eigenvalues
+    }
+
+    /// The eigenvectors as columns, where column `j` belongs to `eigenvalues()[j]`.
+    pub fn eigenvectors(&self) -> &C
+    {
+        &self.eigenvectors
+    }
+
+    /// The eigenvalues and eigenvectors.
+    pub fn into_parts(self) -> (V, C)
+    {
+        (self.eigenvalues, self.eigenvectors)
+    }
+}
+
+/// Scales `x` to unit norm with its element of largest modulus real and positive.
+fn normalise<T: Float>(x: &mut [Complex<T>])
+{
+    let largest: Complex<T> = x
+        .iter()
+        .fold(Complex::new(T::zero(), T::zero()), |max, c| if c.re.hypot(c.im) > max.re.hypot(max.im) { *c } else { max });
+    let modulus: T = largest.re.hypot(largest.im);
+    if modulus.is_zero() {
+        return
+    }
+
+    // Dividing by `largest / |largest|` rotates it onto the positive real axis.
+    let (ur, ui) = (largest.re / modulus, largest.im / modulus);
+    let norm: T = x.iter().fold(T::zero(), |acc, c| acc.hypot(c.re.hypot(c.im)));
+    for c in x.iter_mut() {
+        *c = Complex::new((c.re * ur + c.im * ui) / norm, (c.im * ur - c.re * ui) / norm);
+    }
+}
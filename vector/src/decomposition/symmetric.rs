@@ -0,0 +1,301 @@
+use std::{cmp::Ordering, marker::PhantomData};
+
+use num_traits::Float;
+
+use crate::{kernel, kernel::Pivot, Dense, NotPositiveDefinite, Singular};
+
+/// The Cholesky decomposition `A = L * L^T` of a symmetric positive definite matrix.
+/// 
+/// Only the lower triangle of `A` is read, and `L` overwrites it in a matrix of the same 
+/// type as `A`.
+#[derive(Clone, Debug)]
+pub struct Cholesky<M, T> {
+    factor: M,
+    _phantom: PhantomData<T>
+}
+
+impl<M, T> Cholesky<M, T>
+where
+    M: Dense + AsRef<[T]> + AsMut<[T]>,
+    T: Float
+{
+    /// Factors `a`, failing at the first pivot that is not positive.
+    pub fn new(mut a: M) -> Result<Self, NotPositiveDefinite>
+    {
+        let (n, columns) = a.shape();
+        assert!(n == columns, "the Cholesky decomposition needs a square matrix, not {}x{}", n, columns);
+
+        kernel::potrf::<T>(a.layout(), n, a.as_mut())?;
+        Ok(Cholesky {
+            factor: a,
+            _phantom: PhantomData
+        })
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize
+    {
+        self.factor.shape().0
+    }
+
+    #[inline]
+    fn offset(&self, row: usize, column: usize) -> usize
+    {
+        let (rs, cs) = self.factor.layout().strides(self.factor.shape());
+        row * rs + column * cs
+    }
+
+    /// The lower triangular factor `L`, with a positive diagonal.
+    pub fn l(&self) -> M
+    where
+        M: Clone
+    {
+        let mut l: M = self.factor.clone();
+        for i in 0..self.dimension() {
+            for j in (i + 1)..self.dimension() {
+                let offset: usize = self.offset(i, j);
+                l.as_mut()[offset] = T::zero();
+            }
+        }
+        l
+    }
+
+    /// `det(A)`, the square of the product of the diagonal of `L`.
+    pub fn determinant(&self) -> T
+    {
+        let product: T = (0..self.dimension())
+            .map(|i| self.factor.as_ref()[self.offset(i, i)])
+            .fold(T::one(), |acc, l| acc * l);
+        product * product
+    }
+
+    /// Solves `A * x = b`, overwriting `b` with `x`.
+    pub fn solve_mut<X>(&self, b: &mut X)
+    where
+        X: AsMut<[T]> + ?Sized
+    {
+        kernel::potrs::<T>(self.factor.layout(), self.dimension(), self.factor.as_ref(), b.as_mut());
+    }
+
+    /// Solves `A * x = b`.
+    pub fn solve<X>(&self, b: &X) -> X
+    where
+        X: Clone + AsMut<[T]>
+    {
+        let mut x: X = b.clone();
+        self.solve_mut(&mut x);
+        x
+    }
+}
+
+/// The Bunch–Kaufman decomposition `P * A * P^T = L * D * L^T` of a symmetric matrix, 
+/// which need not be definite.
+/// 
+/// `D` is block diagonal with 1x1 and 2x2 blocks, and `L` is unit lower triangular up to 
+/// the symmetric interchanges applied before each block. Only the lower triangle of `A` 
+/// is read, and the factors overwrite it in a matrix of the same type as `A`, in LAPACK's 
+/// compact form.
+/// 
+/// An eigenvalue of a block of `D` at or below `n * epsilon` times the largest element of 
+/// the rows of `A` that were eliminated in that block counts as zero. Scaling by the block's 
+/// own rows rather than the whole of `A` keeps a badly scaled but nonsingular `A`, such as 
+/// `diag(1e20, 1)`, from having its small eigenvalues counted as zero.
+#[derive(Clone, Debug)]
+pub struct LDLT<M, T> {
+    factors: M,
+    pivots: Vec<Pivot>,
+    tolerances: Vec<T>
+}
+
+impl<M, T> LDLT<M, T>
+where
+    M: Dense + AsRef<[T]> + AsMut<[T]>,
+    T: Float
+{
+    /// Factors `a`, which always succeeds; a singular `a` leaves a singular block in `D`.
+    pub fn new(mut a: M) -> Self
+    {
+        let (n, columns) = a.shape();
+        assert!(n == columns, "the LDL^T decomposition needs a square matrix, not {}x{}", n, columns);
+
+        // The largest element of each row of `A`, read from the lower triangle.
+        let (rs, cs) = a.layout().strides((n, n));
+        let mut largest: Vec<T> = (0..n)
+            .map(|i| (0..n)
+                .map(|j| if j <= i { i * rs + j * cs } else { j * rs + i * cs })
+                .fold(T::zero(), |acc, offset| acc.max(a.as_ref()[offset].abs())))
+            .collect();
+
+        let pivots: Vec<Pivot> = kernel::sytrf::<T>(a.layout(), n, a.as_mut());
+
+        // The rows are interchanged as they were in `sytrf`, so that each block is scaled 
+        // by the rows it eliminated.
+        let epsilon: T = T::from(n).unwrap() * T::epsilon();
+        let mut k: usize = 0;
+        let tolerances: Vec<T> = pivots
+            .iter()
+            .map(|pivot| match *pivot {
+                Pivot::OneByOne(kp) => {
+                    largest.swap(k, kp);
+                    k += 1;
+                    epsilon * largest[k - 1]
+                },
+                Pivot::TwoByTwo(kp) => {
+                    largest.swap(k + 1, kp);
+                    k += 2;
+                    epsilon * largest[k - 2].max(largest[k - 1])
+                }
+            })
+            .collect();
+
+        LDLT {
+            factors: a,
+            pivots,
+            tolerances
+        }
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize
+    {
+        self.factors.shape().0
+    }
+
+    #[inline]
+    fn offset(&self, row: usize, column: usize) -> usize
+    {
+        let (rs, cs) = self.factors.layout().strides(self.factors.shape());
+        row * rs + column * cs
+    }
+
+    #[inline]
+    fn at(&self, row: usize, column: usize) -> T
+    {
+        self.factors.as_ref()[self.offset(row, column)]
+    }
+
+    /// The blocks of `D` in order, each with the row and column interchanged with its last 
+    /// row and column before it was eliminated.
+    pub fn pivots(&self) -> &[Pivot]
+    {
+        &self.pivots
+    }
+
+    /// The blocks of `D`, as the index of their first row with the lower triangle of the 
+    /// block, `(k, d11, Some((d21, d22)))` for a 2x2 block and `(k, d11, None)` for a 1x1.
+    fn blocks(&self) -> impl Iterator<Item = (usize, T, Option<(T, T)>)> + '_
+    {
+        self.pivots
+            .iter()
+            .scan(0, move |k, pivot| {
+                let first: usize = *k;
+                Some(match pivot {
+                    Pivot::OneByOne(_) => {
+                        *k += 1;
+                        (first, self.at(first, first), None)
+                    },
+                    Pivot::TwoByTwo(_) => {
+                        *k += 2;
+                        (first, self.at(first, first), Some((self.at(first + 1, first), self.at(first + 1, first + 1))))
+                    }
+                })
+            })
+    }
+
+    /// The block diagonal factor `D`.
+    pub fn d(&self) -> M
+    where
+        M: Clone
+    {
+        let mut d: M = self.factors.clone();
+        d.as_mut().iter_mut().for_each(|value| *value = T::zero());
+        for (k, d11, block) in self.blocks() {
+            let offset: usize = self.offset(k, k);
+            d.as_mut()[offset] = d11;
+            if let Some((d21, d22)) = block {
+                for &(i, j, value) in &[(k + 1, k, d21), (k, k + 1, d21), (k + 1, k + 1, d22)] {
+                    let offset: usize = self.offset(i, j);
+                    d.as_mut()[offset] = value;
+                }
+            }
+        }
+        d
+    }
+
+    /// `det(A)`, the product of the determinants of the blocks of `D`, as the symmetric 
+    /// interchanges cancel out.
+    pub fn determinant(&self) -> T
+    {
+        self.blocks()
+            .map(|(_, d11, block)| match block {
+                Some((d21, d22)) => d11 * d22 - d21 * d21,
+                None => d11
+            })
+            .fold(T::one(), |acc, det| acc * det)
+    }
+
+    /// The eigenvalues of each block of `D`, with the index of the first row of the block 
+    /// and the block's tolerance.
+    fn block_eigenvalues(&self) -> impl Iterator<Item = (usize, T, T)> + '_
+    {
+        self.blocks()
+            .zip(self.tolerances.iter().copied())
+            .flat_map(|((k, d11, block), tolerance)| {
+                let eigenvalues: [Option<T>; 2] = match block {
+                    Some((d21, d22)) => {
+                        // The larger eigenvalue in modulus first, and the smaller from the 
+                        // determinant, which avoids cancellation.
+                        let two: T = T::one() + T::one();
+                        let mean: T = (d11 + d22) / two;
+                        let radius: T = ((d11 - d22) / two).hypot(d21);
+                        let larger: T = if mean < T::zero() { mean - radius } else { mean + radius };
+                        let smaller: T = if larger.is_zero() { T::zero() } else { (d11 * d22 - d21 * d21) / larger };
+                        [Some(larger), Some(smaller)]
+                    },
+                    None => [Some(d11), None]
+                };
+                IntoIterator::into_iter(eigenvalues).flatten().map(move |eigenvalue| (k, eigenvalue, tolerance))
+            })
+    }
+
+    /// The numbers of positive, negative and zero eigenvalues of `A`, which by Sylvester's 
+    /// law of inertia are those of `D`.
+    pub fn inertia(&self) -> (usize, usize, usize)
+    {
+        self.block_eigenvalues()
+            .fold((0, 0, 0), |(positive, negative, zero), (_, eigenvalue, tolerance)| {
+                if eigenvalue > tolerance {
+                    (positive + 1, negative, zero)
+                } else if eigenvalue < -tolerance {
+                    (positive, negative + 1, zero)
+                } else {
+                    (positive, negative, zero + 1)
+                }
+            })
+    }
+
+    /// Solves `A * x = b`, overwriting `b` with `x`, failing before touching `b` if a block 
+    /// of `D` is numerically singular.
+    pub fn solve_mut<X>(&self, b: &mut X) -> Result<(), Singular>
+    where
+        X: AsMut<[T]> + ?Sized
+    {
+        let singular = |(_, eigenvalue, tolerance): &(usize, T, T)| eigenvalue.abs().partial_cmp(tolerance) != Some(Ordering::Greater);
+        if let Some((pivot, _, _)) = self.block_eigenvalues().find(singular) {
+            return Err(Singular { pivot })
+        }
+
+        kernel::sytrs::<T>(self.factors.layout(), self.dimension(), self.factors.as_ref(), &self.pivots, b.as_mut());
+        Ok(())
+    }
+
+    /// Solves `A * x = b`, failing if a block of `D` is numerically singular.
+    pub fn solve<X>(&self, b: &X) -> Result<X, Singular>
+    where
+        X: Clone + AsMut<[T]>
+    {
+        let mut x: X = b.clone();
+        self.solve_mut(&mut x)?;
+        Ok(x)
+    }
+}
@@ -0,0 +1,86 @@
+use std::{iter::FromIterator, marker::PhantomData, ops::{self, AddAssign, Add, MulAssign, Mul, Neg}};
+
+use algebra::*;
+
+use crate::DimensionMismatch;
+use super::DynVector;
+
+/// A vector space whose dimension is chosen at runtime.
+/// 
+/// This is the counterpart of the spaces generated by `vspace!` for problem sizes that 
+/// are not known at compile time. Vectors are validated against `dimension` when they 
+/// are built and by every operation, in release builds too.
+pub struct DynSpace<T> {
+    dimension: usize,
+    _phantom: PhantomData<T>
+}
+
+impl<T> DynSpace<T>
+{
+    #[inline]
+    pub fn new(dimension: usize) -> Self 
+    {
+        DynSpace {
+            dimension,
+            _phantom: PhantomData
+        }
+    }
+
+    #[inline]
+    pub fn dimension(&self) -> usize
+    {
+        self.dimension
+    }
+
+    /// Panics if an operand does not have `dimension` elements, in every build: a 
+    /// `DynVector` of any length can be handed to any `DynSpace`.
+    #[inline]
+    #[track_caller]
+    fn verify(&self, found: usize)
+    {
+        if let Err(error) = DimensionMismatch::check(self.dimension, found) {
+            panic!("{}", error)
+        }
+    }
+
+    /// Wraps `inner`, panicking if it does not have `dimension` elements.
+    pub fn vector(&self, inner: Vec<T>) -> DynVector<T>
+    {
+        assert!(inner.len() == self.dimension);
+        DynVector(inner)
+    }
+
+    pub fn try_vector(&self, inner: Vec<T>) -> Result<DynVector<T>, DimensionMismatch>
+    {
+        DimensionMismatch::check(self.dimension, inner.len())?;
+        Ok(DynVector(inner))
+    }
+
+    pub fn try_from_iter<I>(&self, iterator: I) -> Result<DynVector<T>, DimensionMismatch>
+    where
+        I: IntoIterator<Item=T>
+    {
+        self.try_vector(Vec::from_iter(iterator))
+    }
+}
+
+impl<T> VectorSpace for DynSpace<T>
+{
+    type Scalar = T;
+
+    type Vector = DynVector<T>;
+}
+
+impl<T> VAdditiveIdentity for DynSpace<T>
+where
+    T: AdditiveIdentity<Output=T>
+{
+    type Output = DynVector<T>;
+
+    fn additive_identity(&self) -> Self::Output
+    {
+        DynVector((0..self.dimension).map(|_| T::additive_identity()).collect())
+    }
+}
+
+crate::vectorspace!(@Defaults [T] DynSpace<T>, DynVector<T>);
@@ -0,0 +1,77 @@
+use std::{fmt, ops::{Index, IndexMut}};
+
+/// A vector of a `DynSpace`, whose dimension is only known at runtime.
+/// 
+/// Vectors are built through their space, which checks them against its dimension.
+#[derive(Clone)]
+pub struct DynVector<T>(pub(crate) Vec<T>);
+
+impl<T> DynVector<T>
+{
+    /// The number of elements in the vector.
+    #[inline]
+    pub fn dimension(&self) -> usize
+    {
+        self.0.len()
+    }
+
+    pub fn into_inner(self) -> Vec<T>
+    {
+        self.0
+    }
+}
+
+impl<'a,T> IntoIterator for &'a DynVector<T>
+{
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a,T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.0.iter()
+    }
+}
+
+impl<T> AsRef<[T]> for DynVector<T>
+{
+    fn as_ref(&self) -> &[T]
+    {
+        &self.0[..]
+    }
+}
+
+impl<T> AsMut<[T]> for DynVector<T>
+{
+    fn as_mut(&mut self) -> &mut [T]
+    {
+        &mut self.0[..]
+    }
+}
+
+impl<T> Index<usize> for DynVector<T>
+{
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &Self::Output 
+    {
+        &self.0[idx]
+    }
+}
+
+impl<T> IndexMut<usize> for DynVector<T>
+{
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output 
+    {
+        &mut self.0[idx]
+    }
+}
+
+impl<T> fmt::Debug for DynVector<T>
+where
+    T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{:?}", self.0)
+    }
+}
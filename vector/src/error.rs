@@ -0,0 +1,94 @@
+use std::{error, fmt};
+
+/// The error returned when a vector or matrix does not have the dimension of its space.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DimensionMismatch {
+    pub expected: usize,
+    pub found: usize
+}
+
+impl DimensionMismatch
+{
+    #[inline]
+    pub fn new(expected: usize, found: usize) -> Self 
+    {
+        DimensionMismatch {
+            expected,
+            found
+        }
+    }
+
+    /// Succeeds when `found` is equal to `expected`.
+    #[inline]
+    pub fn check(expected: usize, found: usize) -> Result<(), Self>
+    {
+        if expected == found {
+            Ok(())
+        } else {
+            Err(Self::new(expected, found))
+        }
+    }
+}
+
+impl fmt::Display for DimensionMismatch
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "expected dimension {}, found {}", self.expected, self.found)
+    }
+}
+
+impl error::Error for DimensionMismatch { }
+
+/// The error returned when a factorisation meets a matrix that is singular.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Singular {
+    /// The step at which no nonzero pivot was left.
+    pub pivot: usize
+}
+
+impl fmt::Display for Singular
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "matrix is singular: no nonzero pivot at step {}", self.pivot)
+    }
+}
+
+impl error::Error for Singular { }
+
+/// The error returned when a Cholesky factorisation meets a matrix that is not 
+/// symmetric positive definite.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotPositiveDefinite {
+    /// The step whose pivot was not positive.
+    pub pivot: usize
+}
+
+impl fmt::Display for NotPositiveDefinite
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "matrix is not positive definite: non-positive pivot at step {}", self.pivot)
+    }
+}
+
+impl error::Error for NotPositiveDefinite { }
+
+/// The error returned when an iterative eigenvalue algorithm does not converge within 
+/// its iteration limit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoConvergence {
+    /// The number of iterations that were spent.
+    pub iterations: usize
+}
+
+impl fmt::Display for NoConvergence
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "eigenvalue iteration did not converge in {} iterations", self.iterations)
+    }
+}
+
+impl error::Error for NoConvergence { }
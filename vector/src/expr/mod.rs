@@ -0,0 +1,178 @@
+//! Lazy expression templates over the vectors of a space.
+//!
+//! `space.expr(&x)` starts an expression. Adding, subtracting, negating and scaling it
+//! records an operation tree rather than computing anything, and `space.eval` walks that
+//! tree once per element, writing straight into the destination. Where `vadd!` and 
+//! `vscale!` make one pass per operand, `space.expr(&x) + 2.0 * &y - &z` makes a single 
+//! pass and allocates nothing.
+
+mod ops;
+
+use std::ops::{Add, Sub, Mul, Neg};
+
+/// A vector-valued expression, evaluated one element at a time.
+pub trait Expr
+{
+    type Scalar;
+
+    /// The number of elements the expression evaluates to.
+    fn dimension(&self) -> usize;
+
+    /// Evaluates element `idx` of the expression.
+    fn at(&self, idx: usize) -> Self::Scalar;
+}
+
+/// An operand of an expression, borrowed from a vector.
+#[derive(Clone, Copy, Debug)]
+pub struct Leaf<'a, T>(&'a [T]);
+
+impl<'a, T> Leaf<'a, T>
+{
+    pub fn new(elements: &'a [T]) -> Self
+    {
+        Leaf(elements)
+    }
+}
+
+impl<T: Clone> Expr for Leaf<'_, T>
+{
+    type Scalar = T;
+
+    fn dimension(&self) -> usize
+    {
+        self.0.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> Self::Scalar
+    {
+        self.0[idx].clone()
+    }
+}
+
+/// `lhs + rhs`.
+#[derive(Clone, Copy, Debug)]
+pub struct Sum<L, R>(L, R);
+
+impl<L, R> Expr for Sum<L, R>
+where
+    L: Expr,
+    R: Expr<Scalar=L::Scalar>,
+    L::Scalar: Add<L::Scalar, Output=L::Scalar>
+{
+    type Scalar = L::Scalar;
+
+    fn dimension(&self) -> usize
+    {
+        self.0.dimension()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> Self::Scalar
+    {
+        self.0.at(idx) + self.1.at(idx)
+    }
+}
+
+/// `lhs - rhs`.
+#[derive(Clone, Copy, Debug)]
+pub struct Difference<L, R>(L, R);
+
+impl<L, R> Expr for Difference<L, R>
+where
+    L: Expr,
+    R: Expr<Scalar=L::Scalar>,
+    L::Scalar: Sub<L::Scalar, Output=L::Scalar>
+{
+    type Scalar = L::Scalar;
+
+    fn dimension(&self) -> usize
+    {
+        self.0.dimension()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> Self::Scalar
+    {
+        self.0.at(idx) - self.1.at(idx)
+    }
+}
+
+/// `-expr`.
+#[derive(Clone, Copy, Debug)]
+pub struct Negation<E>(E);
+
+impl<E> Expr for Negation<E>
+where
+    E: Expr,
+    E::Scalar: Neg<Output=E::Scalar>
+{
+    type Scalar = E::Scalar;
+
+    fn dimension(&self) -> usize
+    {
+        self.0.dimension()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> Self::Scalar
+    {
+        -self.0.at(idx)
+    }
+}
+
+/// `alpha * expr`.
+#[derive(Clone, Copy, Debug)]
+pub struct Scaled<E, T>(T, E);
+
+impl<E, T> Expr for Scaled<E, T>
+where
+    E: Expr<Scalar=T>,
+    T: Clone + Mul<T, Output=T>
+{
+    type Scalar = T;
+
+    fn dimension(&self) -> usize
+    {
+        self.1.dimension()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> Self::Scalar
+    {
+        self.0.clone() * self.1.at(idx)
+    }
+}
+
+/// An expression tree, wrapped so that the arithmetic operators extend it.
+#[derive(Clone, Copy, Debug)]
+pub struct VExpr<E>(E);
+
+impl<E> VExpr<E>
+{
+    pub fn new(expr: E) -> Self
+    {
+        VExpr(expr)
+    }
+
+    pub fn into_inner(self) -> E
+    {
+        self.0
+    }
+}
+
+impl<E: Expr> Expr for VExpr<E>
+{
+    type Scalar = E::Scalar;
+
+    fn dimension(&self) -> usize
+    {
+        self.0.dimension()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> Self::Scalar
+    {
+        self.0.at(idx)
+    }
+}
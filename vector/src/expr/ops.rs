@@ -0,0 +1,99 @@
+use std::ops::{Add, Sub, Mul, Neg};
+
+use crate::kernel::verify;
+
+use super::{Expr, Leaf, Sum, Difference, Negation, Scaled, VExpr};
+
+impl<L, R> Add<VExpr<R>> for VExpr<L>
+where
+    L: Expr,
+    R: Expr<Scalar=L::Scalar>
+{
+    type Output = VExpr<Sum<L, R>>;
+
+    fn add(self, rhs: VExpr<R>) -> Self::Output
+    {
+        verify(self.0.dimension(), rhs.0.dimension());
+        VExpr(Sum(self.0, rhs.0))
+    }
+}
+
+impl<'a, L, V> Add<&'a V> for VExpr<L>
+where
+    L: Expr,
+    L::Scalar: Clone + 'a,
+    V: AsRef<[L::Scalar]>
+{
+    type Output = VExpr<Sum<L, Leaf<'a, L::Scalar>>>;
+
+    fn add(self, rhs: &'a V) -> Self::Output
+    {
+        self + VExpr(Leaf(rhs.as_ref()))
+    }
+}
+
+impl<L, R> Sub<VExpr<R>> for VExpr<L>
+where
+    L: Expr,
+    R: Expr<Scalar=L::Scalar>
+{
+    type Output = VExpr<Difference<L, R>>;
+
+    fn sub(self, rhs: VExpr<R>) -> Self::Output
+    {
+        verify(self.0.dimension(), rhs.0.dimension());
+        VExpr(Difference(self.0, rhs.0))
+    }
+}
+
+impl<'a, L, V> Sub<&'a V> for VExpr<L>
+where
+    L: Expr,
+    L::Scalar: Clone + 'a,
+    V: AsRef<[L::Scalar]>
+{
+    type Output = VExpr<Difference<L, Leaf<'a, L::Scalar>>>;
+
+    fn sub(self, rhs: &'a V) -> Self::Output
+    {
+        self - VExpr(Leaf(rhs.as_ref()))
+    }
+}
+
+impl<E: Expr> Neg for VExpr<E>
+{
+    type Output = VExpr<Negation<E>>;
+
+    fn neg(self) -> Self::Output
+    {
+        VExpr(Negation(self.0))
+    }
+}
+
+impl<E: Expr> Mul<E::Scalar> for VExpr<E>
+{
+    type Output = VExpr<Scaled<E, E::Scalar>>;
+
+    fn mul(self, alpha: E::Scalar) -> Self::Output
+    {
+        VExpr(Scaled(alpha, self.0))
+    }
+}
+
+macro_rules! scalar_lhs {
+    ($($T:ty),*) => {
+        $(
+            impl<E: Expr<Scalar=$T>> Mul<VExpr<E>> for $T
+            {
+                type Output = VExpr<Scaled<E, $T>>;
+
+                fn mul(self, expr: VExpr<E>) -> Self::Output
+                {
+                    VExpr(Scaled(self, expr.0))
+                }
+            }
+        )*
+    };
+}
+
+scalar_lhs!{ i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64 }
@@ -0,0 +1,61 @@
+use super::verify;
+
+/// The number of `GF(2)` elements packed into each word.
+pub const WORD_BITS: usize = 64;
+
+/// The number of words holding `length` packed elements.
+#[inline]
+pub const fn words(length: usize) -> usize
+{
+    length.div_ceil(WORD_BITS)
+}
+
+/// The bits of the last word that hold elements, when `length` elements are packed.
+#[inline]
+pub const fn tail_mask(length: usize) -> u64
+{
+    match length % WORD_BITS {
+        0 => !0,
+        bits => (1 << bits) - 1
+    }
+}
+
+#[inline]
+pub fn get_bit(words: &[u64], idx: usize) -> bool
+{
+    words[idx / WORD_BITS] >> (idx % WORD_BITS) & 1 == 1
+}
+
+#[inline]
+pub fn set_bit(words: &mut [u64], idx: usize, bit: bool)
+{
+    let mask: u64 = 1 << (idx % WORD_BITS);
+    if bit {
+        words[idx / WORD_BITS] |= mask;
+    } else {
+        words[idx / WORD_BITS] &= !mask;
+    }
+}
+
+/// `lhs ^= rhs`, adding 64 packed `GF(2)` elements at a time.
+pub fn xor_assign(lhs: &mut [u64], rhs: &[u64])
+{
+    verify(lhs.len(), rhs.len());
+
+    lhs
+        .iter_mut()
+        .zip(rhs)
+        .for_each(|(l, r)| *l ^= r);
+}
+
+/// The parity of `popcount(x & y)`, the `GF(2)` dot product of the packed elements.
+pub fn and_parity(x: &[u64], y: &[u64]) -> bool
+{
+    verify(x.len(), y.len());
+
+    x
+        .iter()
+        .zip(y)
+        .fold(0, |acc, (xi, yi)| acc ^ (xi & yi))
+        .count_ones() % 2 == 1
+}
@@ -0,0 +1,243 @@
+use num_traits::Float;
+
+use crate::{Layout, NoConvergence};
+
+use super::verify;
+
+/// The number of implicit QL iterations allowed for each eigenvalue.
+const QL_ITERATIONS: usize = 30;
+
+/// The number of cyclic Jacobi sweeps allowed.
+const JACOBI_SWEEPS: usize = 50;
+
+/// Reduces the symmetric `n` by `n` matrix in `a` to tridiagonal form `Q^T * A * Q` with 
+/// Householder reflections, reading only the lower triangle, as in the `tred2` of 
+/// Numerical Recipes.
+/// 
+/// The diagonal is left in `d` and the subdiagonal in `e[..n - 1]`, with `e[n - 1]` set to 
+/// zero, and `a` is overwritten with the orthogonal `Q`.
+pub fn sytrd<T: Float>(layout: Layout, n: usize, a: &mut [T], d: &mut [T], e: &mut [T])
+{
+    verify(n * n, a.len());
+    verify(n, d.len());
+    verify(n, e.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    let at = |i: usize, j: usize| i * rs + j * cs;
+
+    for i in (1..n).rev() {
+        let l: usize = i - 1;
+        let mut h: T = T::zero();
+        if l > 0 {
+            let scale: T = (0..i).fold(T::zero(), |acc, k| acc + a[at(i, k)].abs());
+            if scale.is_zero() {
+                e[i] = a[at(i, l)];
+            } else {
+                for k in 0..i {
+                    a[at(i, k)] = a[at(i, k)] / scale;
+                    h = h + a[at(i, k)] * a[at(i, k)];
+                }
+                let f: T = a[at(i, l)];
+                let g: T = if f >= T::zero() { -h.sqrt() } else { h.sqrt() };
+                e[i] = scale * g;
+                h = h - f * g;
+                a[at(i, l)] = f - g;
+
+                let mut f: T = T::zero();
+                for j in 0..i {
+                    a[at(j, i)] = a[at(i, j)] / h;
+                    let g: T = (0..=j).fold(T::zero(), |acc, k| acc + a[at(j, k)] * a[at(i, k)]);
+                    let g: T = ((j + 1)..i).fold(g, |acc, k| acc + a[at(k, j)] * a[at(i, k)]);
+                    e[j] = g / h;
+                    f = f + e[j] * a[at(i, j)];
+                }
+
+                let hh: T = f / (h + h);
+                for j in 0..i {
+                    let f: T = a[at(i, j)];
+                    let g: T = e[j] - hh * f;
+                    e[j] = g;
+                    for k in 0..=j {
+                        a[at(j, k)] = a[at(j, k)] - (f * e[k] + g * a[at(i, k)]);
+                    }
+                }
+            }
+        } else {
+            e[i] = a[at(i, l)];
+        }
+        d[i] = h;
+    }
+
+    if n > 0 {
+        d[0] = T::zero();
+        e[0] = T::zero();
+    }
+    for i in 0..n {
+        if !d[i].is_zero() {
+            for j in 0..i {
+                let g: T = (0..i).fold(T::zero(), |acc, k| acc + a[at(i, k)] * a[at(k, j)]);
+                for k in 0..i {
+                    a[at(k, j)] = a[at(k, j)] - g * a[at(k, i)];
+                }
+            }
+        }
+        d[i] = a[at(i, i)];
+        a[at(i, i)] = T::one();
+        for j in 0..i {
+            a[at(j, i)] = T::zero();
+            a[at(i, j)] = T::zero();
+        }
+    }
+
+    if n > 0 {
+        e.copy_within(1.., 0);
+        e[n - 1] = T::zero();
+    }
+}
+
+/// Diagonalises the symmetric tridiagonal matrix with diagonal `d` and subdiagonal 
+/// `e[..n - 1]` with the implicit QL algorithm, as in the `tqli` of Numerical Recipes.
+/// 
+/// The unsorted eigenvalues overwrite `d` and `e` is destroyed. The rotations are 
+/// accumulated into the columns of the `n` by `n` matrix in `z`, so `z` should hold 
+/// the `Q` from `sytrd`, or the identity, on entry.
+pub fn steqr<T: Float>(layout: Layout, n: usize, d: &mut [T], e: &mut [T], z: &mut [T]) -> Result<(), NoConvergence>
+{
+    verify(n, d.len());
+    verify(n, e.len());
+    verify(n * n, z.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    let at = |i: usize, j: usize| i * rs + j * cs;
+    let two: T = T::one() + T::one();
+    let mut iterations: usize = 0;
+
+    for l in 0..n {
+        let mut iteration: usize = 0;
+        loop {
+            let m: usize = (l..n.saturating_sub(1))
+                .find(|&m| e[m].abs() <= T::epsilon() * (d[m].abs() + d[m + 1].abs()))
+                .unwrap_or(n - 1);
+            if m == l {
+                break;
+            }
+            if iteration == QL_ITERATIONS {
+                return Err(NoConvergence { iterations })
+            }
+            iteration += 1;
+            iterations += 1;
+
+            let g: T = (d[l + 1] - d[l]) / (two * e[l]);
+            let r: T = g.hypot(T::one());
+            let mut g: T = d[m] - d[l] + e[l] / (g + if g >= T::zero() { r.abs() } else { -r.abs() });
+            let (mut s, mut c, mut p) = (T::one(), T::one(), T::zero());
+            let mut underflow: bool = false;
+
+            for i in (l..m).rev() {
+                let f: T = s * e[i];
+                let b: T = c * e[i];
+                let r: T = f.hypot(g);
+                e[i + 1] = r;
+                if r.is_zero() {
+                    d[i + 1] = d[i + 1] - p;
+                    e[m] = T::zero();
+                    underflow = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                let r: T = (d[i] - g) * s + two * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+
+                for k in 0..n {
+                    let f: T = z[at(k, i + 1)];
+                    z[at(k, i + 1)] = s * z[at(k, i)] + c * f;
+                    z[at(k, i)] = c * z[at(k, i)] - s * f;
+                }
+            }
+            if underflow {
+                continue;
+            }
+            d[l] = d[l] - p;
+            e[l] = g;
+            e[m] = T::zero();
+        }
+    }
+    Ok(())
+}
+
+/// Diagonalises the symmetric `n` by `n` matrix in `a` with the cyclic Jacobi method, 
+/// reading only the lower triangle.
+/// 
+/// A rotation is skipped once `|a[p][q]|` is negligible against `sqrt(|a[p][p] * a[q][q]|)`, 
+/// which keeps small eigenvalues accurate relative to themselves rather than to the norm 
+/// of `a`. The unsorted eigenvalues are written to `w`, their eigenvectors to the columns 
+/// of `v`, and `a` is destroyed.
+pub fn syevj<T: Float>(layout: Layout, n: usize, a: &mut [T], w: &mut [T], v: &mut [T]) -> Result<(), NoConvergence>
+{
+    verify(n * n, a.len());
+    verify(n, w.len());
+    verify(n * n, v.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    let at = |i: usize, j: usize| i * rs + j * cs;
+    let two: T = T::one() + T::one();
+
+    for i in 0..n {
+        for j in 0..n {
+            v[at(i, j)] = if i == j { T::one() } else { T::zero() };
+            if j > i {
+                a[at(i, j)] = a[at(j, i)];
+            }
+        }
+    }
+
+    for _ in 0..JACOBI_SWEEPS {
+        let mut rotated: bool = false;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let apq: T = a[at(p, q)];
+                if apq.abs() <= T::epsilon() * (a[at(p, p)] * a[at(q, q)]).abs().sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let theta: T = (a[at(q, q)] - a[at(p, p)]) / (two * apq);
+                let t: T = theta.abs().hypot(T::one()) + theta.abs();
+                let t: T = if theta < T::zero() { -t.recip() } else { t.recip() };
+                let c: T = t.hypot(T::one()).recip();
+                let s: T = t * c;
+
+                for k in 0..n {
+                    let (akp, akq) = (a[at(k, p)], a[at(k, q)]);
+                    a[at(k, p)] = c * akp - s * akq;
+                    a[at(k, q)] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[at(p, k)], a[at(q, k)]);
+                    a[at(p, k)] = c * apk - s * aqk;
+                    a[at(q, k)] = s * apk + c * aqk;
+                }
+                a[at(p, q)] = T::zero();
+                a[at(q, p)] = T::zero();
+
+                for k in 0..n {
+                    let (vkp, vkq) = (v[at(k, p)], v[at(k, q)]);
+                    v[at(k, p)] = c * vkp - s * vkq;
+                    v[at(k, q)] = s * vkp + c * vkq;
+                }
+            }
+        }
+
+        if !rotated {
+            for (i, w) in w.iter_mut().enumerate() {
+                *w = a[at(i, i)];
+            }
+            return Ok(())
+        }
+    }
+    Err(NoConvergence { iterations: JACOBI_SWEEPS })
+}
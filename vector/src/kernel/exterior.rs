@@ -0,0 +1,38 @@
+use std::ops::{Sub, Mul};
+
+use super::verify;
+
+/// The cross product `lhs x rhs` of two three-element slices.
+pub fn cross<T>(lhs: &[T], rhs: &[T]) -> [T; 3]
+where
+    T: Sub<T, Output=T>,
+    for <'a> &'a T: Mul<&'a T, Output=T>
+{
+    verify(3, lhs.len());
+    verify(3, rhs.len());
+
+    [
+        &lhs[1] * &rhs[2] - &lhs[2] * &rhs[1],
+        &lhs[2] * &rhs[0] - &lhs[0] * &rhs[2],
+        &lhs[0] * &rhs[1] - &lhs[1] * &rhs[0]
+    ]
+}
+
+/// The wedge product `lhs ^ rhs`: `lhs[i] * rhs[j] - lhs[j] * rhs[i]` for every 
+/// `i < j`, in lexicographic order.
+pub fn wedge<T>(lhs: &[T], rhs: &[T]) -> Vec<T>
+where
+    T: Sub<T, Output=T>,
+    for <'a> &'a T: Mul<&'a T, Output=T>
+{
+    verify(lhs.len(), rhs.len());
+
+    let n: usize = lhs.len();
+    let mut bivector: Vec<T> = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            bivector.push(&lhs[i] * &rhs[j] - &lhs[j] * &rhs[i]);
+        }
+    }
+    bivector
+}
@@ -0,0 +1,271 @@
+use std::ops::{Add, Sub, Mul, MulAssign};
+
+use num_traits::{Float, Signed};
+
+use algebra::Conjugate;
+
+use super::verify;
+
+/// The sum of `conj(x[i]) * y[i]`, starting from `identity`.
+pub fn dotc<T>(x: &[T], y: &[T], identity: T) -> T
+where
+    T: Add<T, Output=T> + Conjugate<Output=T>,
+    for <'a> &'a T: Mul<&'a T, Output=T>
+{
+    verify(x.len(), y.len());
+
+    x
+        .iter()
+        .zip(y)
+        .fold(identity, |acc, (xi, yi)| acc + &xi.conj() * yi)
+}
+
+/// The sum of `|x[i]|`.
+pub fn asum<T: Signed>(x: &[T]) -> T
+{
+    x
+        .iter()
+        .fold(T::zero(), |acc, xi| acc + xi.abs())
+}
+
+/// The Euclidean norm of `x`, accumulated as `scale * sqrt(ssq)` so that 
+/// neither overflows nor underflows for representable results.
+pub fn nrm2<T: Float>(x: &[T]) -> T
+{
+    let mut scale: T = T::zero();
+    let mut ssq: T = T::one();
+
+    for xi in x.iter().filter(|xi| !xi.is_zero()) {
+        let absxi: T = xi.abs();
+        if scale < absxi {
+            ssq = T::one() + ssq * (scale / absxi).powi(2);
+            scale = absxi;
+        } else {
+            ssq = ssq + (absxi / scale).powi(2);
+        }
+    }
+    scale * ssq.sqrt()
+}
+
+/// The index of the first element of `x` with the largest absolute value.
+pub fn iamax<T: Signed + PartialOrd>(x: &[T]) -> Option<usize>
+{
+    let mut iter = x
+        .iter()
+        .map(|xi| xi.abs())
+        .enumerate();
+
+    let first: (usize, T) = iter.next()?;
+    let (idx, _) = iter.fold(first, |(idx, max), (i, val)| {
+        if val > max { (i, val) } else { (idx, max) }
+    });
+    Some(idx)
+}
+
+/// `x[i] *= alpha` for every `incx`-th element of `x`, doing nothing when `incx` is zero.
+pub fn scal<T>(alpha: &T, x: &mut [T], incx: usize)
+where
+    for <'a> T: MulAssign<&'a T>
+{
+    if incx == 0 {
+        return
+    }
+
+    x
+        .iter_mut()
+        .step_by(incx)
+        .for_each(|xi| xi.mul_assign(alpha));
+}
+
+/// Applies the Givens rotation `(c, s)` to every point `(x[i], y[i])`.
+pub fn rot<T>(x: &mut [T], y: &mut [T], c: &T, s: &T)
+where
+    T: Copy + Add<T, Output=T> + Sub<T, Output=T> + Mul<T, Output=T>
+{
+    verify(x.len(), y.len());
+
+    for (xi, yi) in x.iter_mut().zip(y.iter_mut()) {
+        let (w, z) = (*xi, *yi);
+        *xi = *c * w + *s * z;
+        *yi = *c * z - *s * w;
+    }
+}
+
+/// Constructs the Givens rotation `(c, s)` that zeroes `b`, leaving `r` in `a` and `z` in `b`.
+pub fn rotg<T: Float>(a: &mut T, b: &mut T) -> (T, T)
+{
+    let roe: T = if a.abs() > b.abs() { *a } else { *b };
+    let scale: T = a.abs() + b.abs();
+
+    let (c, s, r, z) = if scale.is_zero() {
+        (T::one(), T::zero(), T::zero(), T::zero())
+    } else {
+        let mut r: T = scale * ((*a / scale).powi(2) + (*b / scale).powi(2)).sqrt();
+        if roe < T::zero() {
+            r = -r;
+        }
+        let c: T = *a / r;
+        let s: T = *b / r;
+
+        let mut z: T = T::one();
+        if a.abs() > b.abs() {
+            z = s;
+        }
+        if b.abs() >= a.abs() && !c.is_zero() {
+            z = T::one() / c;
+        }
+        (c, s, r, z)
+    };
+
+    *a = r;
+    *b = z;
+    (c, s)
+}
+
+/// Applies the modified Givens rotation `[flag, h11, h21, h12, h22]` to every point `(x[i], y[i])`.
+pub fn rotm<T: Float>(x: &mut [T], y: &mut [T], param: &[T; 5])
+{
+    verify(x.len(), y.len());
+
+    let [flag, h11, h21, h12, h22] = *param;
+    let two: T = T::one() + T::one();
+
+    let (h11, h21, h12, h22) = if flag == -two {
+        return
+    } else if flag < T::zero() {
+        (h11, h21, h12, h22)
+    } else if flag.is_zero() {
+        (T::one(), h21, h12, T::one())
+    } else {
+        (h11, -T::one(), T::one(), h22)
+    };
+
+    for (xi, yi) in x.iter_mut().zip(y.iter_mut()) {
+        let (w, z) = (*xi, *yi);
+        *xi = w * h11 + z * h12;
+        *yi = w * h21 + z * h22;
+    }
+}
+
+/// Constructs the modified Givens rotation that zeroes the second component of 
+/// `(sqrt(d1) * x1, sqrt(d2) * y1)`, following the reference BLAS `rotmg`.
+pub fn rotmg<T: Float>(d1: &mut T, d2: &mut T, x1: &mut T, y1: &T) -> [T; 5]
+{
+    let zero: T = T::zero();
+    let one: T = T::one();
+    let two: T = one + one;
+    let gam: T = T::from(4096.0).unwrap();
+    let gamsq: T = gam * gam;
+    let rgamsq: T = one / gamsq;
+
+    let (mut h11, mut h21, mut h12, mut h22) = (zero, zero, zero, zero);
+    let mut flag: T;
+
+    if *d1 < zero {
+        flag = -one;
+        *d1 = zero;
+        *d2 = zero;
+        *x1 = zero;
+    } else {
+        let p2: T = *d2 * *y1;
+        if p2.is_zero() {
+            return [-two, zero, zero, zero, zero]
+        }
+
+        let p1: T = *d1 * *x1;
+        let q2: T = p2 * *y1;
+        let q1: T = p1 * *x1;
+
+        if q1.abs() > q2.abs() {
+            h21 = -*y1 / *x1;
+            h12 = p2 / p1;
+            let u: T = one - h12 * h21;
+            if u > zero {
+                flag = zero;
+                *d1 = *d1 / u;
+                *d2 = *d2 / u;
+                *x1 = *x1 * u;
+            } else {
+                flag = -one;
+                h11 = zero;
+                h21 = zero;
+                h12 = zero;
+                h22 = zero;
+                *d1 = zero;
+                *d2 = zero;
+                *x1 = zero;
+            }
+        } else if q2 < zero {
+            flag = -one;
+            h11 = zero;
+            h21 = zero;
+            h12 = zero;
+            h22 = zero;
+            *d1 = zero;
+            *d2 = zero;
+            *x1 = zero;
+        } else {
+            flag = one;
+            h11 = p1 / p2;
+            h22 = *x1 / *y1;
+            let u: T = one + h11 * h22;
+            let temp: T = *d2 / u;
+            *d2 = *d1 / u;
+            *d1 = temp;
+            *x1 = *y1 * u;
+        }
+
+        let rescale = |flag: &mut T, h11: &mut T, h21: &mut T, h12: &mut T, h22: &mut T| {
+            if *flag >= zero {
+                if flag.is_zero() {
+                    *h11 = one;
+                    *h22 = one;
+                } else {
+                    *h21 = -one;
+                    *h12 = one;
+                }
+                *flag = -one;
+            }
+        };
+
+        if !d1.is_zero() {
+            while *d1 <= rgamsq || *d1 >= gamsq {
+                rescale(&mut flag, &mut h11, &mut h21, &mut h12, &mut h22);
+                if *d1 <= rgamsq {
+                    *d1 = *d1 * gamsq;
+                    *x1 = *x1 / gam;
+                    h11 = h11 / gam;
+                    h12 = h12 / gam;
+                } else {
+                    *d1 = *d1 / gamsq;
+                    *x1 = *x1 * gam;
+                    h11 = h11 * gam;
+                    h12 = h12 * gam;
+                }
+            }
+        }
+
+        if !d2.is_zero() {
+            while d2.abs() <= rgamsq || d2.abs() >= gamsq {
+                rescale(&mut flag, &mut h11, &mut h21, &mut h12, &mut h22);
+                if d2.abs() <= rgamsq {
+                    *d2 = *d2 * gamsq;
+                    h21 = h21 / gam;
+                    h22 = h22 / gam;
+                } else {
+                    *d2 = *d2 / gamsq;
+                    h21 = h21 * gam;
+                    h22 = h22 * gam;
+                }
+            }
+        }
+    }
+
+    if flag < zero {
+        [flag, h11, h21, h12, h22]
+    } else if flag.is_zero() {
+        [flag, zero, h21, h12, zero]
+    } else {
+        [flag, h11, zero, zero, h22]
+    }
+}
@@ -0,0 +1,102 @@
+use std::ops::{AddAssign, Add, MulAssign, Mul};
+
+use algebra::AdditiveIdentity;
+
+use crate::Layout;
+
+/// `y = alpha * A * x + beta * y` where `A` is the `rows` by `columns` matrix stored in `a`.
+pub fn gemv<T>(layout: Layout, (rows, columns): (usize, usize), alpha: &T, a: &[T], x: &[T], beta: &T, y: &mut [T])
+where
+    for <'a> T: AdditiveIdentity<Output=T> + PartialEq + Add<T, Output=T> + AddAssign<T> + MulAssign<&'a T>,
+    for <'a> &'a T: Mul<&'a T, Output=T>
+{
+    assert!(a.len() == rows * columns);
+    assert!(x.len() == columns);
+    assert!(y.len() == rows);
+
+    // A zero `beta` overwrites `y`, as in BLAS, so that NaN or infinity in `y` is not 
+    // carried into the result.
+    if *beta == T::additive_identity() {
+        y
+            .iter_mut()
+            .for_each(|yi| *yi = T::additive_identity());
+    } else {
+        y
+            .iter_mut()
+            .for_each(|yi| yi.mul_assign(beta));
+    }
+
+    match layout {
+        Layout::RowMajor => {
+            for (yi, row) in y
+                .iter_mut()
+                .zip(a.chunks(columns.max(1)))
+            {
+                if let Some(acc) = row
+                    .iter()
+                    .zip(x)
+                    .map(|(aij, xj)| aij * xj)
+                    .reduce(|acc, val| acc + val)
+                {
+                    yi.add_assign(&acc * alpha)
+                }
+            }
+        },
+        Layout::ColumnMajor => {
+            for (xj, column) in x
+                .iter()
+                .zip(a.chunks(rows.max(1)))
+            {
+                let t: T = alpha * xj;
+                for (yi, aij) in y
+                    .iter_mut()
+                    .zip(column)
+                {
+                    yi.add_assign(aij * &t)
+                }
+            }
+        }
+    }
+}
+
+/// `A = A + alpha * x * y^T` where `A` is the `rows` by `columns` matrix stored in `a`.
+pub fn ger<T>(layout: Layout, (rows, columns): (usize, usize), alpha: &T, x: &[T], y: &[T], a: &mut [T])
+where
+    T: AddAssign<T>,
+    for <'a> &'a T: Mul<&'a T, Output=T>
+{
+    assert!(a.len() == rows * columns);
+    assert!(x.len() == rows);
+    assert!(y.len() == columns);
+
+    match layout {
+        Layout::RowMajor => {
+            for (xi, row) in x
+                .iter()
+                .zip(a.chunks_mut(columns.max(1)))
+            {
+                let t: T = alpha * xi;
+                for (aij, yj) in row
+                    .iter_mut()
+                    .zip(y)
+                {
+                    aij.add_assign(&t * yj)
+                }
+            }
+        },
+        Layout::ColumnMajor => {
+            for (yj, column) in y
+                .iter()
+                .zip(a.chunks_mut(rows.max(1)))
+            {
+                let t: T = alpha * yj;
+                for (aij, xi) in column
+                    .iter_mut()
+                    .zip(x)
+                {
+                    aij.add_assign(xi * &t)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::ops::{AddAssign, Add, MulAssign, Mul};
+
+use algebra::{AdditiveIdentity, Transpose};
+
+use crate::Layout;
+
+/// The number of rows of `A` packed per block.
+pub const MC: usize = 64;
+
+/// The shared dimension of a packed block of `A` and `B`.
+pub const KC: usize = 256;
+
+/// The number of columns of `B` packed per block.
+pub const NC: usize = 512;
+
+/// The number of rows in a register tile.
+pub const MR: usize = 4;
+
+/// The number of columns in a register tile.
+pub const NR: usize = 4;
+
+/// A read-only strided view of the storage of a matrix.
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    data: &'a [T],
+    shape: (usize, usize),
+    strides: (usize, usize)
+}
+
+impl<'a, T> Clone for MatrixView<'a, T>
+{
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+
+impl<'a, T> Copy for MatrixView<'a, T> { }
+
+impl<'a, T> MatrixView<'a, T>
+{
+    pub fn new(data: &'a [T], layout: Layout, shape: (usize, usize)) -> Self
+    {
+        assert!(data.len() == shape.0 * shape.1);
+        MatrixView {
+            data,
+            shape,
+            strides: layout.strides(shape)
+        }
+    }
+
+    /// Views the same storage as the transposed matrix.
+    pub fn transposed(self) -> Self
+    {
+        MatrixView {
+            data: self.data,
+            shape: (self.shape.1, self.shape.0),
+            strides: (self.strides.1, self.strides.0)
+        }
+    }
+
+    /// Views the storage as `op(A)`.
+    pub fn op(self, trans: Transpose) -> Self
+    {
+        match trans {
+            Transpose::NoTrans => self,
+            Transpose::Trans => self.transposed()
+        }
+    }
+
+    #[inline]
+    pub fn shape(&self) -> (usize, usize)
+    {
+        self.shape
+    }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> &'a T
+    {
+        &self.data[row * self.strides.0 + column * self.strides.1]
+    }
+}
+
+/// `C = alpha * A * B + beta * C` where `C` is the matrix with the given layout and shape stored in `c`.
+///
+/// `A` and `B` are copied block by block into contiguous panels sized to stay in cache, 
+/// and each `MR` by `NR` tile of `C` is accumulated in registers before it is written back.
+/// A zero `beta` overwrites `C`, as in BLAS, so that NaN or infinity in `C` is not carried 
+/// into the result.
+pub fn gemm<T>(alpha: &T, a: MatrixView<'_, T>, b: MatrixView<'_, T>, beta: &T, c: &mut [T], layout: Layout, shape: (usize, usize))
+where
+    T: Copy + Add<T, Output=T> + AddAssign<T> + Mul<T, Output=T> + MulAssign<T> + AdditiveIdentity<Output=T> + PartialEq
+{
+    let (m, n) = shape;
+    let k: usize = a.shape.1;
+    assert!(a.shape.0 == m);
+    assert!(b.shape == (k, n));
+    assert!(c.len() == m * n);
+
+    if *beta == T::additive_identity() {
+        c
+            .iter_mut()
+            .for_each(|cij| *cij = T::additive_identity());
+    } else {
+        c
+            .iter_mut()
+            .for_each(|cij| *cij *= *beta);
+    }
+
+    if m == 0 || n == 0 || k == 0 {
+        return
+    }
+
+    let zero: T = T::additive_identity();
+    let (row_stride, column_stride) = layout.strides(shape);
+    let mut a_pack: Vec<T> = vec![zero; round_up(MC.min(m), MR) * KC.min(k)];
+    let mut b_pack: Vec<T> = vec![zero; KC.min(k) * round_up(NC.min(n), NR)];
+
+    for jc in (0..n).step_by(NC) {
+        let nc: usize = NC.min(n - jc);
+
+        for pc in (0..k).step_by(KC) {
+            let kc: usize = KC.min(k - pc);
+            pack_b(&b, (pc, jc), (kc, nc), &mut b_pack, zero);
+
+            for ic in (0..m).step_by(MC) {
+                let mc: usize = MC.min(m - ic);
+                pack_a(&a, (ic, pc), (mc, kc), &mut a_pack, zero);
+
+                for jr in (0..nc).step_by(NR) {
+                    let nr: usize = NR.min(nc - jr);
+                    let b_panel: &[T] = &b_pack[jr * kc..(jr + NR) * kc];
+
+                    for ir in (0..mc).step_by(MR) {
+                        let mr: usize = MR.min(mc - ir);
+                        let a_panel: &[T] = &a_pack[ir * kc..(ir + MR) * kc];
+                        let tile: [[T; NR]; MR] = micro_kernel(a_panel, b_panel, zero);
+
+                        for (i, tile_row) in tile.iter().enumerate().take(mr) {
+                            let offset: usize = (ic + ir + i) * row_stride + (jc + jr) * column_stride;
+                            for (j, tij) in tile_row.iter().enumerate().take(nr) {
+                                c[offset + j * column_stride] += *alpha * *tij;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[inline]
+fn round_up(value: usize, multiple: usize) -> usize
+{
+    value.div_ceil(multiple) * multiple
+}
+
+/// Copies the `mc` by `kc` block of `A` at `(ic, pc)` into panels of `MR` rows, padding the last with zeroes.
+fn pack_a<T: Copy>(a: &MatrixView<'_, T>, (ic, pc): (usize, usize), (mc, kc): (usize, usize), buf: &mut [T], zero: T)
+{
+    for (ir, panel) in (0..mc)
+        .step_by(MR)
+        .zip(buf.chunks_mut(MR * kc))
+    {
+        for (p, column) in panel
+            .chunks_mut(MR)
+            .enumerate()
+        {
+            for (i, element) in column.iter_mut().enumerate() {
+                *element = if ir + i < mc { *a.get(ic + ir + i, pc + p) } else { zero };
+            }
+        }
+    }
+}
+
+/// Copies the `kc` by `nc` block of `B` at `(pc, jc)` into panels of `NR` columns, padding the last with zeroes.
+fn pack_b<T: Copy>(b: &MatrixView<'_, T>, (pc, jc): (usize, usize), (kc, nc): (usize, usize), buf: &mut [T], zero: T)
+{
+    for (jr, panel) in (0..nc)
+        .step_by(NR)
+        .zip(buf.chunks_mut(NR * kc))
+    {
+        for (p, row) in panel
+            .chunks_mut(NR)
+            .enumerate()
+        {
+            for (j, element) in row.iter_mut().enumerate() {
+                *element = if jr + j < nc { *b.get(pc + p, jc + jr + j) } else { zero };
+            }
+        }
+    }
+}
+
+/// Accumulates the product of an `MR` row panel of `A` and an `NR` column panel of `B`.
+#[inline(always)]
+fn micro_kernel<T>(a_panel: &[T], b_panel: &[T], zero: T) -> [[T; NR]; MR]
+where
+    T: Copy + AddAssign<T> + Mul<T, Output=T>
+{
+    let mut tile: [[T; NR]; MR] = [[zero; NR]; MR];
+    for (a, b) in a_panel
+        .chunks_exact(MR)
+        .zip(b_panel.chunks_exact(NR))
+    {
+        for (tile_row, ai) in tile.iter_mut().zip(a) {
+            for (tij, bj) in tile_row.iter_mut().zip(b) {
+                *tij += *ai * *bj;
+            }
+        }
+    }
+    tile
+}
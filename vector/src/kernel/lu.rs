@@ -0,0 +1,114 @@
+use std::cmp::Ordering;
+
+use algebra::{Field, Magnitude};
+
+use crate::{Layout, Singular};
+
+use super::verify;
+
+/// Factors the `n` by `n` matrix in `a` in place with partial pivoting, leaving `U` on and 
+/// above the diagonal and the multipliers of the unit lower triangular `L` below it.
+/// 
+/// Row `k` is swapped with row `pivots[k]` at step `k`, as in LAPACK's `getrf`. Returns 
+/// whether an odd number of rows were swapped, or the first step at which every remaining 
+/// candidate pivot was exactly zero (or not comparable, like NaN). As in LAPACK, the 
+/// factorization is completed even then, leaving that pivot on the diagonal of `U`. A 
+/// pivot that is merely tiny is not an error; how well conditioned `A` is, is a separate 
+/// question.
+pub fn getrf<T>(layout: Layout, n: usize, a: &mut [T], pivots: &mut [usize]) -> Result<bool, Singular>
+where
+    T: Field + Magnitude
+{
+    verify(n * n, a.len());
+    verify(n, pivots.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    let zero: <T as Magnitude>::Output = T::additive_identity().magnitude();
+    let mut odd: bool = false;
+    let mut singular: Option<usize> = None;
+
+    for k in 0..n {
+        let mut p: usize = k;
+        let mut max: <T as Magnitude>::Output = a[k * rs + k * cs].magnitude();
+        for i in (k + 1)..n {
+            let candidate: <T as Magnitude>::Output = a[i * rs + k * cs].magnitude();
+            if candidate > max {
+                p = i;
+                max = candidate;
+            }
+        }
+
+        pivots[k] = p;
+        if p != k {
+            for j in 0..n {
+                a.swap(k * rs + j * cs, p * rs + j * cs);
+            }
+            odd = !odd;
+        }
+        if max.partial_cmp(&zero) != Some(Ordering::Greater) {
+            singular.get_or_insert(k);
+            continue
+        }
+
+        let pivot: T = a[k * rs + k * cs].clone();
+        for i in (k + 1)..n {
+            let l: T = a[i * rs + k * cs].clone() / pivot.clone();
+            for j in (k + 1)..n {
+                let update: T = l.clone() * a[k * rs + j * cs].clone();
+                a[i * rs + j * cs] -= update;
+            }
+            a[i * rs + k * cs] = l;
+        }
+    }
+    match singular {
+        Some(pivot) => Err(Singular { pivot }),
+        None => Ok(odd)
+    }
+}
+
+/// `det(A)` from the factors of `A` left by `getrf`: the product of the diagonal of `U`, 
+/// negated when `odd` rows were swapped. This is zero or NaN when `getrf` failed.
+pub fn getdet<T>(layout: Layout, n: usize, lu: &[T], odd: bool) -> T
+where
+    T: Field
+{
+    verify(n * n, lu.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    let product: T = (0..n)
+        .map(|i| lu[i * rs + i * cs].clone())
+        .fold(T::multiplicative_identity(), |acc, u| acc * u);
+    if odd {
+        product.additive_inv()
+    } else {
+        product
+    }
+}
+
+/// Solves `A * x = b` in place, given the factors and pivots of `A` from `getrf`.
+pub fn getrs<T>(layout: Layout, n: usize, lu: &[T], pivots: &[usize], b: &mut [T])
+where
+    T: Field
+{
+    verify(n * n, lu.len());
+    verify(n, b.len());
+
+    let (rs, cs) = layout.strides((n, n));
+
+    for (k, &p) in pivots.iter().enumerate() {
+        b.swap(k, p);
+    }
+    for i in 0..n {
+        for j in 0..i {
+            let update: T = lu[i * rs + j * cs].clone() * b[j].clone();
+            b[i] -= update;
+        }
+    }
+    for i in (0..n).rev() {
+        for j in (i + 1)..n {
+            let update: T = lu[i * rs + j * cs].clone() * b[j].clone();
+            b[i] -= update;
+        }
+        b[i] = b[i].clone() / lu[i * rs + i * cs].clone();
+    }
+}
@@ -0,0 +1,235 @@
+//! Slice kernels shared by the code that `vspace!` generates.
+//!
+//! The generated operations delegate here so that the `multithread` feature is
+//! resolved against this crate rather than the crate invoking the macro. With the
+//! feature enabled, slices of at least `PARALLEL_THRESHOLD` elements are split into
+//! `CHUNK_SIZE` chunks and handed to rayon; anything smaller stays serial.
+//!
+//! Kernels over several slices `verify` that their lengths agree in debug builds, or 
+//! in every build with the `strict` feature, instead of silently truncating to the shortest.
+
+use std::ops::{AddAssign, SubAssign, Add, MulAssign, DivAssign, Mul, Neg};
+
+use crate::DimensionMismatch;
+use crate::expr::Expr;
+
+pub mod serial;
+
+mod level1;
+pub use level1::*;
+
+mod level2;
+pub use level2::*;
+
+mod level3;
+pub use level3::*;
+
+mod bits;
+pub use bits::*;
+
+mod norm;
+pub use norm::*;
+
+mod exterior;
+pub use exterior::*;
+
+mod lu;
+pub use lu::*;
+
+mod qr;
+pub use qr::*;
+
+mod symmetric;
+pub use symmetric::*;
+
+mod eigen;
+pub use eigen::*;
+
+mod schur;
+pub use schur::*;
+
+#[cfg(feature = "multithread")]
+pub mod parallel;
+
+/// The length at which a kernel switches to the parallel implementation.
+pub const PARALLEL_THRESHOLD: usize = 1 << 15;
+
+/// The number of elements handed to each rayon task.
+pub const CHUNK_SIZE: usize = 1 << 12;
+
+/// Scalars that can be used by the kernels.
+/// 
+/// This is `Send + Sync` when the `multithread` feature is enabled and is 
+/// implemented for every type otherwise.
+#[cfg(feature = "multithread")]
+pub trait Shareable: Send + Sync { }
+
+#[cfg(feature = "multithread")]
+impl<T: Send + Sync> Shareable for T { }
+
+/// Scalars that can be used by the kernels.
+/// 
+/// This is `Send + Sync` when the `multithread` feature is enabled and is 
+/// implemented for every type otherwise.
+#[cfg(not(feature = "multithread"))]
+pub trait Shareable { }
+
+#[cfg(not(feature = "multithread"))]
+impl<T> Shareable for T { }
+
+/// Panics if `found` differs from `expected` in debug builds or with the `strict` feature.
+#[inline]
+#[track_caller]
+pub fn verify(expected: usize, found: usize)
+{
+    if cfg!(any(debug_assertions, feature = "strict")) {
+        if let Err(error) = DimensionMismatch::check(expected, found) {
+            panic!("{}", error)
+        }
+    }
+}
+
+/// `lhs += rhs`.
+pub fn add_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: AddAssign<&'a T> + Shareable
+{
+    verify(lhs.len(), rhs.len());
+
+    #[cfg(feature = "multithread")]
+    {
+        if lhs.len() >= PARALLEL_THRESHOLD {
+            return parallel::add_assign(lhs, rhs);
+        }
+    }
+    serial::add_assign(lhs, rhs)
+}
+
+/// `lhs -= rhs`.
+pub fn sub_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: SubAssign<&'a T> + Shareable
+{
+    verify(lhs.len(), rhs.len());
+
+    #[cfg(feature = "multithread")]
+    {
+        if lhs.len() >= PARALLEL_THRESHOLD {
+            return parallel::sub_assign(lhs, rhs);
+        }
+    }
+    serial::sub_assign(lhs, rhs)
+}
+
+/// `lhs[i] *= rhs[i]`.
+pub fn hadamard_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: MulAssign<&'a T> + Shareable
+{
+    verify(lhs.len(), rhs.len());
+
+    #[cfg(feature = "multithread")]
+    {
+        if lhs.len() >= PARALLEL_THRESHOLD {
+            return parallel::hadamard_assign(lhs, rhs);
+        }
+    }
+    serial::hadamard_assign(lhs, rhs)
+}
+
+/// `lhs[i] /= rhs[i]`.
+pub fn hadamard_div_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: DivAssign<&'a T> + Shareable
+{
+    verify(lhs.len(), rhs.len());
+
+    #[cfg(feature = "multithread")]
+    {
+        if lhs.len() >= PARALLEL_THRESHOLD {
+            return parallel::hadamard_div_assign(lhs, rhs);
+        }
+    }
+    serial::hadamard_div_assign(lhs, rhs)
+}
+
+/// `vector *= scalar`.
+pub fn mul_assign<T>(vector: &mut [T], scalar: &T)
+where
+    for <'a> T: MulAssign<&'a T> + Shareable
+{
+    #[cfg(feature = "multithread")]
+    {
+        if vector.len() >= PARALLEL_THRESHOLD {
+            return parallel::mul_assign(vector, scalar);
+        }
+    }
+    serial::mul_assign(vector, scalar)
+}
+
+/// `vector = -vector`.
+pub fn neg_assign<T>(vector: &mut [T])
+where
+    T: Shareable,
+    for <'a> &'a T: Neg<Output=T>
+{
+    #[cfg(feature = "multithread")]
+    {
+        if vector.len() >= PARALLEL_THRESHOLD {
+            return parallel::neg_assign(vector);
+        }
+    }
+    serial::neg_assign(vector)
+}
+
+/// `y += alpha * x`.
+pub fn axpy<T>(alpha: &T, x: &[T], y: &mut [T])
+where
+    T: AddAssign<T> + Shareable,
+    for <'a> &'a T: Mul<&'a T, Output=T>
+{
+    verify(y.len(), x.len());
+
+    #[cfg(feature = "multithread")]
+    {
+        if y.len() >= PARALLEL_THRESHOLD {
+            return parallel::axpy(alpha, x, y);
+        }
+    }
+    serial::axpy(alpha, x, y)
+}
+
+/// The sum of `x[i] * y[i]`, with `identity` as the starting value of every partial sum.
+pub fn dot<T, F>(x: &[T], y: &[T], identity: F) -> T
+where
+    T: Add<T, Output=T> + Shareable,
+    for <'a> &'a T: Mul<&'a T, Output=T>,
+    F: Fn() -> T + Shareable
+{
+    verify(x.len(), y.len());
+
+    #[cfg(feature = "multithread")]
+    {
+        if x.len() >= PARALLEL_THRESHOLD {
+            return parallel::dot(x, y, identity);
+        }
+    }
+    serial::dot(x, y, identity())
+}
+
+/// `dest[i] = expr.at(i)`, evaluating the whole expression tree in one pass.
+pub fn eval<E>(expr: &E, dest: &mut [E::Scalar])
+where
+    E: Expr + Shareable,
+    E::Scalar: Shareable
+{
+    verify(dest.len(), expr.dimension());
+
+    #[cfg(feature = "multithread")]
+    {
+        if dest.len() >= PARALLEL_THRESHOLD {
+            return parallel::eval(expr, dest);
+        }
+    }
+    serial::eval(expr, dest, 0)
+}
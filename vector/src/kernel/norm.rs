@@ -0,0 +1,48 @@
+use num_traits::Float;
+
+/// The sum of `|x[i]|`.
+pub fn norm_l1<T: Float>(x: &[T]) -> T
+{
+    x
+        .iter()
+        .fold(T::zero(), |acc, xi| acc + xi.abs())
+}
+
+/// The largest `|x[i]|`, or zero for an empty `x`. A NaN anywhere in `x` makes the 
+/// result NaN, where `max` would quietly drop it.
+pub fn norm_inf<T: Float>(x: &[T]) -> T
+{
+    x
+        .iter()
+        .map(|xi| xi.abs())
+        .fold(T::zero(), |acc, a| if a > acc || a.is_nan() { a } else { acc })
+}
+
+/// `(sum |x[i]|^p)^(1/p)`, scaled by the largest element like `nrm2` so that 
+/// intermediate powers neither overflow nor underflow.
+pub fn norm_p<T: Float>(x: &[T], p: T) -> T
+{
+    assert!(p >= T::one(), "the p-norm needs p >= 1");
+
+    let scale: T = norm_inf(x);
+    if p.is_infinite() || scale.is_zero() {
+        return scale
+    }
+
+    let sum: T = x
+        .iter()
+        .fold(T::zero(), |acc, xi| acc + (xi.abs() / scale).powf(p));
+    scale * sum.powf(p.recip())
+}
+
+/// `acos(dot / (norm_x * norm_y))`, with rounding that pushes the cosine just past `±1` 
+/// clamped back. A zero vector has no angle, so its `0 / 0` comes through as NaN.
+pub fn angle<T: Float>(dot: T, norm_x: T, norm_y: T) -> T
+{
+    let cos: T = dot / (norm_x * norm_y);
+    if cos.is_nan() {
+        cos
+    } else {
+        cos.max(-T::one()).min(T::one()).acos()
+    }
+}
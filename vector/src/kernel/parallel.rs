@@ -0,0 +1,101 @@
+use std::ops::{AddAssign, SubAssign, Add, MulAssign, DivAssign, Mul, Neg};
+
+use rayon::prelude::*;
+
+use crate::expr::Expr;
+
+use super::{serial, CHUNK_SIZE};
+
+pub fn add_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: AddAssign<&'a T> + Send + Sync
+{
+    lhs
+        .par_chunks_mut(CHUNK_SIZE)
+        .zip(rhs.par_chunks(CHUNK_SIZE))
+        .for_each(|(l,r)| serial::add_assign(l, r));
+}
+
+pub fn sub_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: SubAssign<&'a T> + Send + Sync
+{
+    lhs
+        .par_chunks_mut(CHUNK_SIZE)
+        .zip(rhs.par_chunks(CHUNK_SIZE))
+        .for_each(|(l,r)| serial::sub_assign(l, r));
+}
+
+pub fn hadamard_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: MulAssign<&'a T> + Send + Sync
+{
+    lhs
+        .par_chunks_mut(CHUNK_SIZE)
+        .zip(rhs.par_chunks(CHUNK_SIZE))
+        .for_each(|(l,r)| serial::hadamard_assign(l, r));
+}
+
+pub fn hadamard_div_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: DivAssign<&'a T> + Send + Sync
+{
+    lhs
+        .par_chunks_mut(CHUNK_SIZE)
+        .zip(rhs.par_chunks(CHUNK_SIZE))
+        .for_each(|(l,r)| serial::hadamard_div_assign(l, r));
+}
+
+pub fn mul_assign<T>(vector: &mut [T], scalar: &T)
+where
+    for <'a> T: MulAssign<&'a T> + Send + Sync
+{
+    vector
+        .par_chunks_mut(CHUNK_SIZE)
+        .for_each(|chunk| serial::mul_assign(chunk, scalar));
+}
+
+pub fn neg_assign<T>(vector: &mut [T])
+where
+    T: Send + Sync,
+    for <'a> &'a T: Neg<Output=T>
+{
+    vector
+        .par_chunks_mut(CHUNK_SIZE)
+        .for_each(|chunk| serial::neg_assign(chunk));
+}
+
+pub fn axpy<T>(alpha: &T, x: &[T], y: &mut [T])
+where
+    T: AddAssign<T> + Send + Sync,
+    for <'a> &'a T: Mul<&'a T, Output=T>
+{
+    y
+        .par_chunks_mut(CHUNK_SIZE)
+        .zip(x.par_chunks(CHUNK_SIZE))
+        .for_each(|(y,x)| serial::axpy(alpha, x, y));
+}
+
+pub fn dot<T, F>(x: &[T], y: &[T], identity: F) -> T
+where
+    T: Add<T, Output=T> + Send + Sync,
+    for <'a> &'a T: Mul<&'a T, Output=T>,
+    F: Fn() -> T + Send + Sync
+{
+    x
+        .par_chunks(CHUNK_SIZE)
+        .zip(y.par_chunks(CHUNK_SIZE))
+        .map(|(x,y)| serial::dot(x, y, identity()))
+        .reduce(&identity, |acc, val| acc + val)
+}
+
+pub fn eval<E>(expr: &E, dest: &mut [E::Scalar])
+where
+    E: Expr + Sync,
+    E::Scalar: Send
+{
+    dest
+        .par_chunks_mut(CHUNK_SIZE)
+        .enumerate()
+        .for_each(|(chunk, d)| serial::eval(expr, d, chunk * CHUNK_SIZE));
+}
@@ -0,0 +1,117 @@
+use num_traits::Float;
+
+use crate::Layout;
+
+use super::verify;
+
+/// The Euclidean norm of `x`, accumulated with `hypot` so that it cannot overflow.
+fn hypot_norm<T: Float>(x: impl Iterator<Item=T>) -> T
+{
+    x.fold(T::zero(), |acc, xi| acc.hypot(xi))
+}
+
+/// Factors the `rows` by `columns` matrix in `a` in place into Householder reflectors, 
+/// as in LAPACK's `geqrf`, or `geqp3` when `jpvt` is given.
+/// 
+/// `R` is left on and above the diagonal and the essential part of the `k`th reflector 
+/// `H_k = I - tau[k] * v * v^T`, whose leading element is an implicit 1, below it. With 
+/// column pivoting the column of largest remaining norm is moved forward at each step, 
+/// so that `|R[k][k]|` is non-increasing, and `jpvt[j]` becomes the original index of 
+/// column `j`.
+pub fn geqrf<T: Float>(layout: Layout, (rows, columns): (usize, usize), a: &mut [T], tau: &mut [T], mut jpvt: Option<&mut [usize]>)
+{
+    verify(rows * columns, a.len());
+    verify(rows.min(columns), tau.len());
+
+    let (rs, cs) = layout.strides((rows, columns));
+
+    if let Some(jpvt) = jpvt.as_deref_mut() {
+        verify(columns, jpvt.len());
+        for (j, p) in jpvt.iter_mut().enumerate() {
+            *p = j;
+        }
+    }
+
+    for k in 0..rows.min(columns) {
+        if let Some(jpvt) = jpvt.as_deref_mut() {
+            let norm = |j: usize| hypot_norm((k..rows).map(|i| a[i * rs + j * cs]));
+            let (mut p, mut max) = (k, norm(k));
+            for j in (k + 1)..columns {
+                let candidate: T = norm(j);
+                if candidate > max {
+                    p = j;
+                    max = candidate;
+                }
+            }
+            if p != k {
+                for i in 0..rows {
+                    a.swap(i * rs + k * cs, i * rs + p * cs);
+                }
+                jpvt.swap(k, p);
+            }
+        }
+
+        let alpha: T = a[k * rs + k * cs];
+        let xnorm: T = hypot_norm(((k + 1)..rows).map(|i| a[i * rs + k * cs]));
+        if xnorm.is_zero() {
+            tau[k] = T::zero();
+            continue;
+        }
+
+        let beta: T = -alpha.signum() * alpha.hypot(xnorm);
+        tau[k] = (beta - alpha) / beta;
+        let scale: T = (alpha - beta).recip();
+        for i in (k + 1)..rows {
+            a[i * rs + k * cs] = a[i * rs + k * cs] * scale;
+        }
+        a[k * rs + k * cs] = beta;
+
+        for j in (k + 1)..columns {
+            let w: T = ((k + 1)..rows)
+                .fold(a[k * rs + j * cs], |acc, i| acc + a[i * rs + k * cs] * a[i * rs + j * cs]);
+            let tw: T = tau[k] * w;
+            a[k * rs + j * cs] = a[k * rs + j * cs] - tw;
+            for i in (k + 1)..rows {
+                a[i * rs + j * cs] = a[i * rs + j * cs] - tw * a[i * rs + k * cs];
+            }
+        }
+    }
+}
+
+/// Applies the reflectors left by `geqrf` to `x`: `Q^T * x` when `transpose` is set and 
+/// `Q * x` otherwise, without forming `Q`.
+pub fn ormqr<T: Float>(layout: Layout, (rows, columns): (usize, usize), qr: &[T], tau: &[T], x: &mut [T], transpose: bool)
+{
+    verify(rows * columns, qr.len());
+    verify(rows, x.len());
+
+    let (rs, cs) = layout.strides((rows, columns));
+    let reflect = |k: usize, x: &mut [T]| {
+        let w: T = ((k + 1)..rows).fold(x[k], |acc, i| acc + qr[i * rs + k * cs] * x[i]);
+        let tw: T = tau[k] * w;
+        x[k] = x[k] - tw;
+        for i in (k + 1)..rows {
+            x[i] = x[i] - tw * qr[i * rs + k * cs];
+        }
+    };
+
+    if transpose {
+        (0..tau.len()).for_each(|k| reflect(k, x));
+    } else {
+        (0..tau.len()).rev().for_each(|k| reflect(k, x));
+    }
+}
+
+/// Solves `R * x = b` in place for the leading `n` by `n` upper triangle `R` of the 
+/// `rows` by `columns` matrix in `r`, reading only the first `n` elements of `b`.
+pub fn trsv_upper<T: Float>(layout: Layout, (rows, columns): (usize, usize), r: &[T], n: usize, b: &mut [T])
+{
+    verify(rows * columns, r.len());
+    assert!(n <= rows.min(columns) && n <= b.len());
+
+    let (rs, cs) = layout.strides((rows, columns));
+    for i in (0..n).rev() {
+        let sum: T = ((i + 1)..n).fold(b[i], |acc, j| acc - r[i * rs + j * cs] * b[j]);
+        b[i] = sum / r[i * rs + i * cs];
+    }
+}
@@ -0,0 +1,462 @@
+use algebra::{Complex, Magnitude};
+use num_traits::Float;
+
+use crate::{Layout, NoConvergence};
+
+use super::verify;
+
+/// The number of Francis double-shift iterations allowed for each row of the matrix, 
+/// as in LAPACK's `lahqr`.
+const FRANCIS_ITERATIONS: usize = 30;
+
+/// Reduces the `n` by `n` matrix in `a` to upper Hessenberg form `Q^T * A * Q` with 
+/// Householder reflections, as in the `orthes` of EISPACK.
+/// 
+/// `a` is overwritten with the Hessenberg matrix, with zeros below the subdiagonal, and 
+/// `q` with the orthogonal `Q`.
+pub fn gehrd<T: Float>(layout: Layout, n: usize, a: &mut [T], q: &mut [T])
+{
+    verify(n * n, a.len());
+    verify(n * n, q.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    let at = |i: usize, j: usize| i * rs + j * cs;
+    let mut ort: Vec<T> = vec![T::zero(); n];
+
+    for m in 1..n.saturating_sub(1) {
+        let scale: T = (m..n).fold(T::zero(), |acc, i| acc + a[at(i, m - 1)].abs());
+        if scale.is_zero() {
+            continue;
+        }
+
+        let mut h: T = T::zero();
+        for i in (m..n).rev() {
+            ort[i] = a[at(i, m - 1)] / scale;
+            h = h + ort[i] * ort[i];
+        }
+        let g: T = if ort[m] > T::zero() { -h.sqrt() } else { h.sqrt() };
+        h = h - ort[m] * g;
+        ort[m] = ort[m] - g;
+
+        for j in m..n {
+            let f: T = (m..n).rev().fold(T::zero(), |acc, i| acc + ort[i] * a[at(i, j)]) / h;
+            for i in m..n {
+                a[at(i, j)] = a[at(i, j)] - f * ort[i];
+            }
+        }
+        for i in 0..n {
+            let f: T = (m..n).rev().fold(T::zero(), |acc, j| acc + ort[j] * a[at(i, j)]) / h;
+            for j in m..n {
+                a[at(i, j)] = a[at(i, j)] - f * ort[j];
+            }
+        }
+        ort[m] = scale * ort[m];
+        a[at(m, m - 1)] = scale * g;
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            q[at(i, j)] = if i == j { T::one() } else { T::zero() };
+        }
+    }
+    for m in (1..n.saturating_sub(1)).rev() {
+        if a[at(m, m - 1)].is_zero() {
+            continue;
+        }
+        for i in (m + 1)..n {
+            ort[i] = a[at(i, m - 1)];
+        }
+        for j in m..n {
+            let g: T = (m..n).fold(T::zero(), |acc, i| acc + ort[i] * q[at(i, j)]);
+            let g: T = (g / ort[m]) / a[at(m, m - 1)];
+            for i in m..n {
+                q[at(i, j)] = q[at(i, j)] + g * ort[i];
+            }
+        }
+    }
+
+    for j in 0..n {
+        for i in (j + 2)..n {
+            a[at(i, j)] = T::zero();
+        }
+    }
+}
+
+/// Reduces the `n` by `n` upper Hessenberg matrix in `h` to real Schur form with the 
+/// Francis double-shift QR algorithm, as in the `hqr2` of EISPACK.
+/// 
+/// `h` is overwritten with the quasi upper triangular `T`, whose diagonal blocks are 1x1 
+/// for real eigenvalues and 2x2 for complex conjugate pairs, and the transformations are 
+/// accumulated into the columns of `z`, which should hold the `Q` from `gehrd`, or the 
+/// identity, on entry. The eigenvalues are written to `wr` and `wi` in the order of the 
+/// diagonal of `T`, with the positive imaginary part first in each pair.
+pub fn hseqr<T: Float>(layout: Layout, n: usize, h: &mut [T], z: &mut [T], wr: &mut [T], wi: &mut [T]) -> Result<(), NoConvergence>
+{
+    verify(n * n, h.len());
+    verify(n * n, z.len());
+    verify(n, wr.len());
+    verify(n, wi.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    let at = |i: usize, j: usize| i * rs + j * cs;
+    let two: T = T::one() + T::one();
+    let eps: T = T::epsilon();
+
+    let norm: T = (0..n)
+        .flat_map(|i| (i.saturating_sub(1)..n).map(move |j| (i, j)))
+        .fold(T::zero(), |acc, (i, j)| acc + h[at(i, j)].abs());
+
+    let limit: usize = FRANCIS_ITERATIONS * n.max(10);
+    let mut iterations: usize = 0;
+    let mut iteration: usize = 0;
+    let mut exshift: T = T::zero();
+
+    // The rows and columns after `hi` have converged.
+    let mut remaining: usize = n;
+    while remaining > 0 {
+        let hi: usize = remaining - 1;
+
+        // Looks for a single small subdiagonal element.
+        let mut l: usize = hi;
+        while l > 0 {
+            let s: T = h[at(l - 1, l - 1)].abs() + h[at(l, l)].abs();
+            let s: T = if s.is_zero() { norm } else { s };
+            if h[at(l, l - 1)].abs() <= eps * s {
+                h[at(l, l - 1)] = T::zero();
+                break;
+            }
+            l -= 1;
+        }
+
+        if l == hi {
+            // A real eigenvalue has converged.
+            h[at(hi, hi)] = h[at(hi, hi)] + exshift;
+            wr[hi] = h[at(hi, hi)];
+            wi[hi] = T::zero();
+            remaining -= 1;
+            iteration = 0;
+        } else if l + 1 == hi {
+            // A 2x2 block has converged, and is split when its eigenvalues are real.
+            let w: T = h[at(hi, hi - 1)] * h[at(hi - 1, hi)];
+            let p: T = (h[at(hi - 1, hi - 1)] - h[at(hi, hi)]) / two;
+            let q: T = p * p + w;
+            let root: T = q.abs().sqrt();
+            h[at(hi, hi)] = h[at(hi, hi)] + exshift;
+            h[at(hi - 1, hi - 1)] = h[at(hi - 1, hi - 1)] + exshift;
+            let x: T = h[at(hi, hi)];
+
+            if q >= T::zero() {
+                let root: T = if p >= T::zero() { p + root } else { p - root };
+                wr[hi - 1] = x + root;
+                wr[hi] = if root.is_zero() { wr[hi - 1] } else { x - w / root };
+                wi[hi - 1] = T::zero();
+                wi[hi] = T::zero();
+
+                let x: T = h[at(hi, hi - 1)];
+                let s: T = x.abs() + root.abs();
+                let (p, q) = (x / s, root / s);
+                let r: T = p.hypot(q);
+                let (p, q) = (p / r, q / r);
+
+                for j in (hi - 1)..n {
+                    let t: T = h[at(hi - 1, j)];
+                    h[at(hi - 1, j)] = q * t + p * h[at(hi, j)];
+                    h[at(hi, j)] = q * h[at(hi, j)] - p * t;
+                }
+                for i in 0..=hi {
+                    let t: T = h[at(i, hi - 1)];
+                    h[at(i, hi - 1)] = q * t + p * h[at(i, hi)];
+                    h[at(i, hi)] = q * h[at(i, hi)] - p * t;
+                }
+                for i in 0..n {
+                    let t: T = z[at(i, hi - 1)];
+                    z[at(i, hi - 1)] = q * t + p * z[at(i, hi)];
+                    z[at(i, hi)] = q * z[at(i, hi)] - p * t;
+                }
+                h[at(hi, hi - 1)] = T::zero();
+            } else {
+                wr[hi - 1] = x + p;
+                wr[hi] = x + p;
+                wi[hi - 1] = root;
+                wi[hi] = -root;
+            }
+            remaining -= 2;
+            iteration = 0;
+        } else {
+            if iterations == limit {
+                return Err(NoConvergence { iterations })
+            }
+
+            let mut x: T = h[at(hi, hi)];
+            let mut y: T = h[at(hi - 1, hi - 1)];
+            let mut w: T = h[at(hi, hi - 1)] * h[at(hi - 1, hi)];
+
+            // Wilkinson's exceptional shift.
+            if iteration == 10 {
+                exshift = exshift + x;
+                for i in 0..=hi {
+                    h[at(i, i)] = h[at(i, i)] - x;
+                }
+                let s: T = h[at(hi, hi - 1)].abs() + h[at(hi - 1, hi - 2)].abs();
+                x = T::from(0.75).unwrap() * s;
+                y = x;
+                w = T::from(-0.4375).unwrap() * s * s;
+            }
+
+            // MATLAB's exceptional shift.
+            if iteration == 30 {
+                let s: T = (y - x) / two;
+                let s: T = s * s + w;
+                if s > T::zero() {
+                    let s: T = if y < x { -s.sqrt() } else { s.sqrt() };
+                    let s: T = x - w / ((y - x) / two + s);
+                    for i in 0..=hi {
+                        h[at(i, i)] = h[at(i, i)] - s;
+                    }
+                    exshift = exshift + s;
+                    x = T::from(0.964).unwrap();
+                    y = x;
+                    w = x;
+                }
+            }
+
+            iteration += 1;
+            iterations += 1;
+
+            // Looks for two consecutive small subdiagonal elements.
+            let mut m: usize = hi - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let hmm: T = h[at(m, m)];
+                let (rr, ss) = (x - hmm, y - hmm);
+                p = (rr * ss - w) / h[at(m + 1, m)] + h[at(m, m + 1)];
+                q = h[at(m + 1, m + 1)] - hmm - rr - ss;
+                r = h[at(m + 2, m + 1)];
+                let s: T = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+                if m == l {
+                    break;
+                }
+                let lhs: T = h[at(m, m - 1)].abs() * (q.abs() + r.abs());
+                let rhs: T = eps * (p.abs() * (h[at(m - 1, m - 1)].abs() + hmm.abs() + h[at(m + 1, m + 1)].abs()));
+                if lhs < rhs {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in (m + 2)..=hi {
+                h[at(i, i - 2)] = T::zero();
+                if i > m + 2 {
+                    h[at(i, i - 3)] = T::zero();
+                }
+            }
+
+            // The double QR step on rows l..=hi and columns m..=hi.
+            for k in m..hi {
+                let notlast: bool = k + 1 != hi;
+                let mut scale: T = T::zero();
+                if k != m {
+                    p = h[at(k, k - 1)];
+                    q = h[at(k + 1, k - 1)];
+                    r = if notlast { h[at(k + 2, k - 1)] } else { T::zero() };
+                    scale = p.abs() + q.abs() + r.abs();
+                    if scale.is_zero() {
+                        continue;
+                    }
+                    p = p / scale;
+                    q = q / scale;
+                    r = r / scale;
+                }
+
+                let s: T = (p * p + q * q + r * r).sqrt();
+                let s: T = if p < T::zero() { -s } else { s };
+                if s.is_zero() {
+                    continue;
+                }
+                if k != m {
+                    // The reflector annihilates the rest of the bulge in column `k - 1`.
+                    h[at(k, k - 1)] = -s * scale;
+                    h[at(k + 1, k - 1)] = T::zero();
+                    if notlast {
+                        h[at(k + 2, k - 1)] = T::zero();
+                    }
+                } else if l != m {
+                    h[at(k, k - 1)] = -h[at(k, k - 1)];
+                }
+                p = p + s;
+                let (x, y, zz) = (p / s, q / s, r / s);
+                q = q / p;
+                r = r / p;
+
+                for j in k..n {
+                    let mut p: T = h[at(k, j)] + q * h[at(k + 1, j)];
+                    if notlast {
+                        p = p + r * h[at(k + 2, j)];
+                        h[at(k + 2, j)] = h[at(k + 2, j)] - p * zz;
+                    }
+                    h[at(k, j)] = h[at(k, j)] - p * x;
+                    h[at(k + 1, j)] = h[at(k + 1, j)] - p * y;
+                }
+                for i in 0..=hi.min(k + 3) {
+                    let mut p: T = x * h[at(i, k)] + y * h[at(i, k + 1)];
+                    if notlast {
+                        p = p + zz * h[at(i, k + 2)];
+                        h[at(i, k + 2)] = h[at(i, k + 2)] - p * r;
+                    }
+                    h[at(i, k)] = h[at(i, k)] - p;
+                    h[at(i, k + 1)] = h[at(i, k + 1)] - p * q;
+                }
+                for i in 0..n {
+                    let mut p: T = x * z[at(i, k)] + y * z[at(i, k + 1)];
+                    if notlast {
+                        p = p + zz * z[at(i, k + 2)];
+                        z[at(i, k + 2)] = z[at(i, k + 2)] - p * r;
+                    }
+                    z[at(i, k)] = z[at(i, k)] - p;
+                    z[at(i, k + 1)] = z[at(i, k + 1)] - p * q;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Computes the eigenvectors of the `n` by `n` real Schur form in `t`, with eigenvalues 
+/// `wr` and `wi` from `hseqr`, by back substitution, as in the `hqr2` of EISPACK.
+/// 
+/// `t` is destroyed, and the Schur vectors in `z` are overwritten with the eigenvectors 
+/// of the original matrix, unnormalised. The eigenvector of a real eigenvalue is the 
+/// column of the same index, and for a complex pair `j, j + 1`, the eigenvector of 
+/// `wr[j] + i * wi[j]` has real part column `j` and imaginary part column `j + 1`, while 
+/// the other is its conjugate.
+pub fn trevc<T: Float + Magnitude>(layout: Layout, n: usize, t: &mut [T], z: &mut [T], wr: &[T], wi: &[T])
+{
+    verify(n * n, t.len());
+    verify(n * n, z.len());
+    verify(n, wr.len());
+    verify(n, wi.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    let at = |i: usize, j: usize| i * rs + j * cs;
+    let two: T = T::one() + T::one();
+    let eps: T = T::epsilon();
+
+    let norm: T = (0..n)
+        .flat_map(|i| (i.saturating_sub(1)..n).map(move |j| (i, j)))
+        .fold(T::zero(), |acc, (i, j)| acc + t[at(i, j)].abs());
+    if norm.is_zero() {
+        return
+    }
+
+    // The second row of a 2x2 block is solved together with the first, one step later.
+    let (mut zz, mut r, mut s) = (T::zero(), T::zero(), T::zero());
+    for en in (0..n).rev() {
+        let (p, q) = (wr[en], wi[en]);
+
+        if q.is_zero() {
+            // A real eigenvector.
+            let mut l: usize = en;
+            t[at(en, en)] = T::one();
+            for i in (0..en).rev() {
+                let w: T = t[at(i, i)] - p;
+                let ra: T = (l..=en).fold(T::zero(), |acc, j| acc + t[at(i, j)] * t[at(j, en)]);
+                if wi[i] < T::zero() {
+                    zz = w;
+                    s = ra;
+                    continue;
+                }
+                l = i;
+                if wi[i].is_zero() {
+                    t[at(i, en)] = -ra / if w.is_zero() { eps * norm } else { w };
+                } else {
+                    let x: T = t[at(i, i + 1)];
+                    let y: T = t[at(i + 1, i)];
+                    let denominator: T = (wr[i] - p) * (wr[i] - p) + wi[i] * wi[i];
+                    let v: T = (x * s - zz * ra) / denominator;
+                    t[at(i, en)] = v;
+                    t[at(i + 1, en)] = if x.abs() > zz.abs() { (-ra - w * v) / x } else { (-s - y * v) / zz };
+                }
+
+                // Rescales against overflow.
+                let v: T = t[at(i, en)].abs();
+                if (eps * v) * v > T::one() {
+                    for j in i..=en {
+                        t[at(j, en)] = t[at(j, en)] / v;
+                    }
+                }
+            }
+        } else if q < T::zero() {
+            // The complex eigenvector of the pair `en - 1, en`, with the imaginary part in 
+            // column `en`.
+            let mut l: usize = en - 1;
+            if t[at(en, en - 1)].abs() > t[at(en - 1, en)].abs() {
+                t[at(en - 1, en - 1)] = q / t[at(en, en - 1)];
+                t[at(en - 1, en)] = -(t[at(en, en)] - p) / t[at(en, en - 1)];
+            } else {
+                let Complex { re, im } = Complex::new(T::zero(), -t[at(en - 1, en)]) / Complex::new(t[at(en - 1, en - 1)] - p, q);
+                t[at(en - 1, en - 1)] = re;
+                t[at(en - 1, en)] = im;
+            }
+            t[at(en, en - 1)] = T::zero();
+            t[at(en, en)] = T::one();
+
+            for i in (0..en.saturating_sub(1)).rev() {
+                let ra: T = (l..=en).fold(T::zero(), |acc, j| acc + t[at(i, j)] * t[at(j, en - 1)]);
+                let sa: T = (l..=en).fold(T::zero(), |acc, j| acc + t[at(i, j)] * t[at(j, en)]);
+                let w: T = t[at(i, i)] - p;
+
+                if wi[i] < T::zero() {
+                    zz = w;
+                    r = ra;
+                    s = sa;
+                    continue;
+                }
+                l = i;
+                if wi[i].is_zero() {
+                    let Complex { re, im } = Complex::new(-ra, -sa) / Complex::new(w, q);
+                    t[at(i, en - 1)] = re;
+                    t[at(i, en)] = im;
+                } else {
+                    let x: T = t[at(i, i + 1)];
+                    let y: T = t[at(i + 1, i)];
+                    let mut vr: T = (wr[i] - p) * (wr[i] - p) + wi[i] * wi[i] - q * q;
+                    let vi: T = (wr[i] - p) * two * q;
+                    if vr.is_zero() && vi.is_zero() {
+                        vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + zz.abs());
+                    }
+                    let Complex { re, im } = Complex::new(x * r - zz * ra + q * sa, x * s - zz * sa - q * ra) / Complex::new(vr, vi);
+                    t[at(i, en - 1)] = re;
+                    t[at(i, en)] = im;
+                    if x.abs() > zz.abs() + q.abs() {
+                        t[at(i + 1, en - 1)] = (-ra - w * re + q * im) / x;
+                        t[at(i + 1, en)] = (-sa - w * im - q * re) / x;
+                    } else {
+                        let Complex { re, im } = Complex::new(-r - y * re, -s - y * im) / Complex::new(zz, q);
+                        t[at(i + 1, en - 1)] = re;
+                        t[at(i + 1, en)] = im;
+                    }
+                }
+
+                // Rescales against overflow.
+                let v: T = t[at(i, en - 1)].abs().max(t[at(i, en)].abs());
+                if (eps * v) * v > T::one() {
+                    for j in i..=en {
+                        t[at(j, en - 1)] = t[at(j, en - 1)] / v;
+                        t[at(j, en)] = t[at(j, en)] / v;
+                    }
+                }
+            }
+        }
+    }
+
+    // Transforms back by the Schur vectors, right to left so that `z` can be overwritten.
+    for j in (0..n).rev() {
+        for i in 0..n {
+            let v: T = (0..=j).fold(T::zero(), |acc, k| acc + z[at(i, k)] * t[at(k, j)]);
+            z[at(i, j)] = v;
+        }
+    }
+}
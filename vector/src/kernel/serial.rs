@@ -0,0 +1,95 @@
+use std::ops::{AddAssign, SubAssign, Add, MulAssign, DivAssign, Mul, Neg};
+
+use crate::expr::Expr;
+
+pub fn add_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: AddAssign<&'a T>
+{
+    lhs
+        .iter_mut()
+        .zip(rhs)
+        .for_each(|(l,r)| l.add_assign(r));
+}
+
+pub fn sub_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: SubAssign<&'a T>
+{
+    lhs
+        .iter_mut()
+        .zip(rhs)
+        .for_each(|(l,r)| l.sub_assign(r));
+}
+
+pub fn hadamard_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: MulAssign<&'a T>
+{
+    lhs
+        .iter_mut()
+        .zip(rhs)
+        .for_each(|(l,r)| l.mul_assign(r));
+}
+
+pub fn hadamard_div_assign<T>(lhs: &mut [T], rhs: &[T])
+where
+    for <'a> T: DivAssign<&'a T>
+{
+    lhs
+        .iter_mut()
+        .zip(rhs)
+        .for_each(|(l,r)| l.div_assign(r));
+}
+
+pub fn mul_assign<T>(vector: &mut [T], scalar: &T)
+where
+    for <'a> T: MulAssign<&'a T>
+{
+    vector
+        .iter_mut()
+        .for_each(|val| val.mul_assign(scalar));
+}
+
+pub fn neg_assign<T>(vector: &mut [T])
+where
+    for <'a> &'a T: Neg<Output=T>
+{
+    vector
+        .iter_mut()
+        .for_each(|val| *val = (&*val).neg());
+}
+
+pub fn axpy<T>(alpha: &T, x: &[T], y: &mut [T])
+where
+    T: AddAssign<T>,
+    for <'a> &'a T: Mul<&'a T, Output=T>
+{
+    for (l,r) in y
+        .iter_mut()
+        .zip(x)
+    {
+        l.add_assign( r * alpha )
+    }
+}
+
+pub fn dot<T>(x: &[T], y: &[T], acc: T) -> T
+where
+    T: Add<T, Output=T>,
+    for <'a> &'a T: Mul<&'a T, Output=T>
+{
+    x
+        .iter()
+        .zip(y)
+        .map(|(xi,yi)| xi * yi)
+        .fold(acc, |acc, val| acc + val)
+}
+
+/// `dest[i] = expr.at(offset + i)`.
+pub fn eval<E: Expr>(expr: &E, dest: &mut [E::Scalar], offset: usize)
+{
+    dest
+        .iter_mut()
+        .enumerate()
+        .for_each(|(idx, d)| *d = expr.at(offset + idx));
+}
@@ -0,0 +1,204 @@
+use std::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::{Layout, NotPositiveDefinite};
+
+use super::verify;
+
+/// Factors the symmetric positive definite `n` by `n` matrix in `a` in place into 
+/// `L * L^T`, as in LAPACK's `potrf`, reading and writing only the lower triangle.
+/// 
+/// Fails at the first step whose pivot is not positive, which leaves `a` partially 
+/// factored.
+pub fn potrf<T: Float>(layout: Layout, n: usize, a: &mut [T]) -> Result<(), NotPositiveDefinite>
+{
+    verify(n * n, a.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    for j in 0..n {
+        let d: T = (0..j).fold(a[j * rs + j * cs], |acc, k| acc - a[j * rs + k * cs] * a[j * rs + k * cs]);
+        if d.partial_cmp(&T::zero()) != Some(Ordering::Greater) {
+            return Err(NotPositiveDefinite { pivot: j })
+        }
+
+        let ljj: T = d.sqrt();
+        a[j * rs + j * cs] = ljj;
+        for i in (j + 1)..n {
+            let sum: T = (0..j).fold(a[i * rs + j * cs], |acc, k| acc - a[i * rs + k * cs] * a[j * rs + k * cs]);
+            a[i * rs + j * cs] = sum / ljj;
+        }
+    }
+    Ok(())
+}
+
+/// Solves `A * x = b` in place, given the factor `L` of `A` from `potrf`.
+pub fn potrs<T: Float>(layout: Layout, n: usize, l: &[T], b: &mut [T])
+{
+    verify(n * n, l.len());
+    verify(n, b.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    for i in 0..n {
+        let sum: T = (0..i).fold(b[i], |acc, k| acc - l[i * rs + k * cs] * b[k]);
+        b[i] = sum / l[i * rs + i * cs];
+    }
+    for i in (0..n).rev() {
+        let sum: T = ((i + 1)..n).fold(b[i], |acc, k| acc - l[k * rs + i * cs] * b[k]);
+        b[i] = sum / l[i * rs + i * cs];
+    }
+}
+
+/// A diagonal block of a Bunch–Kaufman factorisation, with the row and column that were 
+/// interchanged with its last row and column before it was eliminated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pivot {
+    OneByOne(usize),
+    TwoByTwo(usize)
+}
+
+/// Factors the symmetric `n` by `n` matrix in `a` in place into `L * D * L^T` with 
+/// Bunch–Kaufman pivoting, as in LAPACK's `sytf2`, reading and writing only the lower 
+/// triangle.
+/// 
+/// `D` is block diagonal with 1x1 and 2x2 blocks, left on the diagonal and first 
+/// subdiagonal, and the multipliers of `L` are left below the blocks. The returned 
+/// pivots describe the blocks in order. A block is only singular when its whole column 
+/// was already zero, in which case it is a zero 1x1 block.
+pub fn sytrf<T: Float>(layout: Layout, n: usize, a: &mut [T]) -> Vec<Pivot>
+{
+    verify(n * n, a.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    let at = |i: usize, j: usize| i * rs + j * cs;
+    let alpha: T = (T::one() + T::from(17.0).unwrap().sqrt()) / T::from(8.0).unwrap();
+    let mut pivots: Vec<Pivot> = Vec::with_capacity(n);
+
+    let mut k: usize = 0;
+    while k < n {
+        let absakk: T = a[at(k, k)].abs();
+        let (imax, colmax) = ((k + 1)..n)
+            .map(|i| (i, a[at(i, k)].abs()))
+            .fold((k, T::zero()), |max, candidate| if candidate.1 > max.1 { candidate } else { max });
+
+        let (kp, kstep) = if absakk.max(colmax).is_zero() || absakk >= alpha * colmax {
+            (k, 1)
+        } else {
+            let rowmax: T = (k..imax)
+                .map(|j| a[at(imax, j)].abs())
+                .chain(((imax + 1)..n).map(|i| a[at(i, imax)].abs()))
+                .fold(T::zero(), T::max);
+
+            if absakk >= alpha * colmax * (colmax / rowmax) {
+                (k, 1)
+            } else if a[at(imax, imax)].abs() >= alpha * rowmax {
+                (imax, 1)
+            } else {
+                (imax, 2)
+            }
+        };
+
+        let kk: usize = k + kstep - 1;
+        if kp != kk {
+            for i in (kp + 1)..n {
+                a.swap(at(i, kk), at(i, kp));
+            }
+            for j in (kk + 1)..kp {
+                a.swap(at(j, kk), at(kp, j));
+            }
+            a.swap(at(kk, kk), at(kp, kp));
+            if kstep == 2 {
+                a.swap(at(k + 1, k), at(kp, k));
+            }
+        }
+
+        if kstep == 1 {
+            pivots.push(Pivot::OneByOne(kp));
+            if !a[at(k, k)].is_zero() {
+                let r1: T = a[at(k, k)].recip();
+                for j in (k + 1)..n {
+                    let ajk: T = a[at(j, k)];
+                    for i in j..n {
+                        a[at(i, j)] = a[at(i, j)] - r1 * a[at(i, k)] * ajk;
+                    }
+                }
+                for i in (k + 1)..n {
+                    a[at(i, k)] = a[at(i, k)] * r1;
+                }
+            }
+        } else {
+            pivots.push(Pivot::TwoByTwo(kp));
+            let d21: T = a[at(k + 1, k)];
+            let d11: T = a[at(k + 1, k + 1)] / d21;
+            let d22: T = a[at(k, k)] / d21;
+            let t: T = (d11 * d22 - T::one()).recip();
+            let d21: T = t / d21;
+            for j in (k + 2)..n {
+                let wk: T = d21 * (d11 * a[at(j, k)] - a[at(j, k + 1)]);
+                let wkp1: T = d21 * (d22 * a[at(j, k + 1)] - a[at(j, k)]);
+                for i in j..n {
+                    a[at(i, j)] = a[at(i, j)] - a[at(i, k)] * wk - a[at(i, k + 1)] * wkp1;
+                }
+                a[at(j, k)] = wk;
+                a[at(j, k + 1)] = wkp1;
+            }
+        }
+        k += kstep;
+    }
+    pivots
+}
+
+/// Solves `A * x = b` in place, given the factors and pivots of `A` from `sytrf`, whose 
+/// blocks must all be nonsingular.
+pub fn sytrs<T: Float>(layout: Layout, n: usize, ldl: &[T], pivots: &[Pivot], b: &mut [T])
+{
+    verify(n * n, ldl.len());
+    verify(n, b.len());
+
+    let (rs, cs) = layout.strides((n, n));
+    let at = |i: usize, j: usize| i * rs + j * cs;
+
+    let mut k: usize = 0;
+    for pivot in pivots {
+        match *pivot {
+            Pivot::OneByOne(kp) => {
+                b.swap(k, kp);
+                for i in (k + 1)..n {
+                    b[i] = b[i] - ldl[at(i, k)] * b[k];
+                }
+                b[k] = b[k] / ldl[at(k, k)];
+                k += 1;
+            },
+            Pivot::TwoByTwo(kp) => {
+                b.swap(k + 1, kp);
+                for i in (k + 2)..n {
+                    b[i] = b[i] - ldl[at(i, k)] * b[k] - ldl[at(i, k + 1)] * b[k + 1];
+                }
+                let akm1k: T = ldl[at(k + 1, k)];
+                let akm1: T = ldl[at(k, k)] / akm1k;
+                let ak: T = ldl[at(k + 1, k + 1)] / akm1k;
+                let denom: T = akm1 * ak - T::one();
+                let (bkm1, bk) = (b[k] / akm1k, b[k + 1] / akm1k);
+                b[k] = (ak * bkm1 - bk) / denom;
+                b[k + 1] = (akm1 * bk - bkm1) / denom;
+                k += 2;
+            }
+        }
+    }
+
+    for pivot in pivots.iter().rev() {
+        match *pivot {
+            Pivot::OneByOne(kp) => {
+                k -= 1;
+                b[k] = ((k + 1)..n).fold(b[k], |acc, i| acc - ldl[at(i, k)] * b[i]);
+                b.swap(k, kp);
+            },
+            Pivot::TwoByTwo(kp) => {
+                k -= 2;
+                b[k + 1] = ((k + 2)..n).fold(b[k + 1], |acc, i| acc - ldl[at(i, k + 1)] * b[i]);
+                b[k] = ((k + 2)..n).fold(b[k], |acc, i| acc - ldl[at(i, k)] * b[i]);
+                b.swap(k + 1, kp);
+            }
+        }
+    }
+}
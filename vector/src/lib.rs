@@ -1,19 +1,54 @@
 mod array;
 mod space;
+mod bitpacked;
 
-#[allow(unused_macros)]
+mod matrix;
+pub use matrix::*;
+
+mod dynamic;
+pub use dynamic::*;
+
+mod array_space;
+pub use array_space::*;
+
+mod decomposition;
+pub use decomposition::*;
+
+mod error;
+pub use error::*;
 
+pub mod kernel;
+
+pub mod expr;
+
+#[doc(hidden)]
+pub use num_traits;
+
+#[allow(unused_macros)]
 pub enum Implements {
     BinOps,
     UniOps,
-    BlasOps
+    BlasOps,
+    NormOps,
+    ExteriorOps,
+    StdOps,
+    ExprOps,
+    DecompositionOps
 }
 
 pub enum BinOps {
     VAdd,
     VAddMut,
+    CheckedVAdd,
+    CheckedVAddMut,
     VScale,
-    VScaleMut
+    VScaleMut,
+    VSub,
+    VSubMut,
+    VHadamard,
+    VHadamardMut,
+    VHadamardDiv,
+    VHadamardDivMut
 }
 
 pub enum UniOps {
@@ -25,12 +60,134 @@ pub enum BlasOps {
     VAXPY,
     VAXPYMut,
     DotV,
-    DotVMut     
+    DotVMut,
+    CheckedVAXPY,
+    CheckedVAXPYMut,
+    CheckedDotV,
+    CheckedDotVMut,
+    DotC,
+    DotCMut,
+    HermitianInnerProduct,
+    ASumV,
+    Nrm2V,
+    IAMaxV,
+    SwapV,
+    CopyV,
+    ScalV,
+    RotV,
+    RotG,
+    RotMV,
+    RotMG,
+    GEMV,
+    GEMVMut,
+    GER,
+    GERMut,
+    GEMM,
+    GEMMMut
+}
+
+pub enum NormOps {
+    Norm,
+    NormedSpace,
+    InnerProductSpace
+}
+
+/// `VCross` and `VCrossMut` are added by default to spaces declared with `dimension: 3`.
+pub enum ExteriorOps {
+    VCross,
+    VCrossMut,
+    VWedge
+}
+
+/// Operator overloads on the generated vectors, which are opt-in.
+pub enum StdOps {
+    Add,
+    Sub,
+    Neg,
+    Mul,
+    AddAssign,
+    SubAssign,
+    MulAssign
+}
+
+/// Lazy expression templates, which are opt-in. See the `expr` module.
+pub enum ExprOps {
+    Expr
+}
+
+/// Factorisations and solvers for matrix spaces, which are opt-in.
+pub enum DecompositionOps {
+    LU,
+    Solve,
+    SolveMut,
+    Determinant,
+    Inverse,
+    QR,
+    LeastSquares,
+    Cholesky,
+    LDLT,
+    SymmetricEigen,
+    Hessenberg,
+    Schur,
+    Eigen
 }
 
 #[macro_export]
 macro_rules! vspace 
 {
+    /****************** Bit-packed GF(2) storage *******************/
+    (
+        $space:ident {
+            vector: $name:ident,
+            dimension: $length:expr,
+            using: BitPacked
+        }
+    ) => {
+        paste::item! {
+            pub use [< $space:lower >]::{$name, $space};
+
+            #[allow(unused_imports)]
+            mod [< $space:lower >]
+            {
+                use vector::vspace;
+    
+                vspace!(@imports);
+    
+                bitpacked_base!($length, $name);
+                bitpacked_space!($length, $name, $space);
+            }
+        }
+    };
+
+    /****************** Three-dimensional spaces *******************/
+    (
+        $space:ident {
+            vector: $name:ident,
+            dimension: 3
+        }
+    ) => {
+        vspace! {
+            $space {
+                vector: $name,
+                dimension: 3,
+                using: Vec<T>
+            }
+        }
+    };
+
+    (
+        $space:ident {
+            vector: $name:ident,
+            dimension: 3,
+            using: $inner:ty
+        }
+    ) => {
+        vspace!(@defaults $space, $name, 3, $inner; 
+            Implements::ExteriorOps::VCross,
+            Implements::ExteriorOps::VCrossMut
+        );
+    };
+
     /********************* Convenience DSL ************************/
     (
         $space:ident {
@@ -54,6 +211,10 @@ macro_rules! vspace
             using: $inner:ty
         }
     ) => {
+        vspace!(@defaults $space, $name, $length, $inner; );
+    };
+
+    (@defaults $space:ident, $name:ident, $length:expr, $inner:ty; $(Implements::$kind:ident::$branch:ident),*) => {
         vspace! {
             $space {
                 vector: $name,
@@ -61,14 +222,44 @@ macro_rules! vspace
                 using: $inner,
                 Implements::BinOps::VAddMut,
                 Implements::BinOps::VAdd,
+                Implements::BinOps::CheckedVAdd,
+                Implements::BinOps::CheckedVAddMut,
                 Implements::BinOps::VScale,
                 Implements::BinOps::VScaleMut,
+                Implements::BinOps::VSub,
+                Implements::BinOps::VSubMut,
+                Implements::BinOps::VHadamard,
+                Implements::BinOps::VHadamardMut,
+                Implements::BinOps::VHadamardDiv,
+                Implements::BinOps::VHadamardDivMut,
                 Implements::UniOps::VAdditiveInverse,
                 Implements::UniOps::VAdditiveInverseMut,
                 Implements::BlasOps::VAXPY,
                 Implements::BlasOps::VAXPYMut,
                 Implements::BlasOps::DotV,
-                Implements::BlasOps::DotVMut                
+                Implements::BlasOps::DotVMut,
+                Implements::BlasOps::CheckedVAXPY,
+                Implements::BlasOps::CheckedVAXPYMut,
+                Implements::BlasOps::CheckedDotV,
+                Implements::BlasOps::CheckedDotVMut,
+                Implements::BlasOps::DotC,
+                Implements::BlasOps::DotCMut,
+                Implements::BlasOps::HermitianInnerProduct,
+                Implements::BlasOps::ASumV,
+                Implements::BlasOps::Nrm2V,
+                Implements::BlasOps::IAMaxV,
+                Implements::BlasOps::SwapV,
+                Implements::BlasOps::CopyV,
+                Implements::BlasOps::ScalV,
+                Implements::BlasOps::RotV,
+                Implements::BlasOps::RotG,
+                Implements::BlasOps::RotMV,
+                Implements::BlasOps::RotMG,
+                Implements::NormOps::Norm,
+                Implements::NormOps::NormedSpace,
+                Implements::NormOps::InnerProductSpace,
+                Implements::ExteriorOps::VWedge
+                $(, Implements::$kind::$branch)*
             }
         }
     };
@@ -107,8 +298,139 @@ macro_rules! vspace
     };
 
     (@imports) => {
-        use std::{marker, fmt, ops, iter};
+        use std::{marker, fmt, ops, iter, convert};
+        use iter::FromIterator;
+        use convert::TryFrom;
+        use marker::PhantomData;
+        use fmt::Debug;
+        use ops::{AddAssign, Add, MulAssign, Mul, Index, IndexMut, Neg};
+
+        use algebra::*;
+        use vector::*;
+    };
+} 
+
+#[macro_export]
+macro_rules! mspace 
+{
+    /********************* Convenience DSL ************************/
+    (
+        $space:ident {
+            matrix: $name:ident,
+            rows: $rows:expr,
+            columns: $columns:expr
+        }
+    ) => {
+        mspace! {
+            $space {
+                matrix: $name,
+                rows: $rows,
+                columns: $columns,
+                layout: RowMajor
+            }
+        }
+    };
+
+    (
+        $space:ident {
+            matrix: $name:ident,
+            rows: $rows:expr,
+            columns: $columns:expr,
+            layout: $layout:ident
+        }
+    ) => {
+        mspace! {
+            $space {
+                matrix: $name,
+                rows: $rows,
+                columns: $columns,
+                layout: $layout,
+                using: Vec<T>
+            }
+        }
+    };
+
+    (
+        $space:ident {
+            matrix: $name:ident,
+            rows: $rows:expr,
+            columns: $columns:expr,
+            layout: $layout:ident,
+            using: $inner:ty
+        }
+    ) => {
+        mspace! {
+            $space {
+                matrix: $name,
+                rows: $rows,
+                columns: $columns,
+                layout: $layout,
+                using: $inner,
+                Implements::BinOps::VAddMut,
+                Implements::BinOps::VAdd,
+                Implements::BinOps::VScale,
+                Implements::BinOps::VScaleMut,
+                Implements::BinOps::VSub,
+                Implements::BinOps::VSubMut,
+                Implements::BinOps::VHadamard,
+                Implements::BinOps::VHadamardMut,
+                Implements::BinOps::VHadamardDiv,
+                Implements::BinOps::VHadamardDivMut,
+                Implements::UniOps::VAdditiveInverse,
+                Implements::UniOps::VAdditiveInverseMut,
+                Implements::BlasOps::VAXPY,
+                Implements::BlasOps::VAXPYMut,
+                Implements::BlasOps::DotV,
+                Implements::BlasOps::DotVMut,
+                Implements::BlasOps::GEMV,
+                Implements::BlasOps::GEMVMut,
+                Implements::BlasOps::GER,
+                Implements::BlasOps::GERMut,
+                Implements::BlasOps::GEMM,
+                Implements::BlasOps::GEMMMut
+            }
+        }
+    };
+
+    /********************* Implementation ************************/
+    (
+        $space:ident {
+            matrix: $name:ident,
+            rows: $rows:expr,
+            columns: $columns:expr,
+            layout: $layout:ident,
+            using: $inner:ty,
+            $(Implements::$kind:ident::$branch:ident),*
+        }
+    ) => {
+        paste::item! {
+            pub use [< $space:lower >]::{$name, $space};
+
+            #[allow(unused_imports)]
+            mod [< $space:lower >]
+            {
+                use vector::mspace;
+    
+                mspace!(@imports);
+    
+                matrix_base!($rows, $columns, $layout, $name, $inner, T);
+                matrixspace!($rows, $columns, $name, $space, $inner, T);    
+
+                mspace!(@implements $name, $space, $($kind, $branch),*);
+            }
+        }
+    };
+
+    (@implements $name:ident, $space:ident, $($kind:ident, $branch:ident),*) => {
+        $(
+            $kind!(@ $branch $name, $space);
+        )*      
+    };
+
+    (@imports) => {
+        use std::{marker, fmt, ops, iter, convert};
         use iter::FromIterator;
+        use convert::TryFrom;
         use marker::PhantomData;
         use fmt::Debug;
         use ops::{AddAssign, Add, MulAssign, Mul, Index, IndexMut, Neg};
@@ -0,0 +1,9 @@
+use super::Layout;
+
+/// A matrix whose elements are stored contiguously in the order given by its `Layout`.
+pub trait Dense
+{
+    fn shape(&self) -> (usize, usize);
+
+    fn layout(&self) -> Layout;
+}
@@ -0,0 +1,68 @@
+use std::{iter, slice};
+
+/// An iterator over a single row or column of a matrix.
+pub type Strided<'a, T> = iter::Take<iter::StepBy<slice::Iter<'a, T>>>;
+
+/// The order in which the elements of a matrix are stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layout {
+    RowMajor,
+    ColumnMajor
+}
+
+impl Layout
+{
+    /// The layout under which the same storage reads as the transposed matrix.
+    #[inline]
+    pub fn transposed(&self) -> Layout
+    {
+        match self {
+            Layout::RowMajor => Layout::ColumnMajor,
+            Layout::ColumnMajor => Layout::RowMajor
+        }
+    }
+
+    /// The strides between neighbouring rows and neighbouring columns respectively.
+    #[inline]
+    pub fn strides(&self, (rows, columns): (usize, usize)) -> (usize, usize)
+    {
+        match self {
+            Layout::RowMajor => (columns, 1),
+            Layout::ColumnMajor => (1, rows)
+        }
+    }
+
+    /// The position of `(row, column)` in the storage of a matrix with the given shape.
+    #[inline]
+    pub fn offset(&self, (row, column): (usize, usize), shape: (usize, usize)) -> usize
+    {
+        assert!(
+            row < shape.0 && column < shape.1, 
+            "index ({}, {}) is out of bounds for a {}x{} matrix", row, column, shape.0, shape.1
+        );
+        let (row_stride, column_stride) = self.strides(shape);
+        row * row_stride + column * column_stride
+    }
+
+    /// Iterates over the elements of `row`.
+    pub fn row<'a, T>(&self, storage: &'a [T], row: usize, shape: (usize, usize)) -> Strided<'a, T>
+    {
+        assert!(row < shape.0, "row {} is out of bounds for a {}x{} matrix", row, shape.0, shape.1);
+        let (row_stride, column_stride) = self.strides(shape);
+        storage[row * row_stride..]
+            .iter()
+            .step_by(column_stride.max(1))
+            .take(shape.1)
+    }
+
+    /// Iterates over the elements of `column`.
+    pub fn column<'a, T>(&self, storage: &'a [T], column: usize, shape: (usize, usize)) -> Strided<'a, T>
+    {
+        assert!(column < shape.1, "column {} is out of bounds for a {}x{} matrix", column, shape.0, shape.1);
+        let (row_stride, column_stride) = self.strides(shape);
+        storage[column * column_stride..]
+            .iter()
+            .step_by(row_stride.max(1))
+            .take(shape.0)
+    }
+}
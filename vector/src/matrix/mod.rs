@@ -0,0 +1,8 @@
+mod structural;
+mod space;
+
+mod layout;
+pub use layout::*;
+
+mod dense;
+pub use dense::*;
@@ -0,0 +1,51 @@
+#[macro_export]
+macro_rules! matrixspace {
+    ($rows:expr, $columns:expr, $name:ident, $space:ident, $inner:ty, $T:ident) => {
+        pub struct $space<$T> {
+            _phantom: PhantomData<$T>
+        }
+
+        impl<$T> $space<$T>
+        {
+            #[inline]
+            pub fn new() -> Self 
+            {
+                $space {
+                    _phantom: PhantomData
+                }
+            }
+
+            /// The number of elements of every matrix in the space.
+            #[inline]
+            pub fn dimension(&self) -> usize
+            {
+                $rows * $columns
+            }
+
+            /// Checks the length of an operand in debug builds or with the `strict` feature; 
+            /// the matrix types already hold `$rows * $columns` elements.
+            #[inline]
+            #[track_caller]
+            fn verify(&self, found: usize)
+            {
+                $crate::kernel::verify(self.dimension(), found)
+            }
+        }
+
+        impl<$T> MatrixSpace for $space<$T>
+        {
+            type Scalar = $T;
+
+            type Matrix = $name<$T>;
+        }
+
+        impl<$T> VectorSpace for $space<$T>
+        {
+            type Scalar = $T;
+
+            type Vector = $name<$T>;
+        }
+
+        $crate::vectorspace!(@Compare [$T] $space<$T>, $name<$T>);
+    };
+}
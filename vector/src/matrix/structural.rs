@@ -0,0 +1,208 @@
+#[macro_export]
+macro_rules! matrix_base {
+    ($rows:expr, $columns:expr, $layout:ident, $name:ident, $inner:ty, $T:ident) => {
+        #[derive(Clone)]
+        pub struct $name<$T>($inner);
+
+        impl<$T> $name<$T>
+        {
+            pub const ROWS: usize = $rows;
+
+            pub const COLUMNS: usize = $columns;
+
+            pub const LAYOUT: Layout = Layout::$layout;
+
+            pub const DIMENSION: usize = $rows * $columns;
+
+            /// Wraps the storage of a matrix, whose elements are given in the order of `LAYOUT`.
+            pub fn new(inner: $inner) -> Self 
+            {
+                assert!(inner.len() == $rows * $columns);
+                $name(inner)
+            }
+
+            pub fn try_new(inner: $inner) -> Result<Self, DimensionMismatch>
+            {
+                DimensionMismatch::check($rows * $columns, inner.len())?;
+                Ok($name(inner))
+            }
+
+            pub fn try_from_iter<I>(iterator: I) -> Result<Self, DimensionMismatch>
+            where
+                I: IntoIterator<Item=$T>,
+                $inner: FromIterator<$T>
+            {
+                let buf: $inner = iterator
+                    .into_iter()
+                    .collect();
+                Self::try_new(buf)
+            }
+
+            /// A matrix from its rows, which are stored in the order of `LAYOUT`.
+            pub fn from_rows(rows: [[$T; $columns]; $rows]) -> Self
+            where
+                $T: Clone,
+                $inner: FromIterator<$T>
+            {
+                (0..$rows * $columns)
+                    .map(|k| match Self::LAYOUT {
+                        Layout::RowMajor => rows[k / $columns][k % $columns].clone(),
+                        Layout::ColumnMajor => rows[k % $rows][k / $rows].clone()
+                    })
+                    .collect()
+            }
+
+            #[inline]
+            pub fn shape(&self) -> (usize, usize)
+            {
+                (Self::ROWS, Self::COLUMNS)
+            }
+
+            pub fn row(&self, row: usize) -> Strided<'_, $T>
+            {
+                Self::LAYOUT.row(&self.0[..], row, self.shape())
+            }
+
+            pub fn column(&self, column: usize) -> Strided<'_, $T>
+            {
+                Self::LAYOUT.column(&self.0[..], column, self.shape())
+            }
+
+            pub fn rows(&self) -> impl Iterator<Item=Strided<'_, $T>>
+            {
+                (0..Self::ROWS).map(move |row| self.row(row))
+            }
+
+            pub fn columns(&self) -> impl Iterator<Item=Strided<'_, $T>>
+            {
+                (0..Self::COLUMNS).map(move |column| self.column(column))
+            }
+        }
+
+        impl<$T> Dense for $name<$T>
+        {
+            #[inline]
+            fn shape(&self) -> (usize, usize)
+            {
+                (Self::ROWS, Self::COLUMNS)
+            }
+
+            #[inline]
+            fn layout(&self) -> Layout
+            {
+                Self::LAYOUT
+            }
+        }
+
+        impl<$T> TryFrom<$inner> for $name<$T>
+        {
+            type Error = DimensionMismatch;
+
+            fn try_from(inner: $inner) -> Result<Self, Self::Error> {
+                Self::try_new(inner)
+            }
+        }
+
+        impl<'a,$T> TryFrom<&'a [$T]> for $name<$T>
+        where
+            $inner: TryFrom<&'a [$T]>
+        {
+            type Error = DimensionMismatch;
+
+            fn try_from(slice: &'a [$T]) -> Result<Self, Self::Error> {
+                DimensionMismatch::check($rows * $columns, slice.len())?;
+                let inner: $inner = <$inner>::try_from(slice)
+                    .map_err(|_| DimensionMismatch::new($rows * $columns, slice.len()))?;
+                Self::try_new(inner)
+            }
+        }
+
+        impl<'a,$T> IntoIterator for &'a $name<$T>
+        {
+            type Item = &'a $T;
+            type IntoIter = std::slice::Iter<'a,$T>;
+
+            fn into_iter(self) -> Self::IntoIter
+            {
+                self.0.iter()
+            }
+        }
+
+        impl<$T> FromIterator<$T> for $name<$T>
+        where
+            $inner: FromIterator<$T>
+        {
+            fn from_iter<I>(iterator: I) -> Self
+            where
+                I: IntoIterator<Item=$T>
+            {
+                let buf: $inner = iterator
+                    .into_iter()
+                    .collect();
+                Self::new(buf)
+            }
+        }
+
+        impl<'a,$T> FromIterator<&'a $T> for $name<$T>
+        where
+            $inner: FromIterator<&'a $T>
+        {
+            fn from_iter<I>(iterator: I) -> Self
+            where
+                I: IntoIterator<Item=&'a $T>
+            {
+                let buf: $inner = iterator
+                    .into_iter()
+                    .collect();
+                Self::new(buf)
+            }
+        }
+
+        impl<$T> AsRef<[$T]> for $name<$T>
+        {
+            fn as_ref(&self) -> &[$T]
+            {
+                &self.0[..]
+            }
+        }
+
+        impl<$T> AsMut<[$T]> for $name<$T>
+        {
+            fn as_mut(&mut self) -> &mut [$T]
+            {
+                &mut self.0[..]
+            }
+        }
+
+        impl<$T> Index<(usize, usize)> for $name<$T>
+        {
+            type Output = $T;
+
+            fn index(&self, idx: (usize, usize)) -> &Self::Output 
+            {
+                &self.0[Self::LAYOUT.offset(idx, self.shape())]
+            }
+        }
+
+        impl<$T> IndexMut<(usize, usize)> for $name<$T>
+        {
+            fn index_mut(&mut self, idx: (usize, usize)) -> &mut Self::Output 
+            {
+                let offset: usize = Self::LAYOUT.offset(idx, self.shape());
+                &mut self.0[offset]
+            }
+        }
+
+        impl<$T> Debug for $name<$T>
+        where
+            $T: Debug
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+            {
+                f.debug_list()
+                    .entries(self.rows().map(|row| row.collect::<Vec<_>>()))
+                    .finish()
+            }
+        }
+    };
+}
@@ -1,11 +1,11 @@
 #[macro_export]
 macro_rules! BinOps {
-    (@VAdd $name:ident, $space:ident) => {
-        impl<T> VAdd for $space<T>
+    (@VAdd [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VAdd for $space
         where
-            for <'a> T: Copy + AddAssign<&'a T>,
+            for <'a> T: Copy + AddAssign<&'a T> + $crate::kernel::Shareable,
         {
-            type Vector = $name<T>;
+            type Vector = $vector;
             
             fn vadd(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector
             {
@@ -16,30 +16,65 @@ macro_rules! BinOps {
         }
     };
 
-    (@VAddMut $name:ident, $space:ident) => {
-        impl<T> VAddMut for $space<T>
+    (@VAddMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VAddMut for $space
         where
-            for <'a> T: Copy + AddAssign<&'a T>,
+            for <'a> T: Copy + AddAssign<&'a T> + $crate::kernel::Shareable,
         {
-            type Vector = $name<T>;
+            type Vector = $vector;
             
             fn vadd_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector)
             {
-                lhs
-                    .0
-                    .iter_mut()
-                    .zip(rhs)
-                    .for_each(|(l,r)| l.add_assign(r));
+                self.verify(lhs.0.len());
+                self.verify(rhs.0.len());
+                $crate::kernel::add_assign::<T>(&mut lhs.0[..], &rhs.0[..]);
             }
         }
     };
 
-    (@VScale $name:ident, $space:ident) => {
-        impl<T> VScale for $space<T>
+    (@CheckedVAdd [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> CheckedVAdd for $space
         where
-            for <'a> T: Copy + MulAssign<&'a T>,
+            for <'a> T: Copy + AddAssign<&'a T> + $crate::kernel::Shareable,
         {
-            type Vector = $name<T>;
+            type Vector = $vector;
+
+            type Error = DimensionMismatch;
+            
+            fn checked_vadd(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Result<Self::Vector, Self::Error>
+            {
+                let mut buf: Self::Vector = lhs.clone();
+                self.checked_vadd_mut(&mut buf, rhs)?;
+                Ok(buf)
+            }
+        }
+    };
+
+    (@CheckedVAddMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> CheckedVAddMut for $space
+        where
+            for <'a> T: Copy + AddAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Vector = $vector;
+
+            type Error = DimensionMismatch;
+            
+            fn checked_vadd_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector) -> Result<(), Self::Error>
+            {
+                DimensionMismatch::check(self.dimension(), lhs.0.len())?;
+                DimensionMismatch::check(self.dimension(), rhs.0.len())?;
+                $crate::kernel::add_assign::<T>(&mut lhs.0[..], &rhs.0[..]);
+                Ok(())
+            }
+        }
+    };
+
+    (@VScale [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VScale for $space
+        where
+            for <'a> T: Copy + MulAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Vector = $vector;
 
             type Scalar = T;
 
@@ -52,22 +87,120 @@ macro_rules! BinOps {
         }
     };
 
-    (@VScaleMut $name:ident, $space:ident) => {
-        impl<T> VScaleMut for $space<T>
+    (@VScaleMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VScaleMut for $space
         where
-            for <'a> T: Copy + MulAssign<&'a T>,
+            for <'a> T: Copy + MulAssign<&'a T> + $crate::kernel::Shareable,
         {
-            type Vector = $name<T>;
+            type Vector = $vector;
 
             type Scalar = T;
 
             fn vscale_mut(&self, vector: &mut Self::Vector, scalar: &Self::Scalar)
             {
-                vector
-                    .0
-                    .iter_mut()
-                    .for_each(|val| val.mul_assign(scalar));
+                self.verify(vector.0.len());
+                $crate::kernel::mul_assign::<T>(&mut vector.0[..], scalar);
             }
         }
     };
+
+    (@VSub [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VSub for $space
+        where
+            for <'a> T: Copy + ops::SubAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Vector = $vector;
+            
+            fn vsub(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector
+            {
+                let mut buf: Self::Vector = lhs.clone();
+                self.vsub_mut(&mut buf, rhs);
+                buf
+            }
+        }
+    };
+
+    (@VSubMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VSubMut for $space
+        where
+            for <'a> T: Copy + ops::SubAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Vector = $vector;
+            
+            fn vsub_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector)
+            {
+                self.verify(lhs.0.len());
+                self.verify(rhs.0.len());
+                $crate::kernel::sub_assign::<T>(&mut lhs.0[..], &rhs.0[..]);
+            }
+        }
+    };
+
+    (@VHadamard [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VHadamard for $space
+        where
+            for <'a> T: Copy + MulAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Vector = $vector;
+            
+            fn vhadamard(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector
+            {
+                let mut buf: Self::Vector = lhs.clone();
+                self.vhadamard_mut(&mut buf, rhs);
+                buf
+            }
+        }
+    };
+
+    (@VHadamardMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VHadamardMut for $space
+        where
+            for <'a> T: Copy + MulAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Vector = $vector;
+            
+            fn vhadamard_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector)
+            {
+                self.verify(lhs.0.len());
+                self.verify(rhs.0.len());
+                $crate::kernel::hadamard_assign::<T>(&mut lhs.0[..], &rhs.0[..]);
+            }
+        }
+    };
+
+    (@VHadamardDiv [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VHadamardDiv for $space
+        where
+            for <'a> T: Copy + ops::DivAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Vector = $vector;
+            
+            fn vhadamard_div(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector
+            {
+                let mut buf: Self::Vector = lhs.clone();
+                self.vhadamard_div_mut(&mut buf, rhs);
+                buf
+            }
+        }
+    };
+
+    (@VHadamardDivMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VHadamardDivMut for $space
+        where
+            for <'a> T: Copy + ops::DivAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Vector = $vector;
+            
+            fn vhadamard_div_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector)
+            {
+                self.verify(lhs.0.len());
+                self.verify(rhs.0.len());
+                $crate::kernel::hadamard_div_assign::<T>(&mut lhs.0[..], &rhs.0[..]);
+            }
+        }
+    };
+
+    (@$branch:ident $name:ident, $space:ident) => {
+        $crate::BinOps!(@$branch [T] $space<T>, $name<T>);
+    };
 }
@@ -1,12 +1,12 @@
 #[macro_export]
 macro_rules! BlasOps {
-    (@VAXPY $name:ident, $space:ident) => {
-        impl<T> VAXPY for $space<T>
+    (@VAXPY [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VAXPY for $space
         where
-            T: Copy + AddAssign<T>,
+            T: Copy + AddAssign<T> + $crate::kernel::Shareable,
             for <'a> &'a T: Mul<&'a T, Output=T>,
         {
-            type Vector = $name<T>;
+            type Vector = $vector;
 
             type Scalar = T;
 
@@ -19,41 +19,39 @@ macro_rules! BlasOps {
         }
     };
 
-    (@VAXPYMut $name:ident, $space:ident) => {
-        impl<T> VAXPYMut for $space<T>
+    (@VAXPYMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VAXPYMut for $space
         where
-            T: AddAssign<T>,
+            T: AddAssign<T> + $crate::kernel::Shareable,
             for <'a> &'a T: Mul<&'a T, Output=T>,
         {
-            type Vector = $name<T>;
+            type Vector = $vector;
 
             type Scalar = T;
 
             fn vaxpy_mut(&self, a: &Self::Scalar, x: &Self::Vector, y: &mut Self::Vector)
             {
-                for (l,r) in y
-                    .0
-                    .iter_mut()
-                    .zip(x)
-                {
-                    l.add_assign( r * a )
-                }
+                self.verify(x.0.len());
+                self.verify(y.0.len());
+                $crate::kernel::axpy::<T>(a, &x.0[..], &mut y.0[..]);
             }
         }
     };
 
-    (@DotVMut $name:ident, $space:ident) => {
-        impl<T> DotVMut for $space<T>
+    (@DotVMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> DotVMut for $space
         where
             T: AddAssign<T>,
             for <'a> &'a T: Mul<&'a T,Output=T>
         {
-            type Vector = $name<T>;
+            type Vector = $vector;
 
             type Scalar = T;
         
             fn dotv_mut(&self, x: &Self::Vector, y: &Self::Vector, output: &mut Self::Scalar)
             {
+                self.verify(x.0.len());
+                self.verify(y.0.len());
                 x
                     .into_iter()
                     .zip(y) 
@@ -62,25 +60,460 @@ macro_rules! BlasOps {
         }
     };
 
-    (@DotV $name:ident, $space:ident) => {
-        impl<T> DotV for $space<T>
+    (@DotV [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> DotV for $space
         where
-            T: Add<T,Output=T> + AdditiveIdentity<Output=T>,
+            T: Add<T,Output=T> + AdditiveIdentity<Output=T> + $crate::kernel::Shareable,
             for <'a> &'a T: Mul<&'a T,Output=T>,
         {
-            type Vector = $name<T>;
+            type Vector = $vector;
 
             type Scalar = T;
         
             fn dotv(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar
             {
-                let acc: Self::Scalar = T::additive_identity();
+                self.verify(x.0.len());
+                self.verify(y.0.len());
+                $crate::kernel::dot::<T, _>(&x.0[..], &y.0[..], T::additive_identity)
+            }
+        }
+    };
+
+    (@CheckedVAXPY [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> CheckedVAXPY for $space
+        where
+            T: Copy + AddAssign<T> + $crate::kernel::Shareable,
+            for <'a> &'a T: Mul<&'a T, Output=T>,
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            type Error = DimensionMismatch;
+
+            fn checked_vaxpy(&self, alpha: &Self::Scalar, x: &Self::Vector, y: &Self::Vector) -> Result<Self::Vector, Self::Error>
+            {
+                let mut output: Self::Vector = y.clone();
+                self.checked_vaxpy_mut(alpha, x, &mut output)?;
+                Ok(output)
+            }
+        }
+    };
+
+    (@CheckedVAXPYMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> CheckedVAXPYMut for $space
+        where
+            T: AddAssign<T> + $crate::kernel::Shareable,
+            for <'a> &'a T: Mul<&'a T, Output=T>,
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            type Error = DimensionMismatch;
+
+            fn checked_vaxpy_mut(&self, a: &Self::Scalar, x: &Self::Vector, y: &mut Self::Vector) -> Result<(), Self::Error>
+            {
+                DimensionMismatch::check(self.dimension(), x.0.len())?;
+                DimensionMismatch::check(self.dimension(), y.0.len())?;
+                $crate::kernel::axpy::<T>(a, &x.0[..], &mut y.0[..]);
+                Ok(())
+            }
+        }
+    };
+
+    (@CheckedDotVMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> CheckedDotVMut for $space
+        where
+            T: AddAssign<T>,
+            for <'a> &'a T: Mul<&'a T,Output=T>
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            type Error = DimensionMismatch;
+        
+            fn checked_dotv_mut(&self, x: &Self::Vector, y: &Self::Vector, output: &mut Self::Scalar) -> Result<(), Self::Error>
+            {
+                DimensionMismatch::check(self.dimension(), x.0.len())?;
+                DimensionMismatch::check(self.dimension(), y.0.len())?;
+                x
+                    .into_iter()
+                    .zip(y) 
+                    .for_each(|(xi,yi)| output.add_assign(xi * yi));
+                Ok(())
+            }
+        }
+    };
+
+    (@CheckedDotV [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> CheckedDotV for $space
+        where
+            T: Add<T,Output=T> + AdditiveIdentity<Output=T> + $crate::kernel::Shareable,
+            for <'a> &'a T: Mul<&'a T,Output=T>,
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            type Error = DimensionMismatch;
+        
+            fn checked_dotv(&self, x: &Self::Vector, y: &Self::Vector) -> Result<Self::Scalar, Self::Error>
+            {
+                DimensionMismatch::check(self.dimension(), x.0.len())?;
+                DimensionMismatch::check(self.dimension(), y.0.len())?;
+                Ok($crate::kernel::dot::<T, _>(&x.0[..], &y.0[..], T::additive_identity))
+            }
+        }
+    };
+
+    (@DotC [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> DotC for $space
+        where
+            T: Add<T,Output=T> + AdditiveIdentity<Output=T> + Conjugate<Output=T>,
+            for <'a> &'a T: Mul<&'a T,Output=T>,
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            fn dotc(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar
+            {
+                self.verify(x.0.len());
+                self.verify(y.0.len());
+                $crate::kernel::dotc::<T>(&x.0[..], &y.0[..], T::additive_identity())
+            }
+        }
+    };
+
+    (@DotCMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> DotCMut for $space
+        where
+            T: AddAssign<T> + Conjugate<Output=T>,
+            for <'a> &'a T: Mul<&'a T,Output=T>
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            fn dotc_mut(&self, x: &Self::Vector, y: &Self::Vector, output: &mut Self::Scalar)
+            {
+                self.verify(x.0.len());
+                self.verify(y.0.len());
                 x
                     .into_iter()
                     .zip(y)
-                    .map(|(xi,yi)| xi * yi)
-                    .fold(acc, |acc, val| acc + val)          
+                    .for_each(|(xi,yi)| output.add_assign(&xi.conj() * yi));
+            }
+        }
+    };
+
+    (@HermitianInnerProduct [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> HermitianInnerProduct for $space
+        where
+            T: Add<T,Output=T> + AdditiveIdentity<Output=T> + Conjugate<Output=T>,
+            for <'a> &'a T: Mul<&'a T,Output=T>,
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            fn inner(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar
+            {
+                self.verify(x.0.len());
+                self.verify(y.0.len());
+                $crate::kernel::dotc::<T>(&x.0[..], &y.0[..], T::additive_identity())
+            }
+        }
+    };
+
+    (@ASumV [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> ASumV for $space
+        where
+            T: $crate::num_traits::Signed
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            fn asumv(&self, x: &Self::Vector) -> Self::Scalar
+            {
+                self.verify(x.0.len());
+                $crate::kernel::asum::<T>(&x.0[..])
+            }
+        }
+    };
+
+    (@Nrm2V [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> Nrm2V for $space
+        where
+            T: $crate::num_traits::Float
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            fn nrm2v(&self, x: &Self::Vector) -> Self::Scalar
+            {
+                self.verify(x.0.len());
+                $crate::kernel::nrm2::<T>(&x.0[..])
+            }
+        }
+    };
+
+    (@IAMaxV [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> IAMaxV for $space
+        where
+            T: $crate::num_traits::Signed + PartialOrd
+        {
+            type Vector = $vector;
+
+            fn iamaxv(&self, x: &Self::Vector) -> Option<usize>
+            {
+                self.verify(x.0.len());
+                $crate::kernel::iamax::<T>(&x.0[..])
+            }
+        }
+    };
+
+    (@SwapV [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> SwapV for $space
+        {
+            type Vector = $vector;
+
+            fn swapv(&self, x: &mut Self::Vector, y: &mut Self::Vector)
+            {
+                self.verify(x.0.len());
+                self.verify(y.0.len());
+                x.0[..].swap_with_slice(&mut y.0[..]);
             }
         }
     };
+
+    (@CopyV [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> CopyV for $space
+        where
+            T: Clone
+        {
+            type Vector = $vector;
+
+            fn copyv(&self, x: &Self::Vector, y: &mut Self::Vector)
+            {
+                self.verify(x.0.len());
+                self.verify(y.0.len());
+                y.0[..].clone_from_slice(&x.0[..]);
+            }
+        }
+    };
+
+    (@ScalV [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> ScalV for $space
+        where
+            for <'a> T: MulAssign<&'a T>
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            fn scalv(&self, alpha: &Self::Scalar, x: &mut Self::Vector, incx: usize)
+            {
+                self.verify(x.0.len());
+                $crate::kernel::scal::<T>(alpha, &mut x.0[..], incx);
+            }
+        }
+    };
+
+    (@RotV [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> RotV for $space
+        where
+            T: Copy + Add<T, Output=T> + ops::Sub<T, Output=T> + Mul<T, Output=T>
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            fn rotv(&self, x: &mut Self::Vector, y: &mut Self::Vector, c: &Self::Scalar, s: &Self::Scalar)
+            {
+                self.verify(x.0.len());
+                self.verify(y.0.len());
+                $crate::kernel::rot::<T>(&mut x.0[..], &mut y.0[..], c, s);
+            }
+        }
+    };
+
+    (@RotG [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> RotG for $space
+        where
+            T: $crate::num_traits::Float
+        {
+            type Scalar = T;
+
+            fn rotg(&self, a: &mut Self::Scalar, b: &mut Self::Scalar) -> (Self::Scalar, Self::Scalar)
+            {
+                $crate::kernel::rotg::<T>(a, b)
+            }
+        }
+    };
+
+    (@RotMV [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> RotMV for $space
+        where
+            T: $crate::num_traits::Float
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            fn rotmv(&self, x: &mut Self::Vector, y: &mut Self::Vector, param: &[Self::Scalar; 5])
+            {
+                self.verify(x.0.len());
+                self.verify(y.0.len());
+                $crate::kernel::rotm::<T>(&mut x.0[..], &mut y.0[..], param);
+            }
+        }
+    };
+
+    (@RotMG [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> RotMG for $space
+        where
+            T: $crate::num_traits::Float
+        {
+            type Scalar = T;
+
+            fn rotmg(&self, d1: &mut Self::Scalar, d2: &mut Self::Scalar, x1: &mut Self::Scalar, y1: &Self::Scalar) -> [Self::Scalar; 5]
+            {
+                $crate::kernel::rotmg::<T>(d1, d2, x1, y1)
+            }
+        }
+    };
+
+    (@GEMV $name:ident, $space:ident) => {
+        impl<T, X, Y> GEMV<X, Y> for $space<T>
+        where
+            X: AsRef<[T]>,
+            Y: Clone + AsMut<[T]>,
+            for <'a> T: AdditiveIdentity<Output=T> + PartialEq + Add<T, Output=T> + AddAssign<T> + MulAssign<&'a T>,
+            for <'a> &'a T: Mul<&'a T, Output=T>
+        {
+            type Matrix = $name<T>;
+
+            type Scalar = T;
+
+            fn gemv(&self, trans: Transpose, alpha: &Self::Scalar, a: &Self::Matrix, x: &X, beta: &Self::Scalar, y: &Y) -> Y
+            {
+                let mut output: Y = y.clone();
+                self.gemv_mut(trans, alpha, a, x, beta, &mut output);
+                output
+            }
+        }
+    };
+
+    (@GEMVMut $name:ident, $space:ident) => {
+        impl<T, X, Y> GEMVMut<X, Y> for $space<T>
+        where
+            X: AsRef<[T]>,
+            Y: AsMut<[T]>,
+            for <'a> T: AdditiveIdentity<Output=T> + PartialEq + Add<T, Output=T> + AddAssign<T> + MulAssign<&'a T>,
+            for <'a> &'a T: Mul<&'a T, Output=T>
+        {
+            type Matrix = $name<T>;
+
+            type Scalar = T;
+
+            fn gemv_mut(&self, trans: Transpose, alpha: &Self::Scalar, a: &Self::Matrix, x: &X, beta: &Self::Scalar, y: &mut Y)
+            {
+                let (layout, shape) = match trans {
+                    Transpose::NoTrans => ($name::<T>::LAYOUT, ($name::<T>::ROWS, $name::<T>::COLUMNS)),
+                    Transpose::Trans => ($name::<T>::LAYOUT.transposed(), ($name::<T>::COLUMNS, $name::<T>::ROWS))
+                };
+                $crate::kernel::gemv::<T>(layout, shape, alpha, &a.0[..], x.as_ref(), beta, y.as_mut());
+            }
+        }
+    };
+
+    (@GER $name:ident, $space:ident) => {
+        impl<T, X, Y> GER<X, Y> for $space<T>
+        where
+            X: AsRef<[T]>,
+            Y: AsRef<[T]>,
+            T: Clone + AddAssign<T>,
+            for <'a> &'a T: Mul<&'a T, Output=T>
+        {
+            type Matrix = $name<T>;
+
+            type Scalar = T;
+
+            fn ger(&self, alpha: &Self::Scalar, x: &X, y: &Y, a: &Self::Matrix) -> Self::Matrix
+            {
+                let mut output: Self::Matrix = a.clone();
+                self.ger_mut(alpha, x, y, &mut output);
+                output
+            }
+        }
+    };
+
+    (@GERMut $name:ident, $space:ident) => {
+        impl<T, X, Y> GERMut<X, Y> for $space<T>
+        where
+            X: AsRef<[T]>,
+            Y: AsRef<[T]>,
+            T: AddAssign<T>,
+            for <'a> &'a T: Mul<&'a T, Output=T>
+        {
+            type Matrix = $name<T>;
+
+            type Scalar = T;
+
+            fn ger_mut(&self, alpha: &Self::Scalar, x: &X, y: &Y, a: &mut Self::Matrix)
+            {
+                $crate::kernel::ger::<T>($name::<T>::LAYOUT, ($name::<T>::ROWS, $name::<T>::COLUMNS), alpha, x.as_ref(), y.as_ref(), &mut a.0[..]);
+            }
+        }
+    };
+
+    (@GEMM $name:ident, $space:ident) => {
+        impl<T, A, B> GEMM<A, B> for $space<T>
+        where
+            A: Dense + AsRef<[T]>,
+            B: Dense + AsRef<[T]>,
+            T: Copy + Add<T, Output=T> + AddAssign<T> + Mul<T, Output=T> + MulAssign<T> + AdditiveIdentity<Output=T> + PartialEq
+        {
+            type Matrix = $name<T>;
+
+            type Scalar = T;
+
+            fn gemm(&self, transa: Transpose, transb: Transpose, alpha: &Self::Scalar, a: &A, b: &B, beta: &Self::Scalar, c: &Self::Matrix) -> Self::Matrix
+            {
+                let mut output: Self::Matrix = c.clone();
+                self.gemm_mut(transa, transb, alpha, a, b, beta, &mut output);
+                output
+            }
+        }
+    };
+
+    (@GEMMMut $name:ident, $space:ident) => {
+        impl<T, A, B> GEMMMut<A, B> for $space<T>
+        where
+            A: Dense + AsRef<[T]>,
+            B: Dense + AsRef<[T]>,
+            T: Copy + Add<T, Output=T> + AddAssign<T> + Mul<T, Output=T> + MulAssign<T> + AdditiveIdentity<Output=T> + PartialEq
+        {
+            type Matrix = $name<T>;
+
+            type Scalar = T;
+
+            fn gemm_mut(&self, transa: Transpose, transb: Transpose, alpha: &Self::Scalar, a: &A, b: &B, beta: &Self::Scalar, c: &mut Self::Matrix)
+            {
+                let a = $crate::kernel::MatrixView::new(a.as_ref(), a.layout(), a.shape()).op(transa);
+                let b = $crate::kernel::MatrixView::new(b.as_ref(), b.layout(), b.shape()).op(transb);
+
+                $crate::kernel::gemm::<T>(alpha, a, b, beta, &mut c.0[..], $name::<T>::LAYOUT, ($name::<T>::ROWS, $name::<T>::COLUMNS));
+            }
+        }
+    };
+
+    (@$branch:ident $name:ident, $space:ident) => {
+        $crate::BlasOps!(@$branch [T] $space<T>, $name<T>);
+    };
 }
@@ -0,0 +1,256 @@
+/// Factorisations of matrices and the solvers built on them, which are opt-in. The 
+/// arms other than `QR` and `LeastSquares` only compile for spaces whose matrices are 
+/// square.
+#[macro_export]
+macro_rules! DecompositionOps {
+    (@LU $name:ident, $space:ident) => {
+        const _: () = assert!($name::<()>::ROWS == $name::<()>::COLUMNS, "the LU decomposition needs a square matrix");
+
+        impl<T> LUDecomposition for $space<T>
+        where
+            T: Field + Magnitude
+        {
+            type Matrix = $name<T>;
+
+            type Output = $crate::LU<$name<T>, T>;
+
+            type Error = $crate::Singular;
+
+            fn lu(&self, a: &Self::Matrix) -> Result<Self::Output, Self::Error>
+            {
+                $crate::LU::new(a.clone())
+            }
+        }
+    };
+
+    (@Solve $name:ident, $space:ident) => {
+        impl<T, X> Solve<X> for $space<T>
+        where
+            T: Field + Magnitude,
+            X: Clone + AsMut<[T]>
+        {
+            type Matrix = $name<T>;
+
+            type Error = $crate::Singular;
+
+            fn solve(&self, a: &Self::Matrix, b: &X) -> Result<X, Self::Error>
+            {
+                Ok(self.lu(a)?.solve(b))
+            }
+        }
+    };
+
+    (@SolveMut $name:ident, $space:ident) => {
+        impl<T, X> SolveMut<X> for $space<T>
+        where
+            T: Field + Magnitude,
+            X: AsMut<[T]>
+        {
+            type Matrix = $name<T>;
+
+            type Error = $crate::Singular;
+
+            fn solve_mut(&self, a: &Self::Matrix, b: &mut X) -> Result<(), Self::Error>
+            {
+                self.lu(a)?.solve_mut(b);
+                Ok(())
+            }
+        }
+    };
+
+    (@Determinant $name:ident, $space:ident) => {
+        impl<T> Determinant for $space<T>
+        where
+            T: Field + Magnitude
+        {
+            type Matrix = $name<T>;
+
+            type Scalar = T;
+
+            /// Zero for a singular matrix.
+            fn determinant(&self, a: &Self::Matrix) -> Self::Scalar
+            {
+                $crate::LU::determinant_of(a.clone())
+            }
+        }
+    };
+
+    (@Inverse $name:ident, $space:ident) => {
+        impl<T> Inverse for $space<T>
+        where
+            T: Field + Magnitude
+        {
+            type Matrix = $name<T>;
+
+            type Error = $crate::Singular;
+
+            fn inverse(&self, a: &Self::Matrix) -> Result<Self::Matrix, Self::Error>
+            {
+                self.lu(a).map(|lu| lu.inverse())
+            }
+        }
+    };
+
+    (@QR $name:ident, $space:ident) => {
+        impl<T> QRDecomposition for $space<T>
+        where
+            T: $crate::num_traits::Float
+        {
+            type Matrix = $name<T>;
+
+            type Output = $crate::QR<$name<T>, T>;
+
+            fn qr(&self, a: &Self::Matrix) -> Self::Output
+            {
+                $crate::QR::new(a.clone())
+            }
+
+            fn qr_pivoted(&self, a: &Self::Matrix) -> Self::Output
+            {
+                $crate::QR::new_pivoted(a.clone())
+            }
+        }
+    };
+
+    (@LeastSquares $name:ident, $space:ident) => {
+        impl<T, X, Y> LeastSquares<X, Y> for $space<T>
+        where
+            T: $crate::num_traits::Float,
+            X: AsRef<[T]>,
+            Y: FromIterator<T>
+        {
+            type Matrix = $name<T>;
+
+            type Error = $crate::Singular;
+
+            /// Solved through the pivoted QR decomposition, failing for a rank-deficient `A`.
+            fn lstsq(&self, a: &Self::Matrix, b: &X) -> Result<Y, Self::Error>
+            {
+                $crate::QR::new_pivoted(a.clone()).solve(b)
+            }
+        }
+    };
+
+    (@Cholesky $name:ident, $space:ident) => {
+        const _: () = assert!($name::<()>::ROWS == $name::<()>::COLUMNS, "the Cholesky decomposition needs a square matrix");
+
+        impl<T> CholeskyDecomposition for $space<T>
+        where
+            T: $crate::num_traits::Float
+        {
+            type Matrix = $name<T>;
+
+            type Output = $crate::Cholesky<$name<T>, T>;
+
+            type Error = $crate::NotPositiveDefinite;
+
+            fn cholesky(&self, a: &Self::Matrix) -> Result<Self::Output, Self::Error>
+            {
+                $crate::Cholesky::new(a.clone())
+            }
+        }
+    };
+
+    (@LDLT $name:ident, $space:ident) => {
+        const _: () = assert!($name::<()>::ROWS == $name::<()>::COLUMNS, "the LDL^T decomposition needs a square matrix");
+
+        impl<T> LDLTDecomposition for $space<T>
+        where
+            T: $crate::num_traits::Float
+        {
+            type Matrix = $name<T>;
+
+            type Output = $crate::LDLT<$name<T>, T>;
+
+            fn ldlt(&self, a: &Self::Matrix) -> Self::Output
+            {
+                $crate::LDLT::new(a.clone())
+            }
+        }
+    };
+
+    (@SymmetricEigen $name:ident, $space:ident) => {
+        const _: () = assert!($name::<()>::ROWS == $name::<()>::COLUMNS, "a symmetric eigendecomposition needs a square matrix");
+
+        impl<T, V> SymmetricEigenDecomposition<V> for $space<T>
+        where
+            T: $crate::num_traits::Float,
+            V: FromIterator<T>
+        {
+            type Matrix = $name<T>;
+
+            type Output = $crate::SymmetricEigen<$name<T>, V>;
+
+            type Error = $crate::NoConvergence;
+
+            fn eigh(&self, a: &Self::Matrix, order: EigenOrder) -> Result<Self::Output, Self::Error>
+            {
+                $crate::SymmetricEigen::new(a.clone(), order)
+            }
+
+            fn eigh_jacobi(&self, a: &Self::Matrix, order: EigenOrder) -> Result<Self::Output, Self::Error>
+            {
+                $crate::SymmetricEigen::new_jacobi(a.clone(), order)
+            }
+        }
+    };
+
+    (@Hessenberg $name:ident, $space:ident) => {
+        const _: () = assert!($name::<()>::ROWS == $name::<()>::COLUMNS, "the Hessenberg decomposition needs a square matrix");
+
+        impl<T> HessenbergDecomposition for $space<T>
+        where
+            T: $crate::num_traits::Float
+        {
+            type Matrix = $name<T>;
+
+            type Output = $crate::Hessenberg<$name<T>, T>;
+
+            fn hessenberg(&self, a: &Self::Matrix) -> Self::Output
+            {
+                $crate::Hessenberg::new(a.clone())
+            }
+        }
+    };
+
+    (@Schur $name:ident, $space:ident) => {
+        const _: () = assert!($name::<()>::ROWS == $name::<()>::COLUMNS, "the Schur decomposition needs a square matrix");
+
+        impl<T> SchurDecomposition for $space<T>
+        where
+            T: $crate::num_traits::Float
+        {
+            type Matrix = $name<T>;
+
+            type Output = $crate::Schur<$name<T>, T>;
+
+            type Error = $crate::NoConvergence;
+
+            fn schur(&self, a: &Self::Matrix) -> Result<Self::Output, Self::Error>
+            {
+                $crate::Schur::new(a.clone())
+            }
+        }
+    };
+
+    (@Eigen $name:ident, $space:ident) => {
+        const _: () = assert!($name::<()>::ROWS == $name::<()>::COLUMNS, "an eigendecomposition needs a square matrix");
+
+        impl<T, V> EigenDecomposition<V> for $space<T>
+        where
+            T: $crate::num_traits::Float + Magnitude,
+            V: FromIterator<Complex<T>>
+        {
+            type Matrix = $name<T>;
+
+            type Output = $crate::Eigen<$name<Complex<T>>, V>;
+
+            type Error = $crate::NoConvergence;
+
+            fn eig(&self, a: &Self::Matrix) -> Result<Self::Output, Self::Error>
+            {
+                $crate::Eigen::new(a.clone())
+            }
+        }
+    };
+}
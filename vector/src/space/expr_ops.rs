@@ -0,0 +1,47 @@
+/// Lazy expressions over the vectors of a space, which are opt-in. Besides `expr` 
+/// and `eval` on the space, `@Expr` lets a primitive scalar start an expression from 
+/// a borrowed vector, as in `2.0 * &y`.
+#[macro_export]
+macro_rules! ExprOps {
+    (@Expr $name:ident, $space:ident) => {
+        $crate::ExprOps!(@Expr [T] $space<T>, $name<T>);
+
+        $crate::ExprOps!(@ScalarLhs $name; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    };
+
+    (@Expr [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> $space
+        {
+            /// Starts a lazy expression at `x`.
+            pub fn expr<'a>(&self, x: &'a $vector) -> $crate::expr::VExpr<$crate::expr::Leaf<'a, T>>
+            {
+                self.verify(x.0.len());
+                $crate::expr::VExpr::new($crate::expr::Leaf::new(&x.0[..]))
+            }
+
+            /// Evaluates `expr` into `dest` in a single pass.
+            pub fn eval<E>(&self, expr: E, dest: &mut $vector)
+            where
+                E: $crate::expr::Expr<Scalar=T> + $crate::kernel::Shareable,
+                T: $crate::kernel::Shareable
+            {
+                self.verify(dest.0.len());
+                $crate::kernel::eval::<E>(&expr, &mut dest.0[..]);
+            }
+        }
+    };
+
+    (@ScalarLhs $name:ident; $($T:ty),*) => {
+        $(
+            impl<'a> Mul<&'a $name<$T>> for $T
+            {
+                type Output = $crate::expr::VExpr<$crate::expr::Scaled<$crate::expr::Leaf<'a, $T>, $T>>;
+
+                fn mul(self, x: &'a $name<$T>) -> Self::Output
+                {
+                    $crate::expr::VExpr::new($crate::expr::Leaf::new(&x.0[..])) * self
+                }
+            }
+        )*
+    };
+}
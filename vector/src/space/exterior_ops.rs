@@ -0,0 +1,77 @@
+/// Cross and wedge products. `VCross` and `VCrossMut` only compile for spaces 
+/// whose dimension is 3.
+#[macro_export]
+macro_rules! ExteriorOps {
+    (@VCross $name:ident, $space:ident) => {
+        const _: () = assert!($name::<()>::DIMENSION == 3, "the cross product is only defined in three dimensions");
+
+        $crate::ExteriorOps!(@VCross [T] $space<T>, $name<T>);
+    };
+
+    (@VCross [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VCross for $space
+        where
+            T: Clone + ops::Sub<T, Output=T>,
+            for <'a> &'a T: Mul<&'a T, Output=T>,
+            $space: VCrossMut<Vector=$vector>
+        {
+            type Vector = $vector;
+
+            fn vcross(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector
+            {
+                let mut buf: Self::Vector = lhs.clone();
+                self.vcross_mut(&mut buf, rhs);
+                buf
+            }
+        }
+    };
+
+    (@VCrossMut $name:ident, $space:ident) => {
+        const _: () = assert!($name::<()>::DIMENSION == 3, "the cross product is only defined in three dimensions");
+
+        $crate::ExteriorOps!(@VCrossMut [T] $space<T>, $name<T>);
+    };
+
+    (@VCrossMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VCrossMut for $space
+        where
+            T: ops::Sub<T, Output=T>,
+            for <'a> &'a T: Mul<&'a T, Output=T>
+        {
+            type Vector = $vector;
+
+            fn vcross_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector)
+            {
+                self.verify(lhs.0.len());
+                self.verify(rhs.0.len());
+                let product: [T; 3] = $crate::kernel::cross::<T>(&lhs.0[..], &rhs.0[..]);
+                for (l, p) in lhs.0.iter_mut().zip(product) {
+                    *l = p;
+                }
+            }
+        }
+    };
+
+    (@VWedge [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VWedge for $space
+        where
+            T: ops::Sub<T, Output=T>,
+            for <'a> &'a T: Mul<&'a T, Output=T>
+        {
+            type Vector = $vector;
+
+            type Bivector = Vec<T>;
+
+            fn vwedge(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Bivector
+            {
+                self.verify(lhs.0.len());
+                self.verify(rhs.0.len());
+                $crate::kernel::wedge::<T>(&lhs.0[..], &rhs.0[..])
+            }
+        }
+    };
+
+    (@$branch:ident $name:ident, $space:ident) => {
+        $crate::ExteriorOps!(@$branch [T] $space<T>, $name<T>);
+    };
+}
@@ -2,3 +2,8 @@ mod binops;
 mod uniops;
 mod structural;
 mod blas_ops;
+mod norm_ops;
+mod std_ops;
+mod exterior_ops;
+mod expr_ops;
+mod decomposition_ops;
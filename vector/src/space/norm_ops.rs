@@ -0,0 +1,107 @@
+#[macro_export]
+macro_rules! NormOps {
+    (@Norm [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> Norm for $space
+        where
+            T: $crate::num_traits::Float
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            fn norm_l1(&self, x: &Self::Vector) -> Self::Scalar
+            {
+                self.verify(x.0.len());
+                $crate::kernel::norm_l1::<T>(&x.0[..])
+            }
+
+            fn norm_l2(&self, x: &Self::Vector) -> Self::Scalar
+            {
+                self.verify(x.0.len());
+                $crate::kernel::nrm2::<T>(&x.0[..])
+            }
+
+            fn norm_inf(&self, x: &Self::Vector) -> Self::Scalar
+            {
+                self.verify(x.0.len());
+                $crate::kernel::norm_inf::<T>(&x.0[..])
+            }
+
+            fn norm_p(&self, x: &Self::Vector, p: &Self::Scalar) -> Self::Scalar
+            {
+                self.verify(x.0.len());
+                $crate::kernel::norm_p::<T>(&x.0[..], *p)
+            }
+        }
+    };
+
+    (@NormedSpace [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> NormedSpace for $space
+        where
+            T: $crate::num_traits::Float,
+            $space: DotV<Vector=$vector, Scalar=T> 
+                + VScaleMut<Vector=$vector, Scalar=T> 
+                + VAXPYMut<Vector=$vector, Scalar=T>
+        {
+            type Vector = $vector;
+
+            type Scalar = T;
+
+            fn norm(&self, x: &Self::Vector) -> Self::Scalar
+            {
+                self.verify(x.0.len());
+                $crate::kernel::nrm2::<T>(&x.0[..])
+            }
+
+            fn normalize(&self, x: &Self::Vector) -> Self::Vector
+            {
+                let mut buf: Self::Vector = x.clone();
+                self.normalize_mut(&mut buf);
+                buf
+            }
+
+            fn normalize_mut(&self, x: &mut Self::Vector)
+            {
+                let norm: T = self.norm(x);
+                if !norm.is_zero() {
+                    self.vscale_mut(x, &norm.recip());
+                }
+            }
+
+            fn distance(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar
+            {
+                let mut buf: Self::Vector = x.clone();
+                self.vaxpy_mut(&-T::one(), y, &mut buf);
+                self.norm(&buf)
+            }
+        }
+    };
+
+    (@InnerProductSpace [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> InnerProductSpace for $space
+        where
+            T: $crate::num_traits::Float,
+            $space: NormedSpace<Vector=$vector, Scalar=T> 
+                + DotV<Vector=$vector, Scalar=T>
+        {
+            fn inner_product(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar
+            {
+                self.dotv(x, y)
+            }
+
+            /// The cosine is taken between the normalized vectors, so that the dot product 
+            /// of very small or very large vectors neither underflows nor overflows.
+            fn angle(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar
+            {
+                if self.norm(x).is_zero() || self.norm(y).is_zero() {
+                    return T::nan()
+                }
+                $crate::kernel::angle(self.dotv(&self.normalize(x), &self.normalize(y)), T::one(), T::one())
+            }
+        }
+    };
+
+    (@$branch:ident $name:ident, $space:ident) => {
+        $crate::NormOps!(@$branch [T] $space<T>, $name<T>);
+    };
+}
@@ -0,0 +1,219 @@
+/// Operator overloads on the vectors of a space, delegating to the same kernels 
+/// as `BinOps!` and `UniOps!`. Binary operators take owned or borrowed operands; 
+/// an owned left operand is updated in place rather than cloned.
+#[macro_export]
+macro_rules! StdOps {
+    (@Add $name:ident, $space:ident) => {
+        impl<'b, T> Add<&'b $name<T>> for $name<T>
+        where
+            for <'a> T: AddAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Output = $name<T>;
+
+            fn add(mut self, rhs: &'b $name<T>) -> Self::Output
+            {
+                $crate::kernel::add_assign::<T>(&mut self.0[..], &rhs.0[..]);
+                self
+            }
+        }
+
+        impl<T> Add<$name<T>> for $name<T>
+        where
+            for <'a> T: AddAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Output = $name<T>;
+
+            fn add(self, rhs: $name<T>) -> Self::Output
+            {
+                self + &rhs
+            }
+        }
+
+        impl<'a, 'b, T> Add<&'b $name<T>> for &'a $name<T>
+        where
+            for <'c> T: Clone + AddAssign<&'c T> + $crate::kernel::Shareable,
+        {
+            type Output = $name<T>;
+
+            fn add(self, rhs: &'b $name<T>) -> Self::Output
+            {
+                self.clone() + rhs
+            }
+        }
+
+        impl<'a, T> Add<$name<T>> for &'a $name<T>
+        where
+            for <'c> T: Clone + AddAssign<&'c T> + $crate::kernel::Shareable,
+        {
+            type Output = $name<T>;
+
+            fn add(self, rhs: $name<T>) -> Self::Output
+            {
+                self.clone() + &rhs
+            }
+        }
+    };
+
+    (@Sub $name:ident, $space:ident) => {
+        impl<'b, T> ops::Sub<&'b $name<T>> for $name<T>
+        where
+            for <'a> T: ops::SubAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Output = $name<T>;
+
+            fn sub(mut self, rhs: &'b $name<T>) -> Self::Output
+            {
+                $crate::kernel::sub_assign::<T>(&mut self.0[..], &rhs.0[..]);
+                self
+            }
+        }
+
+        impl<T> ops::Sub<$name<T>> for $name<T>
+        where
+            for <'a> T: ops::SubAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Output = $name<T>;
+
+            fn sub(self, rhs: $name<T>) -> Self::Output
+            {
+                self - &rhs
+            }
+        }
+
+        impl<'a, 'b, T> ops::Sub<&'b $name<T>> for &'a $name<T>
+        where
+            for <'c> T: Clone + ops::SubAssign<&'c T> + $crate::kernel::Shareable,
+        {
+            type Output = $name<T>;
+
+            fn sub(self, rhs: &'b $name<T>) -> Self::Output
+            {
+                self.clone() - rhs
+            }
+        }
+
+        impl<'a, T> ops::Sub<$name<T>> for &'a $name<T>
+        where
+            for <'c> T: Clone + ops::SubAssign<&'c T> + $crate::kernel::Shareable,
+        {
+            type Output = $name<T>;
+
+            fn sub(self, rhs: $name<T>) -> Self::Output
+            {
+                self.clone() - &rhs
+            }
+        }
+    };
+
+    (@Neg $name:ident, $space:ident) => {
+        impl<T> Neg for $name<T>
+        where
+            T: $crate::kernel::Shareable,
+            for <'a> &'a T: Neg<Output=T>
+        {
+            type Output = $name<T>;
+
+            fn neg(mut self) -> Self::Output
+            {
+                $crate::kernel::neg_assign::<T>(&mut self.0[..]);
+                self
+            }
+        }
+
+        impl<'a, T> Neg for &'a $name<T>
+        where
+            T: Clone + $crate::kernel::Shareable,
+            for <'c> &'c T: Neg<Output=T>
+        {
+            type Output = $name<T>;
+
+            fn neg(self) -> Self::Output
+            {
+                -self.clone()
+            }
+        }
+    };
+
+    (@Mul $name:ident, $space:ident) => {
+        impl<T> Mul<T> for $name<T>
+        where
+            for <'a> T: MulAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            type Output = $name<T>;
+
+            fn mul(mut self, scalar: T) -> Self::Output
+            {
+                $crate::kernel::mul_assign::<T>(&mut self.0[..], &scalar);
+                self
+            }
+        }
+
+        impl<'a, T> Mul<T> for &'a $name<T>
+        where
+            for <'c> T: Clone + MulAssign<&'c T> + $crate::kernel::Shareable,
+        {
+            type Output = $name<T>;
+
+            fn mul(self, scalar: T) -> Self::Output
+            {
+                self.clone() * scalar
+            }
+        }
+    };
+
+    (@AddAssign $name:ident, $space:ident) => {
+        impl<'b, T> AddAssign<&'b $name<T>> for $name<T>
+        where
+            for <'a> T: AddAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            fn add_assign(&mut self, rhs: &'b $name<T>)
+            {
+                $crate::kernel::add_assign::<T>(&mut self.0[..], &rhs.0[..]);
+            }
+        }
+
+        impl<T> AddAssign<$name<T>> for $name<T>
+        where
+            for <'a> T: AddAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            fn add_assign(&mut self, rhs: $name<T>)
+            {
+                *self += &rhs;
+            }
+        }
+    };
+
+    (@SubAssign $name:ident, $space:ident) => {
+        impl<'b, T> ops::SubAssign<&'b $name<T>> for $name<T>
+        where
+            for <'a> T: ops::SubAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            fn sub_assign(&mut self, rhs: &'b $name<T>)
+            {
+                $crate::kernel::sub_assign::<T>(&mut self.0[..], &rhs.0[..]);
+            }
+        }
+
+        impl<T> ops::SubAssign<$name<T>> for $name<T>
+        where
+            for <'a> T: ops::SubAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            fn sub_assign(&mut self, rhs: $name<T>)
+            {
+                *self -= &rhs;
+            }
+        }
+    };
+
+    (@MulAssign $name:ident, $space:ident) => {
+        impl<T> MulAssign<T> for $name<T>
+        where
+            for <'a> T: MulAssign<&'a T> + $crate::kernel::Shareable,
+        {
+            fn mul_assign(&mut self, scalar: T)
+            {
+                $crate::kernel::mul_assign::<T>(&mut self.0[..], &scalar);
+            }
+        }
+    };
+}
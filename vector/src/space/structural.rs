@@ -14,6 +14,22 @@ macro_rules! vectorspace {
                     _phantom: PhantomData
                 }
             }
+
+            /// The number of elements of every vector in the space.
+            #[inline]
+            pub fn dimension(&self) -> usize
+            {
+                $length
+            }
+
+            /// Checks the length of an operand in debug builds or with the `strict` feature; 
+            /// the vector types already hold `$length` elements.
+            #[inline]
+            #[track_caller]
+            fn verify(&self, found: usize)
+            {
+                $crate::kernel::verify(self.dimension(), found)
+            }
         }
 
         impl<$T> VectorSpace for $space<$T>
@@ -23,23 +39,103 @@ macro_rules! vectorspace {
             type Vector = $space<$T>;
         }
 
-        impl<$T> VPartialEq for $space<$T>
+        $crate::vectorspace!(@Compare [$T] $space<$T>, $name<$T>);
+    };
+
+    // The operations that `vspace!` implements by default, for a space written by hand 
+    // whose dimension need not be a literal. The space needs an inherent `dimension`, and 
+    // a `verify` that checks the length of each operand against it.
+    (@Defaults $generics:tt $space:ty, $vector:ty) => {
+        $crate::vectorspace!(@Compare $generics $space, $vector);
+
+        $crate::BinOps!(@VAdd $generics $space, $vector);
+        $crate::BinOps!(@VAddMut $generics $space, $vector);
+        $crate::BinOps!(@CheckedVAdd $generics $space, $vector);
+        $crate::BinOps!(@CheckedVAddMut $generics $space, $vector);
+        $crate::BinOps!(@VScale $generics $space, $vector);
+        $crate::BinOps!(@VScaleMut $generics $space, $vector);
+        $crate::BinOps!(@VSub $generics $space, $vector);
+        $crate::BinOps!(@VSubMut $generics $space, $vector);
+        $crate::BinOps!(@VHadamard $generics $space, $vector);
+        $crate::BinOps!(@VHadamardMut $generics $space, $vector);
+        $crate::BinOps!(@VHadamardDiv $generics $space, $vector);
+        $crate::BinOps!(@VHadamardDivMut $generics $space, $vector);
+        $crate::UniOps!(@VAdditiveInverse $generics $space, $vector);
+        $crate::UniOps!(@VAdditiveInverseMut $generics $space, $vector);
+        $crate::BlasOps!(@VAXPY $generics $space, $vector);
+        $crate::BlasOps!(@VAXPYMut $generics $space, $vector);
+        $crate::BlasOps!(@DotV $generics $space, $vector);
+        $crate::BlasOps!(@DotVMut $generics $space, $vector);
+        $crate::BlasOps!(@CheckedVAXPY $generics $space, $vector);
+        $crate::BlasOps!(@CheckedVAXPYMut $generics $space, $vector);
+        $crate::BlasOps!(@CheckedDotV $generics $space, $vector);
+        $crate::BlasOps!(@CheckedDotVMut $generics $space, $vector);
+        $crate::BlasOps!(@DotC $generics $space, $vector);
+        $crate::BlasOps!(@DotCMut $generics $space, $vector);
+        $crate::BlasOps!(@HermitianInnerProduct $generics $space, $vector);
+        $crate::BlasOps!(@ASumV $generics $space, $vector);
+        $crate::BlasOps!(@Nrm2V $generics $space, $vector);
+        $crate::BlasOps!(@IAMaxV $generics $space, $vector);
+        $crate::BlasOps!(@SwapV $generics $space, $vector);
+        $crate::BlasOps!(@CopyV $generics $space, $vector);
+        $crate::BlasOps!(@ScalV $generics $space, $vector);
+        $crate::BlasOps!(@RotV $generics $space, $vector);
+        $crate::BlasOps!(@RotG $generics $space, $vector);
+        $crate::BlasOps!(@RotMV $generics $space, $vector);
+        $crate::BlasOps!(@RotMG $generics $space, $vector);
+        $crate::NormOps!(@Norm $generics $space, $vector);
+        $crate::NormOps!(@NormedSpace $generics $space, $vector);
+        $crate::NormOps!(@InnerProductSpace $generics $space, $vector);
+        $crate::ExteriorOps!(@VWedge $generics $space, $vector);
+        $crate::ExprOps!(@Expr $generics $space, $vector);
+    };
+
+    (@Compare [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VPartialEq for $space
         where
-            $T: PartialEq
+            T: PartialEq
         {
-            type Vector = $name<$T>;
+            type Vector = $vector;
 
             fn eq(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> bool
             {
-                for (l, r) in lhs
+                lhs.0.len() == rhs.0.len() && lhs
                     .into_iter()
-                    .zip( rhs.into_iter() ) 
-                {
-                    if (l != r) {
-                        return false
-                    }
-                }
-                return true
+                    .zip( rhs.into_iter() )
+                    .all(|(l, r)| l == r)
+            }
+        }
+
+        impl<$($generics)*> VApproxEq for $space
+        where
+            T: ApproxEq
+        {
+            type Vector = $vector;
+
+            type Epsilon = T::Epsilon;
+
+            fn abs_diff_eq(&self, lhs: &Self::Vector, rhs: &Self::Vector, epsilon: &Self::Epsilon) -> bool
+            {
+                lhs.0.len() == rhs.0.len() && lhs
+                    .into_iter()
+                    .zip( rhs.into_iter() )
+                    .all(|(l, r)| l.abs_diff_eq(r, epsilon))
+            }
+
+            fn relative_eq(&self, lhs: &Self::Vector, rhs: &Self::Vector, epsilon: &Self::Epsilon, max_relative: &Self::Epsilon) -> bool
+            {
+                lhs.0.len() == rhs.0.len() && lhs
+                    .into_iter()
+                    .zip( rhs.into_iter() )
+                    .all(|(l, r)| l.relative_eq(r, epsilon, max_relative))
+            }
+
+            fn ulps_eq(&self, lhs: &Self::Vector, rhs: &Self::Vector, epsilon: &Self::Epsilon, max_ulps: u32) -> bool
+            {
+                lhs.0.len() == rhs.0.len() && lhs
+                    .into_iter()
+                    .zip( rhs.into_iter() )
+                    .all(|(l, r)| l.ulps_eq(r, epsilon, max_ulps))
             }
         }
     };
@@ -1,12 +1,12 @@
 #[macro_export]
 macro_rules! UniOps {
-    (@VAdditiveInverse $name:ident, $space:ident) => {
-        impl<T> VAdditiveInverse for $space<T>
+    (@VAdditiveInverse [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VAdditiveInverse for $space
         where
-            T: Copy,
+            T: Copy + $crate::kernel::Shareable,
             for <'a> &'a T: Neg<Output=T>
         {
-            type Vector = $name<T>;
+            type Vector = $vector;
 
             fn additive_inv(&self, vector: &Self::Vector) -> Self::Vector
             {
@@ -17,21 +17,23 @@ macro_rules! UniOps {
         }
     };
 
-    (@VAdditiveInverseMut $name:ident, $space:ident) => {
-        impl<T> VAdditiveInverseMut for $space<T>
+    (@VAdditiveInverseMut [$($generics:tt)*] $space:ty, $vector:ty) => {
+        impl<$($generics)*> VAdditiveInverseMut for $space
         where
-            T: Copy,
+            T: Copy + $crate::kernel::Shareable,
             for <'a> &'a T: Neg<Output=T>
         {
-            type Vector = $name<T>;
+            type Vector = $vector;
 
             fn additive_inv_mut(&self, vector: &mut Self::Vector)
             {
-                vector
-                    .0
-                    .iter_mut()
-                    .for_each(|val| *val = (*val).neg() );
+                self.verify(vector.0.len());
+                $crate::kernel::neg_assign::<T>(&mut vector.0[..]);
             }
         }
     };
+
+    (@$branch:ident $name:ident, $space:ident) => {
+        $crate::UniOps!(@$branch [T] $space<T>, $name<T>);
+    };
 }
@@ -0,0 +1,90 @@
+use std::convert::TryFrom;
+
+use vector::{ArraySpace, ArrayVector, DimensionMismatch};
+
+use algebra::*;
+
+/// Generic over the dimension, which a `vspace!` space cannot be.
+fn sum<const N: usize>(space: &ArraySpace<isize, N>, vectors: &[ArrayVector<isize, N>]) -> ArrayVector<isize, N>
+{
+    vectors
+        .iter()
+        .fold(space.additive_identity(), |acc, v| space.vadd(&acc, v))
+}
+
+#[test]
+fn test_generic_dimension()
+{
+    let plane = ArraySpace::<isize, 2>::new();
+    let test = sum(&plane, &[ ArrayVector::from([ 1, 2 ]), ArrayVector::from([ 3, 4 ]) ]);
+    assert!(plane.eq(&test, &ArrayVector::from([ 4, 6 ])));
+
+    let space = ArraySpace::<isize, 4>::new();
+    let test = sum(&space, &[ ArrayVector::from([ 1, 2, 3, 4 ]); 3 ]);
+    assert!(space.eq(&test, &ArrayVector::from([ 3, 6, 9, 12 ])));
+}
+
+#[test]
+fn test_construction()
+{
+    assert_eq!(ArrayVector::<isize, 3>::DIMENSION, 3);
+    assert_eq!(ArraySpace::<isize, 3>::DIMENSION, 3);
+    assert!(ArrayVector::<isize, 3>::try_from(&[ 1, 2, 3 ][..]).is_ok());
+    assert_eq!(ArrayVector::<isize, 3>::try_from(&[ 1, 2 ][..]).unwrap_err(), DimensionMismatch::new(3, 2));
+    assert_eq!(ArrayVector::<isize, 3>::try_from_iter(0..5).unwrap_err(), DimensionMismatch::new(3, 5));
+    assert_eq!(ArrayVector::<isize, 3>::try_from_iter(0..3).unwrap().into_inner(), [ 0, 1, 2 ]);
+}
+
+#[test]
+fn test_binops()
+{
+    let space = ArraySpace::<isize, 3>::new();
+    let x = ArrayVector::from([ 3, 0, -1 ]);
+    let y = ArrayVector::from([ 10, 1, 2 ]);
+
+    assert!(space.eq(&space.vadd(&x, &y), &ArrayVector::from([ 13, 1, 1 ])));
+    assert!(space.eq(&space.vscale(&x, &2), &ArrayVector::from([ 6, 0, -2 ])));
+    assert!(space.eq(&space.additive_inv(&x), &ArrayVector::from([ -3, 0, 1 ])));
+    assert!(space.eq(&space.vsub(&x, &y), &ArrayVector::from([ -7, -1, -3 ])));
+    assert!(space.eq(&space.vhadamard(&x, &y), &ArrayVector::from([ 30, 0, -2 ])));
+    assert!(space.eq(&space.vhadamard_div(&y, &ArrayVector::from([ 5, 1, -2 ])), &ArrayVector::from([ 2, 1, -1 ])));
+    assert_eq!(space.checked_vadd(&x, &y).map(ArrayVector::into_inner), Ok([ 13, 1, 1 ]));
+}
+
+#[test]
+fn test_blas()
+{
+    let space = ArraySpace::<isize, 3>::new();
+    let mut x = ArrayVector::from([ 3, 0, -1 ]);
+    let mut y = ArrayVector::from([ 10, 1, 2 ]);
+
+    assert_eq!(space.dotv(&x, &y), 28);
+    assert!(space.eq(&space.vaxpy(&2, &x, &y), &ArrayVector::from([ 16, 1, 0 ])));
+    assert_eq!(space.asumv(&x), 4);
+    assert_eq!(space.iamaxv(&y), Some(0));
+
+    space.swapv(&mut x, &mut y);
+    assert_eq!(x.into_inner(), [ 10, 1, 2 ]);
+    space.scalv(&-1, &mut y, 2);
+    assert_eq!(y.into_inner(), [ -3, 0, 1 ]);
+}
+
+#[test]
+fn test_nrm2()
+{
+    let space = ArraySpace::<f64, 2>::default();
+    assert!((space.nrm2v(&ArrayVector::from([ 3.0, 4.0 ])) - 5.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_normed_space()
+{
+    let space = ArraySpace::<f64, 2>::new();
+    let x = ArrayVector::from([ 0.0, 2.0 ]);
+    let y = ArrayVector::from([ 3.0, 0.0 ]);
+
+    assert!((space.norm_l1(&x) - 2.0).abs() < 1e-12);
+    assert!((space.distance(&x, &y) - 13f64.sqrt()).abs() < 1e-12);
+    assert!((space.angle(&x, &y) - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    assert_eq!(space.normalize(&y).into_inner(), [ 1.0, 0.0 ]);
+}
@@ -0,0 +1,136 @@
+use std::convert::TryFrom;
+
+use vector::{vspace, DimensionMismatch};
+
+use algebra::*;
+
+vspace! {
+    BitSpace {
+        vector: BitVector,
+        dimension: 130,
+        using: BitPacked
+    }
+}
+
+vspace! {
+    SymbolSpace {
+        vector: SymbolVector,
+        dimension: 3
+    }
+}
+
+fn bits(ones: &[usize]) -> BitVector
+{
+    (0..BitVector::DIMENSION)
+        .map(|idx| GF2::from(ones.contains(&idx)))
+        .collect()
+}
+
+#[test]
+fn test_gf2()
+{
+    let (zero, one) = (GF2::from(false), GF2::from(true));
+    assert_eq!(one + one, zero);
+    assert_eq!(one * one, one);
+    assert_eq!(one * zero, zero);
+    assert_eq!(one.multiplicative_inv(), one);
+    assert_eq!(-one, one);
+}
+
+#[test]
+fn test_gf256()
+{
+    let x = GF256::new(0x53);
+    let y = GF256::new(0xCA);
+
+    assert_eq!(x + y, GF256::new(0x99));
+    assert_eq!(GF256::new(2) * GF256::new(0x80), GF256::new(0x1D));
+    assert_eq!(x * y / y, x);
+    assert_eq!(x.pow(255), GF256::multiplicative_identity());
+
+    for value in 1..256 {
+        let x = GF256::new(value);
+        assert_eq!(x * x.multiplicative_inv(), GF256::multiplicative_identity());
+    }
+    assert!(GF256::new(0).checked_inv().is_none());
+}
+
+#[test]
+fn test_gf256_aes()
+{
+    type Aes = GF2n<8, 0x11B>;
+    assert_eq!(Aes::new(0x57) * Aes::new(0x83), Aes::new(0xC1));
+    assert_eq!(Aes::new(0x53).multiplicative_inv(), Aes::new(0xCA));
+}
+
+#[test]
+fn test_gf65536()
+{
+    let x = GF65536::new(0xBEEF);
+    assert_eq!(x * x.multiplicative_inv(), GF65536::multiplicative_identity());
+    assert_eq!(GF65536::new(0x1_0000), GF65536::new(0x100B));
+}
+
+#[test]
+fn test_symbol_space()
+{
+    let space = SymbolSpace::<GF256>::new();
+    let x: SymbolVector<GF256> = [ 1, 2, 3 ].iter().map(|&v| GF256::new(v)).collect();
+    let y: SymbolVector<GF256> = [ 4, 5, 6 ].iter().map(|&v| GF256::new(v)).collect();
+
+    assert!(space.eq(&space.vadd(&space.vadd(&x, &y), &y), &x));
+    assert_eq!(space.dotv(&x, &y), GF256::new(4) + GF256::new(10) + GF256::new(10));
+}
+
+#[test]
+fn test_bitpacked_construction()
+{
+    let x = bits(&[ 0, 64, 129 ]);
+
+    assert_eq!(BitVector::WORDS, 3);
+    assert_eq!(x.words(), &[ 1, 1, 2 ]);
+    assert_eq!(x.get(129), GF2::from(true));
+    assert_eq!(x.get(128), GF2::from(false));
+    assert_eq!(BitVector::try_from_iter(vec![ GF2::from(true); 3 ]).unwrap_err(), DimensionMismatch::new(130, 3));
+    assert!(BitVector::try_from(&[ GF2::from(false); 130 ][..]).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn test_bitpacked_tail()
+{
+    BitVector::from_words(vec![ 0, 0, 4 ]);
+}
+
+#[test]
+fn test_bitpacked_ops()
+{
+    let space = BitSpace::new();
+    let x = bits(&[ 0, 3, 64, 129 ]);
+    let y = bits(&[ 3, 65, 129 ]);
+
+    assert!(space.eq(&space.vadd(&x, &y), &bits(&[ 0, 64, 65 ])));
+    assert!(space.eq(&space.vadd(&x, &x), &space.additive_identity()));
+    assert!(space.eq(&space.additive_inv(&x), &x));
+    assert!(space.eq(&space.vscale(&x, &GF2::from(false)), &space.additive_identity()));
+    assert!(space.eq(&space.vaxpy(&GF2::from(true), &x, &y), &bits(&[ 0, 64, 65 ])));
+    assert!(space.eq(&space.vaxpy(&GF2::from(false), &x, &y), &y));
+
+    assert_eq!(space.dotv(&x, &y), GF2::from(false));
+    assert_eq!(space.dotv(&x, &bits(&[ 129 ])), GF2::from(true));
+}
+
+#[test]
+fn test_bitpacked_matches_unpacked()
+{
+    let space = BitSpace::new();
+    let x = bits(&[ 1, 2, 5, 70, 71, 100 ]);
+    let y = bits(&[ 2, 5, 6, 71, 100, 101, 128 ]);
+
+    let expected = x
+        .iter()
+        .zip(y.iter())
+        .fold(GF2::additive_identity(), |acc, (xi, yi)| acc + xi * yi);
+    assert_eq!(space.dotv(&x, &y), expected);
+    assert_eq!(format!("{:?}", bits(&[ 1 ])).len(), 2 + 130 * 3 - 2);
+}
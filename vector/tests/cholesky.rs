@@ -0,0 +1,212 @@
+use vector::{vspace, mspace, kernel::Pivot, NotPositiveDefinite, Singular};
+
+use algebra::*;
+
+vspace! {
+    Space4 {
+        vector: Vector4,
+        dimension: 4
+    }
+}
+
+macro_rules! test {
+    ($name:ident, $object:ident, $space:ident, $layout:ident) => {
+        mod $name {
+            use super::*;
+
+            mspace! {
+                $space {
+                    matrix: $object,
+                    rows: 4,
+                    columns: 4,
+                    layout: $layout,
+                    using: Vec<T>,
+                    Implements::DecompositionOps::LU,
+                    Implements::DecompositionOps::Determinant,
+                    Implements::DecompositionOps::Cholesky,
+                    Implements::DecompositionOps::LDLT
+                }
+            }
+
+            fn mul(a: &$object<f64>, x: &[f64]) -> Vec<f64>
+            {
+                (0..4)
+                    .map(|i| (0..4).fold(0.0, |acc, j| acc + a[(i, j)] * x[j]))
+                    .collect()
+            }
+
+            fn assert_solves(a: &$object<f64>, x: &Vector4<f64>, b: &Vector4<f64>)
+            {
+                for (l, r) in mul(a, x.as_ref()).iter().zip(b.as_ref()) {
+                    assert!(l.abs_diff_eq(r, &1e-12), "{:?} does not solve for {:?}", x, b);
+                }
+            }
+
+            fn l() -> [[f64; 4]; 4]
+            {
+                [ [ 2.0, 0.0, 0.0, 0.0 ], [ 6.0, 1.0, 0.0, 0.0 ], [ -8.0, 5.0, 3.0, 0.0 ], [ 1.0, 2.0, -1.0, 1.0 ] ]
+            }
+
+            /// `L * L^T`, which is symmetric positive definite.
+            fn spd() -> $object<f64>
+            {
+                let l = l();
+                let mut a = [ [ 0.0; 4 ]; 4 ];
+                for (i, row) in a.iter_mut().enumerate() {
+                    for (j, value) in row.iter_mut().enumerate() {
+                        *value = (0..4).fold(0.0, |acc, k| acc + l[i][k] * l[j][k]);
+                    }
+                }
+                $object::from_rows(a)
+            }
+
+            /// A symmetric indefinite matrix with a zero diagonal, so that no 1x1 pivot is 
+            /// acceptable at the first step.
+            fn indefinite() -> $object<f64>
+            {
+                $object::from_rows([ [ 0.0, 1.0, 2.0, 3.0 ], [ 1.0, 0.0, 4.0, 5.0 ], [ 2.0, 4.0, 0.0, 6.0 ], [ 3.0, 5.0, 6.0, 0.0 ] ])
+            }
+
+            #[test]
+            fn test_cholesky_factor()
+            {
+                let cholesky = $space::<f64>::new().cholesky(&spd()).unwrap();
+                let (factor, l) = (cholesky.l(), l());
+                for i in 0..4 {
+                    for j in 0..4 {
+                        assert!(factor[(i, j)].abs_diff_eq(&l[i][j], &1e-12), "{:?}", factor);
+                    }
+                }
+                assert!(cholesky.determinant().abs_diff_eq(&36.0, &1e-9));
+            }
+
+            #[test]
+            fn test_cholesky_solve()
+            {
+                let a = spd();
+                let cholesky = $space::<f64>::new().cholesky(&a).unwrap();
+                let b = Vector4::new(vec![ 1.0, -2.0, 3.0, 0.5 ]);
+
+                let x = cholesky.solve(&b);
+                assert_solves(&a, &x, &b);
+
+                let mut y = b.clone();
+                cholesky.solve_mut(&mut y);
+                assert_eq!(x.as_ref(), y.as_ref());
+            }
+
+            #[test]
+            fn test_cholesky_reads_lower_triangle()
+            {
+                let space = $space::<f64>::new();
+                let mut a = spd();
+                a[(0, 3)] = 100.0;
+                a[(1, 2)] = f64::NAN;
+                assert_eq!(space.cholesky(&a).unwrap().l().as_ref(), space.cholesky(&spd()).unwrap().l().as_ref());
+            }
+
+            #[test]
+            fn test_not_positive_definite()
+            {
+                let space = $space::<f64>::new();
+                let error = space.cholesky(&indefinite()).unwrap_err();
+                assert_eq!(error, NotPositiveDefinite { pivot: 0 });
+
+                let mut a = spd();
+                a[(2, 2)] -= 9.0;
+                assert_eq!(space.cholesky(&a).unwrap_err(), NotPositiveDefinite { pivot: 2 });
+
+                let mut nan = spd();
+                nan[(3, 3)] = f64::NAN;
+                assert_eq!(space.cholesky(&nan).unwrap_err(), NotPositiveDefinite { pivot: 3 });
+            }
+
+            #[test]
+            fn test_ldlt_solve()
+            {
+                let space = $space::<f64>::new();
+                let b = Vector4::new(vec![ 1.0, -2.0, 3.0, 0.5 ]);
+
+                for a in &[ spd(), indefinite() ] {
+                    let ldlt = space.ldlt(a);
+                    let x = ldlt.solve(&b).unwrap();
+                    assert_solves(a, &x, &b);
+
+                    let mut y = b.clone();
+                    ldlt.solve_mut(&mut y).unwrap();
+                    assert_eq!(x.as_ref(), y.as_ref());
+
+                    let determinant: f64 = space.determinant(a);
+                    assert!(ldlt.determinant().abs_diff_eq(&determinant, &1e-9), "{} != {}", ldlt.determinant(), determinant);
+                }
+            }
+
+            #[test]
+            fn test_ldlt_pivots()
+            {
+                let space = $space::<f64>::new();
+                assert!(space.ldlt(&spd()).pivots().iter().all(|pivot| matches!(pivot, Pivot::OneByOne(_))));
+
+                let ldlt = space.ldlt(&indefinite());
+                assert!(matches!(ldlt.pivots()[0], Pivot::TwoByTwo(_)), "{:?}", ldlt.pivots());
+
+                let d = ldlt.d();
+                for i in 0..4 {
+                    for j in 0..4 {
+                        assert_eq!(d[(i, j)], d[(j, i)]);
+                        if i > j + 1 {
+                            assert_eq!(d[(i, j)], 0.0);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn test_ldlt_inertia()
+            {
+                let space = $space::<f64>::new();
+                assert_eq!(space.ldlt(&spd()).inertia(), (4, 0, 0));
+
+                let a = $object::from_rows([ [ 0.0, 1.0, 0.0, 0.0 ], [ 1.0, 0.0, 0.0, 0.0 ], [ 0.0, 0.0, 2.0, 0.0 ], [ 0.0, 0.0, 0.0, -3.0 ] ]);
+                let ldlt = space.ldlt(&a);
+                assert_eq!(ldlt.inertia(), (2, 2, 0));
+                assert!(ldlt.determinant().abs_diff_eq(&6.0, &1e-12));
+            }
+
+            #[test]
+            fn test_ldlt_singular()
+            {
+                let space = $space::<f64>::new();
+                let ldlt = space.ldlt(&$object::from_rows([ [ 1.0; 4 ]; 4 ]));
+                assert_eq!(ldlt.inertia(), (1, 0, 3));
+                assert_eq!(ldlt.determinant(), 0.0);
+
+                let mut b = Vector4::new(vec![ 1.0, 2.0, 3.0, 4.0 ]);
+                assert_eq!(ldlt.solve_mut(&mut b), Err(Singular { pivot: 1 }));
+                assert_eq!(b.as_ref(), &[ 1.0, 2.0, 3.0, 4.0 ]);
+
+                // u * u^T + v * v^T for u = (1, 2, 3, 4) and v = (1, -1, 2, 0), whose 
+                // zero eigenvalues only show up as rounding error.
+                let rank_two = $object::from_rows([ [ 2.0, 1.0, 5.0, 4.0 ], [ 1.0, 5.0, 4.0, 8.0 ], [ 5.0, 4.0, 13.0, 12.0 ], [ 4.0, 8.0, 12.0, 16.0 ] ]);
+                let ldlt = space.ldlt(&rank_two);
+                assert_eq!(ldlt.inertia(), (2, 0, 2));
+                assert!(ldlt.solve(&b).is_err());
+            }
+
+            #[test]
+            fn test_ldlt_badly_scaled()
+            {
+                let space = $space::<f64>::new();
+                let a = $object::from_rows([ [ 1e20, 0.0, 0.0, 0.0 ], [ 0.0, 1.0, 0.0, 0.0 ], [ 0.0, 0.0, -1.0, 0.0 ], [ 0.0, 0.0, 0.0, 1e-3 ] ]);
+                let ldlt = space.ldlt(&a);
+                assert_eq!(ldlt.inertia(), (3, 1, 0));
+
+                let x = ldlt.solve(&Vector4::new(vec![ 1e20, 2.0, 3.0, 4e-3 ])).unwrap();
+                assert_eq!(x.as_ref(), &[ 1.0, 2.0, -3.0, 4.0 ]);
+            }
+        }
+    };
+}
+
+test!(test_row_major, RowMatrix, RowSpace, RowMajor);
+test!(test_column_major, ColumnMatrix, ColumnSpace, ColumnMajor);
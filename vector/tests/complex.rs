@@ -0,0 +1,111 @@
+use vector::vspace;
+
+use algebra::*;
+
+vspace! {
+    ComplexSpace {
+        vector: ComplexVector,
+        dimension: 2
+    }
+}
+
+type C = Complex<f64>;
+
+#[test]
+fn test_arithmetic()
+{
+    let a = C::new(1.0, 2.0);
+    let b = C::new(3.0, -1.0);
+
+    assert_eq!(a + b, C::new(4.0, 1.0));
+    assert_eq!(a - b, C::new(-2.0, 3.0));
+    assert_eq!(a * b, C::new(5.0, 5.0));
+    assert!(((a * b) / b).ulps_eq(&a, &f64::EPSILON, 4));
+    assert_eq!(-a, C::new(-1.0, -2.0));
+    assert_eq!(C::i() * C::i(), C::from(-1.0));
+}
+
+#[test]
+fn test_field()
+{
+    let a = C::new(3.0, 4.0);
+
+    assert_eq!(a.conj(), C::new(3.0, -4.0));
+    assert_eq!(a.norm_sqr(), 25.0);
+    assert_eq!(a * a.multiplicative_inv(), C::multiplicative_identity());
+    assert_eq!(a + a.additive_inv(), C::additive_identity());
+    assert_eq!(format!("{}", a.conj()), "3-4i");
+}
+
+#[test]
+fn test_scaled_division()
+{
+    // `|z|^2` overflows and underflows for these, which the quotient must not.
+    let big = C::new(1e300, 1e300);
+    let small = C::new(1e-300, -1e-300);
+
+    assert_eq!(big / big, C::multiplicative_identity());
+    assert_eq!(small / small, C::multiplicative_identity());
+    assert!(big.multiplicative_inv().ulps_eq(&C::new(5e-301, -5e-301), &0.0, 4));
+    assert!(small.multiplicative_inv().ulps_eq(&C::new(5e299, 5e299), &0.0, 4));
+}
+
+#[test]
+fn test_gaussian_integers()
+{
+    let a = Complex::new(2, 1);
+    let b = Complex::new(1, -1);
+    assert_eq!(a * b, Complex::new(3, -1));
+}
+
+#[test]
+fn test_vector_space()
+{
+    let space = ComplexSpace::<C>::new();
+    let x = ComplexVector::new(vec![ C::new(1.0, 1.0), C::new(0.0, 2.0) ]);
+    let y = ComplexVector::new(vec![ C::new(2.0, 0.0), C::new(1.0, -1.0) ]);
+
+    let exp = ComplexVector::new(vec![ C::new(3.0, 1.0), C::new(1.0, 1.0) ]);
+    assert!(space.eq(&space.vadd(&x, &y), &exp));
+
+    let exp = ComplexVector::new(vec![ C::new(-1.0, 1.0), C::new(-2.0, 0.0) ]);
+    assert!(space.eq(&space.vscale(&x, &C::i()), &exp));
+
+    let exp = ComplexVector::new(vec![ C::new(-1.0, -1.0), C::new(0.0, -2.0) ]);
+    assert!(space.eq(&space.additive_inv(&x), &exp));
+}
+
+#[test]
+fn test_dotc()
+{
+    let space = ComplexSpace::<C>::new();
+    let x = ComplexVector::new(vec![ C::new(1.0, 1.0), C::new(0.0, 2.0) ]);
+    let y = ComplexVector::new(vec![ C::new(2.0, 0.0), C::new(1.0, -1.0) ]);
+
+    assert_eq!(space.dotv(&x, &y), C::new(4.0, 4.0));
+    assert_eq!(space.dotc(&x, &y), C::new(0.0, -4.0));
+
+    let mut output = C::new(1.0, 0.0);
+    space.dotc_mut(&x, &y, &mut output);
+    assert_eq!(output, C::new(1.0, -4.0));
+}
+
+#[test]
+fn test_hermitian_inner_product()
+{
+    let space = ComplexSpace::<C>::new();
+    let x = ComplexVector::new(vec![ C::new(1.0, 1.0), C::new(0.0, 2.0) ]);
+    let y = ComplexVector::new(vec![ C::new(2.0, 0.0), C::new(1.0, -1.0) ]);
+
+    assert_eq!(space.inner(&x, &y), space.inner(&y, &x).conj());
+    assert_eq!(space.inner(&x, &x), C::new(6.0, 0.0));
+}
+
+#[test]
+fn test_real_dotc()
+{
+    let space = ComplexSpace::<isize>::new();
+    let x = ComplexVector::new(vec![ 3, -1 ]);
+    let y = ComplexVector::new(vec![ 2, 5 ]);
+    assert_eq!(space.dotc(&x, &y), space.dotv(&x, &y));
+}
@@ -0,0 +1,219 @@
+use vector::{mspace, DynSpace, DynVector, DimensionMismatch};
+
+use algebra::*;
+
+mspace! {
+    Space {
+        matrix: Matrix,
+        rows: 2,
+        columns: 3
+    }
+}
+
+#[test]
+fn test_dimension()
+{
+    let space = DynSpace::<isize>::new(4);
+    assert_eq!(space.dimension(), 4);
+    assert_eq!(space.vector(vec![ 1, 2, 3, 4 ]).dimension(), 4);
+}
+
+#[test]
+fn test_try_vector()
+{
+    let space = DynSpace::<isize>::new(3);
+    assert!(space.try_vector(vec![ 1, 2, 3 ]).is_ok());
+    assert_eq!(space.try_vector(vec![ 1, 2 ]).unwrap_err(), DimensionMismatch::new(3, 2));
+    assert_eq!(space.try_from_iter(0..4).unwrap_err(), DimensionMismatch::new(3, 4));
+}
+
+#[test]
+#[should_panic]
+fn test_vector_mismatch()
+{
+    DynSpace::<isize>::new(3).vector(vec![ 1, 2 ]);
+}
+
+#[test]
+fn test_additive_identity()
+{
+    let space = DynSpace::<isize>::new(5);
+    let zero = space.additive_identity();
+    assert!(space.eq(&zero, &space.vector(vec![ 0; 5 ])));
+}
+
+#[test]
+fn test_binops()
+{
+    let space = DynSpace::<isize>::new(3);
+    let x = space.vector(vec![ 3, 0, -1 ]);
+    let y = space.vector(vec![ 10, 1, 2 ]);
+
+    assert!(space.eq(&space.vadd(&x, &y), &space.vector(vec![ 13, 1, 1 ])));
+    assert!(space.eq(&space.vscale(&x, &2), &space.vector(vec![ 6, 0, -2 ])));
+    assert!(space.eq(&space.additive_inv(&x), &space.vector(vec![ -3, 0, 1 ])));
+    assert!(space.eq(&space.vsub(&x, &y), &space.vector(vec![ -7, -1, -3 ])));
+    assert!(space.eq(&space.vhadamard(&x, &y), &space.vector(vec![ 30, 0, -2 ])));
+    assert!(space.eq(&space.vhadamard_div(&y, &space.vector(vec![ 5, 1, -2 ])), &space.vector(vec![ 2, 1, -1 ])));
+
+    let mut z = x.clone();
+    assert!(space.eq(&vadd!(space, z, &y, &y), &space.vector(vec![ 23, 2, 3 ])));
+}
+
+#[test]
+fn test_blas()
+{
+    let space = DynSpace::<isize>::new(3);
+    let x = space.vector(vec![ 3, 0, -1 ]);
+    let y = space.vector(vec![ 10, 1, 2 ]);
+
+    assert_eq!(space.dotv(&x, &y), 28);
+    assert!(space.eq(&space.vaxpy(&2, &x, &y), &space.vector(vec![ 16, 1, 0 ])));
+    assert_eq!(space.asumv(&x), 4);
+    assert_eq!(space.iamaxv(&y), Some(0));
+}
+
+#[test]
+fn test_checked_ops()
+{
+    let space = DynSpace::<isize>::new(3);
+    let other = DynSpace::<isize>::new(2);
+    let x = space.vector(vec![ 3, 0, -1 ]);
+    let y = other.vector(vec![ 10, 1 ]);
+
+    assert_eq!(space.checked_vadd(&x, &x).map(|v| v.into_inner()), Ok(vec![ 6, 0, -2 ]));
+    assert_eq!(space.checked_vadd(&x, &y).unwrap_err(), DimensionMismatch::new(3, 2));
+    assert_eq!(space.checked_vaxpy(&1, &y, &x).unwrap_err(), DimensionMismatch::new(3, 2));
+    assert_eq!(space.checked_dotv(&x, &y), Err(DimensionMismatch::new(3, 2)));
+    assert_eq!(other.checked_dotv(&y, &y), Ok(101));
+}
+
+#[test]
+fn test_eq()
+{
+    let space = DynSpace::<isize>::new(3);
+    let x = space.vector(vec![ 3, 0, -1 ]);
+    let y = DynSpace::<isize>::new(2).vector(vec![ 3, 0 ]);
+
+    assert!(space.eq(&x, &x));
+    assert!(!space.eq(&x, &y));
+}
+
+#[test]
+fn test_nrm2()
+{
+    let space = DynSpace::<f64>::new(2);
+    let x = space.vector(vec![ 3.0, 4.0 ]);
+    assert!((space.nrm2v(&x) - 5.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_gemv()
+{
+    let matrix_space = Space::<isize>::new();
+    let columns = DynSpace::<isize>::new(3);
+    let rows = DynSpace::<isize>::new(2);
+
+    let a = Matrix::new(vec![ 1, 2, 3, 4, 5, 6 ]);
+    let x = columns.vector(vec![ 1, 0, -1 ]);
+    let y = rows.vector(vec![ 1, 1 ]);
+
+    let test = matrix_space.gemv(Transpose::NoTrans, &1, &a, &x, &2, &y);
+    assert!(rows.eq(&test, &rows.vector(vec![ 0, 0 ])));
+}
+
+#[test]
+fn test_normed_space()
+{
+    let space = DynSpace::<f64>::new(4);
+    let x = space.vector(vec![ 1.0, 1.0, 1.0, 1.0 ]);
+
+    assert!((space.norm(&x) - 2.0).abs() < 1e-12);
+    assert!((space.norm_inf(&x) - 1.0).abs() < 1e-12);
+    assert!((space.norm(&space.normalize(&x)) - 1.0).abs() < 1e-12);
+    assert!((space.distance(&x, &space.additive_identity()) - 2.0).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_vscale_mut_mismatched()
+{
+    let space = DynSpace::<isize>::new(3);
+    let mut x = DynSpace::<isize>::new(2).vector(vec![ 1, 2 ]);
+    space.vscale_mut(&mut x, &2);
+}
+
+/// Vectors of the space `DynSpace::new(3)` and of `DynSpace::new(2)`, which share a type.
+fn mismatched() -> (DynSpace<isize>, DynVector<isize>, DynVector<isize>)
+{
+    let space = DynSpace::<isize>::new(3);
+    let x = space.vector(vec![ 1, 2, 3 ]);
+    let y = DynSpace::<isize>::new(2).vector(vec![ 1, 2 ]);
+    (space, x, y)
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_vadd_mut_mismatched()
+{
+    let (space, mut x, y) = mismatched();
+    space.vadd_mut(&mut x, &y);
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_vadd_mut_lhs_mismatched()
+{
+    let (space, x, mut y) = mismatched();
+    space.vadd_mut(&mut y, &x);
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_vaxpy_mut_mismatched()
+{
+    let (space, x, mut y) = mismatched();
+    space.vaxpy_mut(&2, &x, &mut y);
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_dotv_mismatched()
+{
+    let (space, x, y) = mismatched();
+    space.dotv(&x, &y);
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_dotv_mut_mismatched()
+{
+    let (space, x, y) = mismatched();
+    let mut output: isize = 0;
+    space.dotv_mut(&x, &y, &mut output);
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_swapv_mismatched()
+{
+    let (space, mut x, mut y) = mismatched();
+    space.swapv(&mut x, &mut y);
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_expr_mismatched()
+{
+    let (space, _, y) = mismatched();
+    space.expr(&y);
+}
+
+#[test]
+fn test_eq_mismatched()
+{
+    let (space, x, y) = mismatched();
+    assert!( space.eq(&x, &x) );
+    assert!( !space.eq(&x, &y) );
+    assert!( !space.eq(&y, &x) );
+}
@@ -0,0 +1,177 @@
+use vector::{vspace, mspace, NoConvergence};
+
+use algebra::*;
+
+vspace! {
+    Space4 {
+        vector: Vector4,
+        dimension: 4
+    }
+}
+
+fn mul(x: Complex<f64>, y: Complex<f64>) -> Complex<f64>
+{
+    Complex::new(x.re * y.re - x.im * y.im, x.re * y.im + x.im * y.re)
+}
+
+macro_rules! test {
+    ($name:ident, $object:ident, $space:ident, $layout:ident) => {
+        mod $name {
+            use super::*;
+
+            mspace! {
+                $space {
+                    matrix: $object,
+                    rows: 4,
+                    columns: 4,
+                    layout: $layout,
+                    using: Vec<T>,
+                    Implements::DecompositionOps::Hessenberg,
+                    Implements::DecompositionOps::Schur,
+                    Implements::DecompositionOps::Eigen
+                }
+            }
+
+            type Eigen = vector::Eigen<$object<Complex<f64>>, Vector4<Complex<f64>>>;
+
+            /// The companion matrix of `(x^2 + 1) * (x - 2) * (x - 3)`.
+            fn companion() -> $object<f64>
+            {
+                $object::from_rows([ [ 5.0, -7.0, 5.0, -6.0 ], [ 1.0, 0.0, 0.0, 0.0 ], [ 0.0, 1.0, 0.0, 0.0 ], [ 0.0, 0.0, 1.0, 0.0 ] ])
+            }
+
+            fn a() -> $object<f64>
+            {
+                $object::from_rows([ [ 4.0, -2.0, 1.0, 3.0 ], [ 1.0, 3.0, -1.0, 2.0 ], [ 0.0, 2.0, 5.0, -1.0 ], [ 2.0, 1.0, 0.0, 1.0 ] ])
+            }
+
+            /// Checks that `a = u * b * u^T` with `u` orthogonal.
+            fn check_similar(a: &$object<f64>, u: &$object<f64>, b: &$object<f64>)
+            {
+                for i in 0..4 {
+                    for j in 0..4 {
+                        let ubu: f64 = (0..4)
+                            .flat_map(|k| (0..4).map(move |l| (k, l)))
+                            .fold(0.0, |acc, (k, l)| acc + u[(i, k)] * b[(k, l)] * u[(j, l)]);
+                        assert!(ubu.abs_diff_eq(&a[(i, j)], &1e-12), "{:?} * {:?} * U^T != {:?}", u, b, a);
+
+                        let dot: f64 = (0..4).fold(0.0, |acc, k| acc + u[(k, i)] * u[(k, j)]);
+                        assert!(dot.abs_diff_eq(&if i == j { 1.0 } else { 0.0 }, &1e-12), "{:?} is not orthogonal", u);
+                    }
+                }
+            }
+
+            fn check_eigen(a: &$object<f64>, eigen: &Eigen)
+            {
+                let (values, vectors) = (eigen.eigenvalues(), eigen.eigenvectors());
+                for j in 0..4 {
+                    let norm: f64 = (0..4).map(|i| vectors[(i, j)].norm_sqr()).sum();
+                    assert!(norm.abs_diff_eq(&1.0, &1e-12));
+
+                    for i in 0..4 {
+                        let ax: Complex<f64> = (0..4).fold(Complex::new(0.0, 0.0), |acc, k| {
+                            Complex::new(acc.re + a[(i, k)] * vectors[(k, j)].re, acc.im + a[(i, k)] * vectors[(k, j)].im)
+                        });
+                        let lx: Complex<f64> = mul(values[j], vectors[(i, j)]);
+                        assert!(ax.abs_diff_eq(&lx, &1e-12), "column {} of {:?} for {:?}", j, vectors, values);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_hessenberg()
+            {
+                let a = a();
+                let hessenberg = $space::<f64>::new().hessenberg(&a);
+                let h = hessenberg.h();
+                for j in 0..4 {
+                    for i in (j + 2)..4 {
+                        assert_eq!(h[(i, j)], 0.0);
+                    }
+                }
+                check_similar(&a, hessenberg.q(), h);
+            }
+
+            #[test]
+            fn test_schur()
+            {
+                let space = $space::<f64>::new();
+                for a in &[ a(), companion() ] {
+                    let schur = space.schur(a).unwrap();
+                    let t = schur.t();
+                    check_similar(a, schur.z(), t);
+
+                    // Quasi upper triangular, without two consecutive subdiagonal elements.
+                    for j in 0..4 {
+                        for i in (j + 2)..4 {
+                            assert_eq!(t[(i, j)], 0.0);
+                        }
+                    }
+                    for j in 0..2 {
+                        assert!(t[(j + 1, j)] == 0.0 || t[(j + 2, j + 1)] == 0.0, "{:?}", t);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_complex_eigenvalues()
+            {
+                let eigen: Eigen = $space::<f64>::new().eig(&companion()).unwrap();
+                check_eigen(&companion(), &eigen);
+
+                let values = eigen.eigenvalues();
+                let mut found: Vec<(f64, f64)> = (0..4).map(|i| (values[i].re, values[i].im)).collect();
+                found.sort_by(|l, r| l.partial_cmp(r).unwrap());
+                let expected: [(f64, f64); 4] = [ (0.0, -1.0), (0.0, 1.0), (2.0, 0.0), (3.0, 0.0) ];
+                for (l, r) in found.iter().zip(&expected) {
+                    assert!(l.0.abs_diff_eq(&r.0, &1e-12) && l.1.abs_diff_eq(&r.1, &1e-12), "{:?}", found);
+                }
+
+                // Conjugate pairs are adjacent, with the positive imaginary part first.
+                let pair: usize = (0..4).find(|&i| values[i].im != 0.0).unwrap();
+                assert!(values[pair].im > 0.0);
+                assert_eq!(values[pair + 1], Complex::new(values[pair].re, -values[pair].im));
+                for i in 0..4 {
+                    let (l, r) = (eigen.eigenvectors()[(i, pair)], eigen.eigenvectors()[(i, pair + 1)]);
+                    assert_eq!(l, Complex::new(r.re, -r.im));
+                }
+            }
+
+            #[test]
+            fn test_real_eigenvalues()
+            {
+                let space = $space::<f64>::new();
+                let a = a();
+                let eigen: Eigen = space.eig(&a).unwrap();
+                check_eigen(&a, &eigen);
+
+                let values = eigen.eigenvalues();
+                let trace: Complex<f64> = (0..4).fold(Complex::new(0.0, 0.0), |acc, i| Complex::new(acc.re + values[i].re, acc.im + values[i].im));
+                assert!(trace.abs_diff_eq(&Complex::new(13.0, 0.0), &1e-12));
+
+                let symmetric = $object::from_rows([ [ 2.0, -1.0, 0.0, 0.0 ], [ -1.0, 2.0, -1.0, 0.0 ], [ 0.0, -1.0, 2.0, -1.0 ], [ 0.0, 0.0, -1.0, 2.0 ] ]);
+                let eigen: Eigen = space.eig(&symmetric).unwrap();
+                check_eigen(&symmetric, &eigen);
+                for i in 0..4 {
+                    assert_eq!(eigen.eigenvalues()[i].im, 0.0);
+                }
+            }
+
+            #[test]
+            fn test_degenerate()
+            {
+                let space = $space::<f64>::new();
+                let zero: Eigen = space.eig(&$object::from_rows([ [ 0.0; 4 ]; 4 ])).unwrap();
+                check_eigen(&$object::from_rows([ [ 0.0; 4 ]; 4 ]), &zero);
+
+                let mut nan = a();
+                nan[(2, 1)] = f64::NAN;
+                let eigen: Result<Eigen, NoConvergence> = space.eig(&nan);
+                assert!(eigen.is_err());
+            }
+        }
+    };
+}
+
+test!(test_row_major, RowMatrix, RowSpace, RowMajor);
+test!(test_column_major, ColumnMatrix, ColumnSpace, ColumnMajor);
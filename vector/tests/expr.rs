@@ -0,0 +1,108 @@
+use vector::{vspace, ArraySpace, ArrayVector, DynSpace};
+use vector::expr::Expr;
+
+use algebra::*;
+
+vspace! {
+    Space {
+        vector: Vector,
+        dimension: 4,
+        using: Vec<T>,
+        Implements::BinOps::VAdd,
+        Implements::BinOps::VAddMut,
+        Implements::BinOps::VScale,
+        Implements::BinOps::VScaleMut,
+        Implements::ExprOps::Expr
+    }
+}
+
+fn v(values: [f64; 4]) -> Vector<f64>
+{
+    Vector::new(values.to_vec())
+}
+
+#[test]
+fn test_fused_expression()
+{
+    let space = Space::<f64>::new();
+    let (x, y, z) = (v([ 1.0, 2.0, 3.0, 4.0 ]), v([ 0.5, -1.0, 0.0, 2.0 ]), v([ 1.0, 1.0, 1.0, 1.0 ]));
+    let mut dest = v([ 0.0; 4 ]);
+
+    space.eval(space.expr(&x) + 2.0 * &y - &z, &mut dest);
+    assert!(space.eq(&dest, &v([ 1.0, -1.0, 2.0, 7.0 ])));
+
+    space.eval(-(space.expr(&x) - space.expr(&y) * 0.5), &mut dest);
+    assert!(space.eq(&dest, &v([ -0.75, -2.5, -3.0, -3.0 ])));
+}
+
+#[test]
+fn test_expression_is_lazy()
+{
+    let space = Space::<isize>::new();
+    let x = Vector::new(vec![ 1, 2, 3, 4 ]);
+    let y = Vector::new(vec![ 4, 3, 2, 1 ]);
+
+    let expr = 3isize * &x + &y;
+    assert_eq!(expr.dimension(), 4);
+    assert_eq!(expr.at(2), 11);
+
+    let mut dest = Vector::new(vec![ 0; 4 ]);
+    space.eval(expr, &mut dest);
+    assert!(space.eq(&dest, &Vector::new(vec![ 7, 9, 11, 13 ])));
+}
+
+#[test]
+fn test_matches_eager_path()
+{
+    let space = Space::<f64>::new();
+    let (x, y) = (v([ 0.1, 0.2, 0.3, 0.4 ]), v([ 1.5, -2.5, 3.5, -4.5 ]));
+
+    let mut eager = x.clone();
+    let eager = vadd!(space, eager, &space.vscale(&y, &2.0), &x);
+
+    let mut lazy = v([ 0.0; 4 ]);
+    space.eval(space.expr(&x) + 2.0 * &y + &x, &mut lazy);
+    assert!(space.eq(&lazy, &eager));
+}
+
+#[test]
+#[should_panic]
+#[cfg(any(debug_assertions, feature = "strict"))]
+fn test_dimension_mismatch()
+{
+    let space = DynSpace::<f64>::new(3);
+    let x = space.vector(vec![ 1.0, 2.0, 3.0 ]);
+    let mut dest = DynSpace::<f64>::new(2).vector(vec![ 0.0; 2 ]);
+
+    space.eval(space.expr(&x) * 2.0, &mut dest);
+}
+
+#[test]
+fn test_dynamic_and_array_spaces()
+{
+    let space = DynSpace::<isize>::new(3);
+    let (x, y) = (space.vector(vec![ 1, 2, 3 ]), space.vector(vec![ 3, 2, 1 ]));
+    let mut dest = space.additive_identity();
+    space.eval(space.expr(&x) - &y, &mut dest);
+    assert!(space.eq(&dest, &space.vector(vec![ -2, 0, 2 ])));
+
+    let space = ArraySpace::<isize, 3>::new();
+    let (x, y) = (ArrayVector::from([ 1, 2, 3 ]), ArrayVector::from([ 3, 2, 1 ]));
+    let mut dest = space.additive_identity();
+    space.eval(2 * space.expr(&x) + &y, &mut dest);
+    assert_eq!(dest.into_inner(), [ 5, 6, 7 ]);
+}
+
+#[test]
+fn test_long_expression()
+{
+    let space = DynSpace::<f64>::new(1 << 16);
+    let x = space.try_from_iter((0..1 << 16).map(|i| i as f64)).unwrap();
+    let y = space.try_from_iter((0..1 << 16).map(|i| (i % 7) as f64)).unwrap();
+
+    let mut lazy = space.additive_identity();
+    space.eval(space.expr(&x) - 0.5 * space.expr(&y), &mut lazy);
+
+    let eager = space.vaxpy(&-0.5, &y, &x);
+    assert!(space.eq(&lazy, &eager));
+}
@@ -0,0 +1,108 @@
+use vector::{vspace, ArraySpace, ArrayVector, DynSpace};
+
+use algebra::*;
+
+vspace! {
+    Space3 {
+        vector: Vector3,
+        dimension: 3
+    }
+}
+
+vspace! {
+    Space4 {
+        vector: Vector4,
+        dimension: 4,
+        using: [T; 4]
+    }
+}
+
+fn v(values: [f64; 3]) -> Vector3<f64>
+{
+    Vector3::new(values.to_vec())
+}
+
+#[test]
+fn test_vcross()
+{
+    let space = Space3::<f64>::new();
+    let (x, y, z) = (v([ 1.0, 0.0, 0.0 ]), v([ 0.0, 1.0, 0.0 ]), v([ 0.0, 0.0, 1.0 ]));
+
+    assert!(space.eq(&space.vcross(&x, &y), &z));
+    assert!(space.eq(&space.vcross(&y, &x), &space.additive_inv(&z)));
+    assert!(space.eq(&space.vcross(&x, &x), &v([ 0.0; 3 ])));
+
+    let (a, b) = (v([ 2.0, -1.0, 3.0 ]), v([ 0.5, 4.0, -2.0 ]));
+    let mut c = a.clone();
+    space.vcross_mut(&mut c, &b);
+    assert!(space.eq(&c, &v([ -10.0, 5.5, 8.5 ])));
+    assert_eq!(space.dotv(&c, &a), 0.0);
+    assert_eq!(space.dotv(&c, &b), 0.0);
+}
+
+#[test]
+fn test_triple_products()
+{
+    let space = Space3::<f64>::new();
+    let (a, b, c) = (v([ 1.0, 2.0, 3.0 ]), v([ -1.0, 0.5, 2.0 ]), v([ 4.0, 0.0, -1.0 ]));
+
+    let volume = space.scalar_triple(&a, &b, &c);
+    assert_eq!(volume, space.scalar_triple(&b, &c, &a));
+    assert_eq!(volume, -space.scalar_triple(&b, &a, &c));
+    assert_eq!(volume, 7.5);
+
+    // a x (b x c) = b (a . c) - c (a . b)
+    let exp = space.vsub(&space.vscale(&b, &space.dotv(&a, &c)), &space.vscale(&c, &space.dotv(&a, &b)));
+    assert!(space.relative_eq(&space.vector_triple(&a, &b, &c), &exp, &f64::EPSILON, &f64::EPSILON));
+}
+
+#[test]
+fn test_vwedge()
+{
+    let space = Space4::<isize>::new();
+    let x = Vector4::new([ 1, 2, 3, 4 ]);
+    let y = Vector4::new([ 0, 1, -1, 2 ]);
+
+    let bivector = space.vwedge(&x, &y);
+    assert_eq!(bivector, vec![ 1, -1, 2, -5, 0, 10 ]);
+    assert!(space.vwedge(&x, &x).iter().all(|&e| e == 0));
+}
+
+#[test]
+fn test_vwedge_matches_vcross()
+{
+    let space = Space3::<f64>::new();
+    let (a, b) = (v([ 2.0, -1.0, 3.0 ]), v([ 0.5, 4.0, -2.0 ]));
+
+    let cross = space.vcross(&a, &b);
+    let wedge = space.vwedge(&a, &b);
+    assert_eq!(wedge, vec![ cross[2], -cross[1], cross[0] ]);
+}
+
+#[test]
+fn test_array_space()
+{
+    let space = ArraySpace::<isize, 3>::new();
+    let (a, b) = (ArrayVector::from([ 1, 2, 3 ]), ArrayVector::from([ 4, 5, 6 ]));
+    let c = ArrayVector::from([ 0, 1, 1 ]);
+
+    assert_eq!(space.vcross(&a, &b).into_inner(), [ -3, 6, -3 ]);
+    assert_eq!(space.scalar_triple(&a, &b, &c), 3);
+
+    let mut d = a;
+    space.vcross_mut(&mut d, &b);
+    assert_eq!(d.into_inner(), [ -3, 6, -3 ]);
+
+    let space = ArraySpace::<isize, 2>::new();
+    assert_eq!(space.vwedge(&ArrayVector::from([ 1, 2 ]), &ArrayVector::from([ 3, 4 ])), vec![ -2 ]);
+}
+
+#[test]
+fn test_dynamic_space()
+{
+    let space = DynSpace::<isize>::new(5);
+    let x = space.vector(vec![ 1, 0, 0, 0, 2 ]);
+    let y = space.vector(vec![ 0, 1, 0, 0, 0 ]);
+
+    assert_eq!(space.vwedge(&x, &y), vec![ 1, 0, 0, 0, 0, 0, -2, 0, 0, 0 ]);
+}
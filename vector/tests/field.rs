@@ -0,0 +1,46 @@
+use algebra::*;
+
+fn power<T: Ring>(base: T, exponent: u32) -> T
+{
+    let mut acc: T = T::multiplicative_identity();
+    for _ in 0..exponent {
+        acc *= base.clone();
+    }
+    acc
+}
+
+fn solve_linear<T: Field>(a: T, b: T) -> T
+{
+    b.additive_inv() * a.multiplicative_inv()
+}
+
+#[test]
+fn test_identities()
+{
+    assert_eq!(<i32 as MultiplicativeIdentity>::multiplicative_identity(), 1);
+    assert_eq!(<&f64 as MultiplicativeIdentity>::multiplicative_identity(), 1.0);
+    assert_eq!(<u8 as AdditiveIdentity>::additive_identity(), 0);
+}
+
+#[test]
+fn test_inverses()
+{
+    assert_eq!(5i64.additive_inv(), -5);
+    assert_eq!(1u8.additive_inv(), 255);
+    assert_eq!(4.0f32.multiplicative_inv(), 0.25);
+    assert!(0.0f64.multiplicative_inv().is_infinite());
+}
+
+#[test]
+fn test_ring()
+{
+    assert_eq!(power(3isize, 4), 81);
+    assert_eq!(power(2u64, 0), 1);
+    assert_eq!(power(0.5f64, 2), 0.25);
+}
+
+#[test]
+fn test_field()
+{
+    assert_eq!(solve_linear(4.0f64, 2.0), -0.5);
+}
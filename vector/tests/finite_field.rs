@@ -0,0 +1,71 @@
+use vector::vspace;
+
+use algebra::*;
+
+vspace! {
+    FieldSpace {
+        vector: FieldVector,
+        dimension: 4
+    }
+}
+
+type F7 = Fp<7>;
+
+fn f7(values: [u64; 4]) -> FieldVector<F7>
+{
+    values.iter().map(|&v| F7::new(v)).collect()
+}
+
+#[test]
+fn test_arithmetic()
+{
+    assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+    assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+    assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+    assert_eq!(F7::new(1) / F7::new(3), F7::new(5));
+    assert_eq!(-F7::new(3), F7::new(4));
+    assert_eq!(F7::from(-1i64), F7::new(6));
+    assert_eq!(F7::new(3).pow(6), F7::multiplicative_identity());
+}
+
+#[test]
+fn test_inverses()
+{
+    for value in 1..7 {
+        let x = F7::new(value);
+        assert_eq!(x * x.multiplicative_inv(), F7::multiplicative_identity());
+        assert_eq!(x + x.additive_inv(), F7::additive_identity());
+    }
+    assert!(F7::new(0).checked_inv().is_none());
+    assert!(Fp::<8>::new(4).checked_inv().is_none());
+}
+
+#[test]
+fn test_large_modulus()
+{
+    type F = Fp<{ (1 << 61) - 1 }>;
+    let x = F::new((1 << 60) + 12345);
+    assert_eq!(x * x.multiplicative_inv(), F::multiplicative_identity());
+    assert_eq!(F::new(F::MODULUS - 1) + F::new(2), F::new(1));
+}
+
+#[test]
+#[should_panic(expected = "0 has no inverse modulo 7")]
+fn test_inverse_of_zero()
+{
+    F7::new(0).multiplicative_inv();
+}
+
+#[test]
+fn test_vector_space()
+{
+    let space = FieldSpace::<F7>::new();
+    let x = f7([ 1, 2, 3, 4 ]);
+    let y = f7([ 6, 5, 4, 3 ]);
+
+    assert_eq!(space.dotv(&x, &y), F7::new(6 + 10 + 12 + 12));
+    assert!(space.eq(&space.vadd(&x, &y), &f7([ 0, 0, 0, 0 ])));
+    assert!(space.eq(&space.vscale(&x, &F7::new(3)), &f7([ 3, 6, 2, 5 ])));
+    assert!(space.eq(&space.vaxpy(&F7::new(2), &x, &y), &f7([ 1, 2, 3, 4 ])));
+    assert!(space.eq(&space.additive_inv(&x), &y));
+}
@@ -0,0 +1,205 @@
+use vector::{vspace, mspace, Singular};
+
+use algebra::*;
+
+macro_rules! test {
+    ($name:ident, $object:ident, $space:ident, $layout:ident) => {
+        mod $name {
+            use super::*;
+
+            mspace! {
+                $space {
+                    matrix: $object,
+                    rows: 3,
+                    columns: 3,
+                    layout: $layout,
+                    using: Vec<T>,
+                    Implements::DecompositionOps::LU,
+                    Implements::DecompositionOps::Solve,
+                    Implements::DecompositionOps::SolveMut,
+                    Implements::DecompositionOps::Determinant,
+                    Implements::DecompositionOps::Inverse
+                }
+            }
+
+            fn product<T: Field + Copy>(a: &$object<T>, b: &$object<T>) -> [[T; 3]; 3]
+            {
+                let mut c: [[T; 3]; 3] = [[T::additive_identity(); 3]; 3];
+                for (i, row) in c.iter_mut().enumerate() {
+                    for (j, value) in row.iter_mut().enumerate() {
+                        for k in 0..3 {
+                            *value += a[(i, k)] * b[(k, j)];
+                        }
+                    }
+                }
+                c
+            }
+
+            fn a() -> $object<f64>
+            {
+                $object::from_rows([ [ 2.0, 1.0, 1.0 ], [ 4.0, -6.0, 0.0 ], [ -2.0, 7.0, 2.0 ] ])
+            }
+
+            #[test]
+            fn test_factors()
+            {
+                let space = $space::<f64>::new();
+                let a = a();
+                let lu = space.lu(&a).unwrap();
+
+                let (l, u) = (lu.l(), lu.u());
+                assert_eq!(lu.permutation(), vec![ 1, 0, 2 ]);
+                for i in 0..3 {
+                    assert_eq!(l[(i, i)], 1.0);
+                    for j in (i + 1)..3 {
+                        assert_eq!(l[(i, j)], 0.0);
+                        assert_eq!(u[(j, i)], 0.0);
+                    }
+                }
+
+                let lu_product = product(&l, &u);
+                for (i, &p) in lu.permutation().iter().enumerate() {
+                    for j in 0..3 {
+                        assert!(lu_product[i][j].abs_diff_eq(&a[(p, j)], &1e-12));
+                    }
+                }
+            }
+
+            #[test]
+            fn test_solve()
+            {
+                let space = $space::<f64>::new();
+                let vectors = Space::<f64>::new();
+                let b = Vector::new(vec![ 5.0, -2.0, 9.0 ]);
+
+                let x = space.solve(&a(), &b).unwrap();
+                assert!(vectors.relative_eq(&x, &Vector::new(vec![ 1.0, 1.0, 2.0 ]), &1e-12, &1e-12), "{:?}", x);
+
+                let mut y = b.clone();
+                space.solve_mut(&a(), &mut y).unwrap();
+                assert!(vectors.eq(&x, &y));
+            }
+
+            #[test]
+            fn test_determinant_and_inverse()
+            {
+                let space = $space::<f64>::new();
+                assert!(space.determinant(&a()).abs_diff_eq(&-16.0, &1e-12));
+
+                let inverse = space.inverse(&a()).unwrap();
+                let identity = product(&a(), &inverse);
+                for (i, row) in identity.iter().enumerate() {
+                    for (j, value) in row.iter().enumerate() {
+                        let exp: f64 = if i == j { 1.0 } else { 0.0 };
+                        assert!(value.abs_diff_eq(&exp, &1e-12), "{:?}", identity);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_singular()
+            {
+                let space = $space::<f64>::new();
+                let a = $object::from_rows([ [ 1.0, 2.0, 3.0 ], [ 2.0, 4.0, 6.0 ], [ 1.0, 0.0, 1.0 ] ]);
+
+                assert_eq!(space.lu(&a).unwrap_err(), Singular { pivot: 2 });
+                assert_eq!(space.inverse(&a).unwrap_err(), Singular { pivot: 2 });
+                assert_eq!(space.determinant(&a), 0.0);
+
+                let nan = $object::from_rows([ [ f64::NAN, 0.0, 0.0 ], [ 0.0, 1.0, 0.0 ], [ 0.0, 0.0, 1.0 ] ]);
+                assert_eq!(space.lu(&nan).unwrap_err(), Singular { pivot: 0 });
+                assert!(space.determinant(&nan).is_nan());
+            }
+
+            #[test]
+            fn test_condition()
+            {
+                let space = $space::<f64>::new();
+
+                // The last pivot is rounding error rather than an exact zero, which only the 
+                // condition number gives away.
+                let rounded = $object::from_rows([ [ 1.0, 2.0, 3.0 ], [ 4.0, 5.0, 6.0 ], [ 7.0, 8.0, 9.0 ] ]);
+                let lu = space.lu(&rounded).unwrap();
+                let condition = lu.condition(&rounded);
+                assert!(condition > 1e15, "{}", condition);
+                assert!(space.determinant(&rounded).abs() < 1e-12);
+
+                assert!(space.lu(&a()).unwrap().condition(&a()) < 100.0);
+            }
+
+            #[test]
+            fn test_badly_scaled()
+            {
+                let space = $space::<f64>::new();
+                let vectors = Space::<f64>::new();
+                let a = $object::from_rows([ [ 1e20, 0.0, 0.0 ], [ 0.0, 1.0, 0.0 ], [ 0.0, 0.0, 1.0 ] ]);
+
+                assert!(space.lu(&a).is_ok());
+                assert_eq!(space.determinant(&a), 1e20);
+
+                let x = space.solve(&a, &Vector::new(vec![ 1e20, 2.0, 3.0 ])).unwrap();
+                assert!(vectors.eq(&x, &Vector::new(vec![ 1.0, 2.0, 3.0 ])), "{:?}", x);
+            }
+
+            #[test]
+            fn test_rational()
+            {
+                let space = $space::<Rational<i64>>::new();
+                let r = |n: i64| Rational::from_integer(n);
+                let a = $object::from_rows([ [ r(2), r(1), r(1) ], [ r(4), r(-6), r(0) ], [ r(-2), r(7), r(2) ] ]);
+
+                assert_eq!(space.determinant(&a), r(-16));
+
+                let inverse = space.inverse(&a).unwrap();
+                assert_eq!(inverse[(0, 0)], Rational::new(3, 4));
+                assert_eq!(product(&a, &inverse), [ [ r(1), r(0), r(0) ], [ r(0), r(1), r(0) ], [ r(0), r(0), r(1) ] ]);
+            }
+
+            #[test]
+            fn test_prime_field()
+            {
+                let space = $space::<Fp<7>>::new();
+                let f = |n: i64| Fp::<7>::from(n);
+                let a = $object::from_rows([ [ f(0), f(1), f(2) ], [ f(3), f(4), f(5) ], [ f(6), f(0), f(3) ] ]);
+                let b: Vec<Fp<7>> = vec![ f(1), f(2), f(3) ];
+
+                let x = space.solve(&a, &b).unwrap();
+                for i in 0..3 {
+                    let row: Fp<7> = (0..3).fold(f(0), |acc, j| acc + a[(i, j)] * x[j]);
+                    assert_eq!(row, b[i]);
+                }
+            }
+
+            #[test]
+            fn test_complex()
+            {
+                let space = $space::<Complex<f64>>::new();
+                let c = |re: f64, im: f64| Complex::new(re, im);
+                let a = $object::from_rows([ 
+                    [ c(1.0, 1.0), c(0.0, 0.0), c(2.0, 0.0) ], 
+                    [ c(0.0, -1.0), c(3.0, 0.0), c(0.0, 0.0) ], 
+                    [ c(1.0, 0.0), c(0.0, 2.0), c(1.0, -1.0) ] 
+                ]);
+                let x = vec![ c(1.0, 0.0), c(0.0, 1.0), c(-1.0, 1.0) ];
+                let b: Vec<Complex<f64>> = (0..3)
+                    .map(|i| (0..3).fold(c(0.0, 0.0), |acc, j| acc + a[(i, j)] * x[j]))
+                    .collect();
+
+                let test = space.solve(&a, &b).unwrap();
+                for (l, r) in test.iter().zip(&x) {
+                    assert!(l.abs_diff_eq(r, &1e-12), "{:?}", test);
+                }
+            }
+        }
+    };
+}
+
+vspace! {
+    Space {
+        vector: Vector,
+        dimension: 3
+    }
+}
+
+test!(test_row_major, RowMatrix, RowSpace, RowMajor);
+test!(test_column_major, ColumnMatrix, ColumnSpace, ColumnMajor);
@@ -0,0 +1,336 @@
+macro_rules! test {
+    ($name:ident, $object:ty, $space:ty) => {
+        mod $name {
+            use super::*;
+
+            fn matrix() -> $object
+            {
+                let mut test: $object = <$object>::try_from([0; 6]).unwrap();
+                for row in 0..2 {
+                    for column in 0..3 {
+                        test[(row, column)] = (10 * row + column) as isize;
+                    }
+                }
+                test
+            }
+
+            #[test]
+            fn test_shape()
+            {
+                let test: $object = matrix();
+                assert_eq!( test.shape(), (2, 3) );
+                assert_eq!( <$object>::ROWS, 2 );
+                assert_eq!( <$object>::COLUMNS, 3 );
+            }
+
+            #[test]
+            fn test_index()
+            {
+                let test: $object = matrix();
+                assert_eq!( test[(0, 0)], 0 );
+                assert_eq!( test[(0, 2)], 2 );
+                assert_eq!( test[(1, 0)], 10 );
+                assert_eq!( test[(1, 2)], 12 );
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_index_out_of_bounds()
+            {
+                let test: $object = matrix();
+                let _ = test[(0, 3)];
+            }
+
+            #[test]
+            fn test_rows()
+            {
+                let test: $object = matrix();
+                let rows: Vec<Vec<isize>> = test
+                    .rows()
+                    .map(|row| row.cloned().collect())
+                    .collect();
+                assert_eq!( rows, vec![ vec![0, 1, 2], vec![10, 11, 12] ] );
+            }
+
+            #[test]
+            fn test_columns()
+            {
+                let test: $object = matrix();
+                let columns: Vec<Vec<isize>> = test
+                    .columns()
+                    .map(|column| column.cloned().collect())
+                    .collect();
+                assert_eq!( columns, vec![ vec![0, 10], vec![1, 11], vec![2, 12] ] );
+            }
+
+            #[test]
+            fn test_addition_mut()
+            {
+                let matrix_space = <$space>::new();
+                let mut x: $object = matrix();
+                let y: $object = matrix();
+
+                let test: $object = vadd!(matrix_space, x, &y);
+                for (row, column) in (0..2).flat_map(|r| (0..3).map(move |c| (r, c))) {
+                    assert_eq!( test[(row, column)], 2 * (10 * row + column) as isize );
+                }
+            }
+
+            #[test]
+            fn test_multiplication_mut()
+            {
+                let matrix_space = <$space>::new();
+                let mut x: $object = matrix();
+
+                let test: $object = vscale!(matrix_space, x, &-3);
+                assert_eq!( test[(1, 2)], -36 );
+            }
+
+            #[test]
+            fn test_additive_inverse()
+            {
+                let matrix_space = <$space>::new();
+                let x: $object = matrix();
+
+                let test: $object = matrix_space.additive_inv(&x);
+                let exp: $object = matrix_space.vscale(&x, &-1);
+                assert!( matrix_space.eq(&exp, &test) );
+            }
+
+            #[test]
+            fn test_gemv()
+            {
+                let matrix_space = <$space>::new();
+                let a: $object = matrix();
+                let x: Vector3<isize> = Vector3::new([ 1, 2, 3 ]);
+                let y: Vector2<isize> = Vector2::new([ 1, 1 ]);
+
+                let test: Vector2<isize> = matrix_space.gemv(Transpose::NoTrans, &2, &a, &x, &3, &y);
+                let exp: Vector2<isize> = Vector2::new([ 19, 139 ]);
+                assert!( Space2::new().eq(&exp, &test), "Expected: {:?}, Got: {:?}", &exp, &test );
+            }
+
+            #[test]
+            fn test_gemv_transpose()
+            {
+                let matrix_space = <$space>::new();
+                let a: $object = matrix();
+                let x: Vector2<isize> = Vector2::new([ 1, 2 ]);
+                let mut y: Vector3<isize> = Vector3::new([ 1, 0, -1 ]);
+
+                matrix_space.gemv_mut(Transpose::Trans, &1, &a, &x, &-1, &mut y);
+                let exp: Vector3<isize> = Vector3::new([ 19, 23, 27 ]);
+                assert!( Space3::new().eq(&exp, &y), "Expected: {:?}, Got: {:?}", &exp, &y );
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_gemv_dimension_mismatch()
+            {
+                let matrix_space = <$space>::new();
+                let a: $object = matrix();
+                let x: Vector2<isize> = Vector2::new([ 1, 2 ]);
+                let mut y: Vector2<isize> = Vector2::new([ 1, 1 ]);
+
+                matrix_space.gemv_mut(Transpose::NoTrans, &1, &a, &x, &1, &mut y);
+            }
+
+            #[test]
+            fn test_ger()
+            {
+                let matrix_space = <$space>::new();
+                let a: $object = matrix();
+                let x: Vector2<isize> = Vector2::new([ 1, 2 ]);
+                let y: Vector3<isize> = Vector3::new([ 1, 0, -1 ]);
+
+                let test: $object = matrix_space.ger(&1, &x, &y, &a);
+                let rows: Vec<Vec<isize>> = test
+                    .rows()
+                    .map(|row| row.cloned().collect())
+                    .collect();
+                assert_eq!( rows, vec![ vec![1, 1, 1], vec![12, 11, 10] ] );
+            }
+
+            #[test]
+            fn test_gemm()
+            {
+                let matrix_space = MatrixSpaceSquare::new();
+                let a: $object = matrix();
+                let c: MatrixSquare<isize> = MatrixSquare::new(vec![ 1, 1, 1, 1 ]);
+
+                let test: MatrixSquare<isize> = matrix_space.gemm(Transpose::NoTrans, Transpose::Trans, &1, &a, &a, &-1, &c);
+                let exp: MatrixSquare<isize> = MatrixSquare::new(vec![ 4, 34, 34, 364 ]);
+                assert!( matrix_space.eq(&exp, &test), "Expected: {:?}, Got: {:?}", &exp, &test );
+            }
+
+            #[test]
+            fn test_dotv()
+            {
+                let matrix_space = <$space>::new();
+                let x: $object = matrix();
+
+                let test: isize = matrix_space.dotv(&x, &x);
+                assert_eq!( test, 1 + 4 + 100 + 121 + 144 );
+            }
+        }
+    };
+}
+
+use std::convert::TryFrom;
+
+use vector::{mspace, vspace};
+
+use algebra::*;
+use algebra::{vadd, vscale};
+
+vspace! {
+    Space2 {
+        vector: Vector2,
+        dimension: 2,
+        using: [T; 2]
+    }
+}
+
+vspace! {
+    Space3 {
+        vector: Vector3,
+        dimension: 3,
+        using: [T; 3]
+    }
+}
+
+mspace! {
+    MatrixSpaceRowMajor {
+        matrix: MatrixRowMajor,
+        rows: 2,
+        columns: 3
+    }
+}
+
+test!(test_row_major, MatrixRowMajor<isize>, MatrixSpaceRowMajor<isize>);
+
+impl From<[isize; 6]> for MatrixRowMajor<isize>
+{
+    fn from(array: [isize; 6]) -> Self {
+        Self::new( array.to_vec() )
+    }
+}
+
+mspace! {
+    MatrixSpaceColumnMajor {
+        matrix: MatrixColumnMajor,
+        rows: 2,
+        columns: 3,
+        layout: ColumnMajor
+    }
+}
+
+test!(test_column_major, MatrixColumnMajor<isize>, MatrixSpaceColumnMajor<isize>);
+
+impl From<[isize; 6]> for MatrixColumnMajor<isize>
+{
+    fn from(array: [isize; 6]) -> Self {
+        Self::new( array.to_vec() )
+    }
+}
+
+mspace! {
+    MatrixSpaceArray {
+        matrix: MatrixArray,
+        rows: 2,
+        columns: 3,
+        layout: ColumnMajor,
+        using: [T; 6]
+    }
+}
+
+test!(test_array, MatrixArray<isize>, MatrixSpaceArray<isize>);
+
+mspace! {
+    MatrixSpaceSquare {
+        matrix: MatrixSquare,
+        rows: 2,
+        columns: 2
+    }
+}
+
+mspace! {
+    MatrixSpaceTall {
+        matrix: MatrixTall,
+        rows: 300,
+        columns: 70,
+        layout: ColumnMajor
+    }
+}
+
+mspace! {
+    MatrixSpaceWide {
+        matrix: MatrixWide,
+        rows: 300,
+        columns: 9
+    }
+}
+
+mspace! {
+    MatrixSpaceProduct {
+        matrix: MatrixProduct,
+        rows: 70,
+        columns: 9
+    }
+}
+
+#[test]
+fn test_gemm_blocked()
+{
+    let matrix_space = MatrixSpaceProduct::new();
+    let a: MatrixTall<isize> = (0..300 * 70).map(|i| (i % 13) - 6).collect();
+    let b: MatrixWide<isize> = (0..300 * 9).map(|i| (i % 7) - 3).collect();
+    let c: MatrixProduct<isize> = (0..70 * 9).collect();
+
+    let test: MatrixProduct<isize> = matrix_space.gemm(Transpose::Trans, Transpose::NoTrans, &2, &a, &b, &3, &c);
+
+    for row in 0..70 {
+        for column in 0..9 {
+            let exp: isize = (0..300)
+                .map(|p| a[(p, row)] * b[(p, column)])
+                .sum::<isize>() * 2 + c[(row, column)] * 3;
+            assert_eq!( test[(row, column)], exp, "Mismatch at ({}, {})", row, column );
+        }
+    }
+}
+
+#[test]
+fn test_storage_order()
+{
+    let row_major: MatrixRowMajor<isize> = (1..=6).collect();
+    let column_major: MatrixColumnMajor<isize> = vec![ 1, 4, 2, 5, 3, 6 ].into_iter().collect();
+
+    for row in 0..2 {
+        assert!( row_major.row(row).eq(column_major.row(row)) );
+    }
+}
+
+#[test]
+fn test_gemv_zero_beta()
+{
+    let matrix_space = MatrixSpaceRowMajor::new();
+    let a: MatrixRowMajor<f64> = (1..=6).map(|i| i as f64).collect();
+    let x: Vector3<f64> = Vector3::new([ 1.0, 1.0, 1.0 ]);
+    let mut y: Vector2<f64> = Vector2::new([ f64::NAN, f64::INFINITY ]);
+
+    matrix_space.gemv_mut(Transpose::NoTrans, &1.0, &a, &x, &0.0, &mut y);
+    let exp: Vector2<f64> = Vector2::new([ 6.0, 15.0 ]);
+    assert!( Space2::new().eq(&exp, &y), "Expected: {:?}, Got: {:?}", &exp, &y );
+}
+
+#[test]
+fn test_gemm_zero_beta()
+{
+    let matrix_space = MatrixSpaceSquare::new();
+    let a: MatrixRowMajor<f64> = (1..=6).map(|i| i as f64).collect();
+    let mut c: MatrixSquare<f64> = MatrixSquare::new(vec![ f64::NAN, f64::INFINITY, f64::NEG_INFINITY, f64::NAN ]);
+
+    matrix_space.gemm_mut(Transpose::NoTrans, Transpose::Trans, &1.0, &a, &a, &0.0, &mut c);
+    let exp: MatrixSquare<f64> = MatrixSquare::new(vec![ 14.0, 32.0, 32.0, 77.0 ]);
+    assert!( matrix_space.eq(&exp, &c), "Expected: {:?}, Got: {:?}", &exp, &c );
+}
@@ -0,0 +1,144 @@
+use vector::{vspace, mspace, Singular};
+
+use algebra::*;
+
+vspace! {
+    Space3 {
+        vector: Vector3,
+        dimension: 3
+    }
+}
+
+vspace! {
+    Space4 {
+        vector: Vector4,
+        dimension: 4
+    }
+}
+
+macro_rules! test {
+    ($name:ident, $object:ident, $space:ident, $layout:ident) => {
+        mod $name {
+            use super::*;
+
+            mspace! {
+                $space {
+                    matrix: $object,
+                    rows: 4,
+                    columns: 3,
+                    layout: $layout,
+                    using: Vec<T>,
+                    Implements::DecompositionOps::QR,
+                    Implements::DecompositionOps::LeastSquares
+                }
+            }
+
+            /// The Vandermonde matrix of `1, t, t^2` at `t = 0, 1, 2, 3`.
+            fn vandermonde() -> $object<f64>
+            {
+                $object::from_rows([ [ 1.0, 0.0, 0.0 ], [ 1.0, 1.0, 1.0 ], [ 1.0, 2.0, 4.0 ], [ 1.0, 3.0, 9.0 ] ])
+            }
+
+            fn check_factors(a: &$object<f64>, qr: &vector::QR<$object<f64>, f64>)
+            {
+                let r = qr.r();
+                for j in 0..3 {
+                    for i in (j + 1)..4 {
+                        assert_eq!(r[(i, j)], 0.0);
+                    }
+
+                    let mut column: Vec<f64> = r.column(j).cloned().collect();
+                    qr.q_mul_mut(&mut column[..]);
+
+                    let p: usize = qr.permutation().map_or(j, |permutation| permutation[j]);
+                    for (i, value) in column.iter().enumerate() {
+                        assert!(value.abs_diff_eq(&a[(i, p)], &1e-12), "{:?} != column {} of {:?}", column, p, a);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_factors()
+            {
+                let space = $space::<f64>::new();
+                let a = vandermonde();
+
+                let qr = space.qr(&a);
+                assert!(qr.permutation().is_none());
+                check_factors(&a, &qr);
+
+                let qr = space.qr_pivoted(&a);
+                assert_eq!(qr.permutation().map(|permutation| permutation[0]), Some(2));
+                check_factors(&a, &qr);
+
+                let r = qr.r();
+                assert!(r[(0, 0)].abs() >= r[(1, 1)].abs() && r[(1, 1)].abs() >= r[(2, 2)].abs());
+            }
+
+            #[test]
+            fn test_q_is_orthogonal()
+            {
+                let qr = $space::<f64>::new().qr(&vandermonde());
+                let x: Vec<f64> = vec![ 1.0, -2.0, 0.5, 3.0 ];
+
+                let mut y: Vec<f64> = x.clone();
+                qr.qt_mul_mut(&mut y[..]);
+                assert!(vector::kernel::nrm2(&y).abs_diff_eq(&vector::kernel::nrm2(&x), &1e-12));
+
+                qr.q_mul_mut(&mut y[..]);
+                for (l, r) in y.iter().zip(&x) {
+                    assert!(l.abs_diff_eq(r, &1e-12));
+                }
+            }
+
+            #[test]
+            fn test_lstsq_exact()
+            {
+                let space = $space::<f64>::new();
+                let vectors = Space3::<f64>::new();
+
+                // y = 1 - 2t + t^2 / 2
+                let b = Vector4::new(vec![ 1.0, -0.5, -1.0, -0.5 ]);
+                let x: Vector3<f64> = space.lstsq(&vandermonde(), &b).unwrap();
+                assert!(vectors.abs_diff_eq(&x, &Vector3::new(vec![ 1.0, -2.0, 0.5 ]), &1e-12), "{:?}", x);
+            }
+
+            #[test]
+            fn test_lstsq_residual_is_orthogonal()
+            {
+                let space = $space::<f64>::new();
+                let a = $object::from_rows([ [ 1.0, 0.0, 2.0 ], [ 1.0, 1.0, -1.0 ], [ 0.0, 1.0, 1.0 ], [ 1.0, 1.0, 1.0 ] ]);
+                let b = Vector4::new(vec![ 1.0, 2.0, 0.0, 4.0 ]);
+
+                let x: Vec<f64> = space.lstsq(&a, &b).unwrap();
+                let residual: Vec<f64> = (0..4)
+                    .map(|i| (0..3).fold(-b[i], |acc, j| acc + a[(i, j)] * x[j]))
+                    .collect();
+                for j in 0..3 {
+                    let projection: f64 = (0..4).fold(0.0, |acc, i| acc + a[(i, j)] * residual[i]);
+                    assert!(projection.abs_diff_eq(&0.0, &1e-12), "{:?}", residual);
+                }
+            }
+
+            #[test]
+            fn test_rank_deficient()
+            {
+                let space = $space::<f64>::new();
+                let a = $object::from_rows([ [ 1.0, 2.0, 3.0 ], [ 0.0, 1.0, 1.0 ], [ 1.0, 0.0, 1.0 ], [ 2.0, 1.0, 3.0 ] ]);
+
+                assert_eq!(space.qr_pivoted(&a).rank(), 2);
+                assert_eq!(space.qr_pivoted(&vandermonde()).rank(), 3);
+
+                let b = Vector4::new(vec![ 1.0, 1.0, 1.0, 1.0 ]);
+                let x: Result<Vector3<f64>, Singular> = space.lstsq(&a, &b);
+                assert_eq!(x.unwrap_err(), Singular { pivot: 2 });
+
+                let zero: Result<Vector3<f64>, Singular> = space.lstsq(&$object::from_rows([ [ 0.0; 3 ]; 4 ]), &b);
+                assert_eq!(zero.unwrap_err(), Singular { pivot: 0 });
+            }
+        }
+    };
+}
+
+test!(test_row_major, RowMatrix, RowSpace, RowMajor);
+test!(test_column_major, ColumnMatrix, ColumnSpace, ColumnMajor);
@@ -0,0 +1,105 @@
+use vector::vspace;
+
+use algebra::*;
+
+vspace! {
+    RationalSpace {
+        vector: RationalVector,
+        dimension: 3
+    }
+}
+
+type Q = Rational<i64>;
+
+fn q(numer: i64, denom: i64) -> Q
+{
+    Q::new(numer, denom)
+}
+
+#[test]
+fn test_normalisation()
+{
+    assert_eq!(q(2, 4), q(1, 2));
+    assert_eq!(q(3, -6), q(-1, 2));
+    assert_eq!(q(-3, -6).numer(), 1);
+    assert_eq!(q(0, -5), Q::additive_identity());
+    assert_eq!(q(6, 3).denom(), 1);
+    assert!(Q::checked_new(1, 0).is_none());
+    assert_eq!(Rational::<i8>::new(i8::MIN, i8::MIN), Rational::from_integer(1));
+    assert!(Rational::<i8>::checked_new(i8::MIN, -1).is_none());
+}
+
+#[test]
+fn test_arithmetic()
+{
+    assert_eq!(q(1, 2) + q(1, 3), q(5, 6));
+    assert_eq!(q(1, 2) - q(1, 3), q(1, 6));
+    assert_eq!(q(2, 3) * q(9, 4), q(3, 2));
+    assert_eq!(q(2, 3) / q(4, 9), q(3, 2));
+    assert_eq!(-q(2, 3), q(-2, 3));
+    assert_eq!(q(2, 3).multiplicative_inv(), q(3, 2));
+    assert_eq!(Rational::<u8>::new(3, 2) - Rational::new(1, 2), Rational::from_integer(1));
+}
+
+#[test]
+fn test_overflow()
+{
+    let big = Rational::<i8>::from_integer(100);
+    assert!(big.checked_add(&big).is_none());
+    assert!(big.checked_mul(&big).is_none());
+    assert!(Rational::<u8>::new(1, 2).checked_sub(&Rational::new(1, 1)).is_none());
+
+    let cancelled = Rational::<i8>::new(100, 3) * Rational::new(3, 100);
+    assert_eq!(cancelled, Rational::from_integer(1));
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn test_overflow_panics()
+{
+    let big = Rational::<i8>::from_integer(100);
+    let _ = big + big;
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_inverse_of_zero()
+{
+    Q::additive_identity().multiplicative_inv();
+}
+
+#[test]
+fn test_ordering()
+{
+    assert!(q(1, 3) < q(1, 2));
+    assert!(q(-1, 2) < q(-1, 3));
+    assert!(q(7, 3) > q(2, 1));
+    assert_eq!(q(4, 6).cmp(&q(2, 3)), std::cmp::Ordering::Equal);
+
+    let max = Rational::<i8>::new(i8::MAX, 126);
+    let min = Rational::<i8>::new(i8::MAX - 1, 125);
+    assert!(max < min);
+}
+
+#[test]
+fn test_display()
+{
+    assert_eq!(format!("{}", q(-2, 4)), "-1/2");
+    assert_eq!(format!("{}", q(4, 2)), "2");
+}
+
+#[test]
+fn test_vector_space()
+{
+    let space = RationalSpace::<Q>::new();
+    let x = RationalVector::new(vec![ q(1, 2), q(1, 3), q(-1, 6) ]);
+    let y = RationalVector::new(vec![ q(2, 1), q(3, 1), q(6, 1) ]);
+
+    assert_eq!(space.dotv(&x, &y), q(1, 1));
+
+    let exp = RationalVector::new(vec![ q(5, 2), q(10, 3), q(35, 6) ]);
+    assert!(space.eq(&space.vadd(&x, &y), &exp));
+
+    let exp = RationalVector::new(vec![ q(9, 4), q(19, 6), q(71, 12) ]);
+    assert!(space.eq(&space.vaxpy(&q(1, 2), &x, &y), &exp));
+}
@@ -0,0 +1,102 @@
+use vector::vspace;
+
+use algebra::*;
+
+vspace! {
+    Space {
+        vector: Vector,
+        dimension: 3,
+        using: Vec<T>,
+        Implements::BinOps::VAdd,
+        Implements::BinOps::VAddMut,
+        Implements::BinOps::VScale,
+        Implements::BinOps::VScaleMut,
+        Implements::StdOps::Add,
+        Implements::StdOps::Sub,
+        Implements::StdOps::Neg,
+        Implements::StdOps::Mul,
+        Implements::StdOps::AddAssign,
+        Implements::StdOps::SubAssign,
+        Implements::StdOps::MulAssign
+    }
+}
+
+fn v(values: [isize; 3]) -> Vector<isize>
+{
+    Vector::new(values.to_vec())
+}
+
+#[test]
+fn test_add()
+{
+    let space = Space::<isize>::new();
+    let (x, y) = (v([ 3, 0, -1 ]), v([ 10, 1, 2 ]));
+    let exp = v([ 13, 1, 1 ]);
+
+    assert!(space.eq(&(&x + &y), &exp));
+    assert!(space.eq(&(x.clone() + &y), &exp));
+    assert!(space.eq(&(&x + y.clone()), &exp));
+    assert!(space.eq(&(x + y), &exp));
+}
+
+#[test]
+fn test_sub()
+{
+    let space = Space::<isize>::new();
+    let (x, y) = (v([ 3, 0, -1 ]), v([ 10, 1, 2 ]));
+    let exp = v([ -7, -1, -3 ]);
+
+    assert!(space.eq(&(&x - &y), &exp));
+    assert!(space.eq(&(x.clone() - &y), &exp));
+    assert!(space.eq(&(&x - y.clone()), &exp));
+    assert!(space.eq(&(x - y), &exp));
+}
+
+#[test]
+fn test_neg()
+{
+    let space = Space::<isize>::new();
+    let x = v([ 3, 0, -1 ]);
+
+    assert!(space.eq(&-&x, &v([ -3, 0, 1 ])));
+    assert!(space.eq(&-x, &v([ -3, 0, 1 ])));
+}
+
+#[test]
+fn test_mul()
+{
+    let space = Space::<isize>::new();
+    let x = v([ 3, 0, -1 ]);
+
+    assert!(space.eq(&(&x * 2), &v([ 6, 0, -2 ])));
+    assert!(space.eq(&(&x * 2), &space.vscale(&x, &2)));
+    assert!(space.eq(&(x * -1), &v([ -3, 0, 1 ])));
+}
+
+#[test]
+fn test_assign()
+{
+    let space = Space::<isize>::new();
+    let mut x = v([ 3, 0, -1 ]);
+    let y = v([ 10, 1, 2 ]);
+
+    x += &y;
+    assert!(space.eq(&x, &space.vadd(&v([ 3, 0, -1 ]), &y)));
+    x -= y.clone();
+    assert!(space.eq(&x, &v([ 3, 0, -1 ])));
+    x *= 3;
+    assert!(space.eq(&x, &v([ 9, 0, -3 ])));
+    x += y;
+    assert!(space.eq(&x, &v([ 19, 1, -1 ])));
+}
+
+#[test]
+fn test_expression()
+{
+    let space = Space::<f64>::new();
+    let x = Vector::new(vec![ 1.0, 2.0, 3.0 ]);
+    let y = Vector::new(vec![ 0.5, 0.5, 0.5 ]);
+
+    let test = &x * 2.0 - &y + -&x;
+    assert!(space.eq(&test, &Vector::new(vec![ 0.5, 1.5, 2.5 ])));
+}
@@ -0,0 +1,87 @@
+#![cfg(any(debug_assertions, feature = "strict"))]
+
+use vector::kernel;
+use vector::{vspace, Layout};
+
+use algebra::*;
+
+vspace! {
+    Space {
+        vector: Vector,
+        dimension: 3
+    }
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_add_assign()
+{
+    kernel::add_assign(&mut [ 1, 2, 3 ], &[ 1, 2 ]);
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 2, found 3")]
+fn test_axpy()
+{
+    kernel::axpy(&2, &[ 1, 2, 3 ], &mut [ 1, 2 ]);
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_dot()
+{
+    kernel::dot(&[ 1, 2, 3 ], &[ 1, 2 ], || 0);
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_rot()
+{
+    kernel::rot(&mut [ 1.0, 2.0, 3.0 ], &mut [ 1.0, 2.0 ], &0.6, &0.8);
+}
+
+#[test]
+#[should_panic(expected = "expected dimension 3, found 2")]
+fn test_rotm()
+{
+    kernel::rotm(&mut [ 1.0, 2.0, 3.0 ], &mut [ 1.0, 2.0 ], &[ -1.0, 1.0, 0.0, 0.0, 1.0 ]);
+}
+
+#[test]
+#[should_panic]
+fn test_gemv()
+{
+    kernel::gemv(Layout::RowMajor, (2, 2), &1, &[ 1, 2, 3, 4 ], &[ 1, 2, 3 ], &0, &mut [ 0, 0 ]);
+}
+
+#[test]
+#[should_panic]
+fn test_ger()
+{
+    kernel::ger(Layout::RowMajor, (2, 2), &1, &[ 1, 2 ], &[ 1, 2, 3 ], &mut [ 0, 0, 0, 0 ]);
+}
+
+#[test]
+#[should_panic]
+fn test_gemm()
+{
+    let a: kernel::MatrixView<'_, isize> = kernel::MatrixView::new(&[ 1, 2, 3, 4 ], Layout::RowMajor, (2, 2));
+    let b: kernel::MatrixView<'_, isize> = kernel::MatrixView::new(&[ 1, 2, 3, 4, 5, 6 ], Layout::RowMajor, (3, 2));
+    kernel::gemm(&1, a.op(Transpose::NoTrans), b, &0, &mut [ 0, 0, 0, 0 ], Layout::RowMajor, (2, 2));
+}
+
+#[test]
+fn test_matching_dimensions()
+{
+    let vector_space = Space::new();
+    let mut x: Vector<isize> = Vector::new(vec![ 1, 2, 3 ]);
+    let y: Vector<isize> = Vector::new(vec![ 4, 5, 6 ]);
+
+    vector_space.vadd_mut(&mut x, &y);
+    vector_space.vaxpy_mut(&2, &y, &mut x);
+    assert_eq!( vector_space.dotv(&x, &y), 4 * 13 + 5 * 17 + 6 * 21 );
+
+    let mut output: isize = 0;
+    vector_space.dotv_mut(&x, &y, &mut output);
+    assert_eq!( output, 4 * 13 + 5 * 17 + 6 * 21 );
+}
@@ -0,0 +1,154 @@
+use vector::{vspace, mspace, SymmetricEigen};
+
+use algebra::*;
+
+vspace! {
+    Space4 {
+        vector: Vector4,
+        dimension: 4
+    }
+}
+
+macro_rules! test {
+    ($name:ident, $object:ident, $space:ident, $layout:ident) => {
+        mod $name {
+            use super::*;
+
+            mspace! {
+                $space {
+                    matrix: $object,
+                    rows: 4,
+                    columns: 4,
+                    layout: $layout,
+                    using: Vec<T>,
+                    Implements::DecompositionOps::SymmetricEigen
+                }
+            }
+
+            type Eigen = SymmetricEigen<$object<f64>, Vector4<f64>>;
+
+            fn a() -> $object<f64>
+            {
+                $object::from_rows([ [ 4.0, 1.0, -2.0, 2.0 ], [ 1.0, 2.0, 0.0, 1.0 ], [ -2.0, 0.0, 3.0, -2.0 ], [ 2.0, 1.0, -2.0, -1.0 ] ])
+            }
+
+            /// The second difference matrix, with eigenvalues `2 - 2 * cos(k * pi / 5)`.
+            fn laplacian() -> $object<f64>
+            {
+                $object::from_rows([ [ 2.0, -1.0, 0.0, 0.0 ], [ -1.0, 2.0, -1.0, 0.0 ], [ 0.0, -1.0, 2.0, -1.0 ], [ 0.0, 0.0, -1.0, 2.0 ] ])
+            }
+
+            fn decompositions(a: &$object<f64>, order: EigenOrder) -> Vec<Eigen>
+            {
+                let space = $space::<f64>::new();
+                vec![ space.eigh(a, order).unwrap(), space.eigh_jacobi(a, order).unwrap() ]
+            }
+
+            fn check(a: &$object<f64>, eigen: &Eigen)
+            {
+                let (values, vectors) = (eigen.eigenvalues(), eigen.eigenvectors());
+                for j in 0..4 {
+                    for i in 0..4 {
+                        let av: f64 = (0..4).fold(0.0, |acc, k| acc + a[(i, k)] * vectors[(k, j)]);
+                        assert!(av.abs_diff_eq(&(values[j] * vectors[(i, j)]), &1e-12), "column {} of {:?}", j, vectors);
+
+                        let dot: f64 = (0..4).fold(0.0, |acc, k| acc + vectors[(k, i)] * vectors[(k, j)]);
+                        assert!(dot.abs_diff_eq(&if i == j { 1.0 } else { 0.0 }, &1e-12), "{:?} is not orthogonal", vectors);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_decomposition()
+            {
+                let a = a();
+                for eigen in decompositions(&a, EigenOrder::Ascending) {
+                    check(&a, &eigen);
+
+                    let values = eigen.eigenvalues();
+                    assert!((1..4).all(|i| values[i - 1] <= values[i]), "{:?}", values);
+                    let trace: f64 = (0..4).map(|i| values[i]).sum();
+                    assert!(trace.abs_diff_eq(&8.0, &1e-12));
+                }
+            }
+
+            #[test]
+            fn test_known_eigenvalues()
+            {
+                let a = laplacian();
+                let expected: Vec<f64> = (1..=4)
+                    .map(|k| 2.0 - 2.0 * (k as f64 * std::f64::consts::PI / 5.0).cos())
+                    .collect();
+                for eigen in decompositions(&a, EigenOrder::Ascending) {
+                    check(&a, &eigen);
+                    for (l, r) in eigen.eigenvalues().as_ref().iter().zip(&expected) {
+                        assert!(l.abs_diff_eq(r, &1e-12), "{:?} != {:?}", eigen.eigenvalues(), expected);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_order()
+            {
+                let a = $object::from_rows([ [ 3.0, 0.0, 0.0, 0.0 ], [ 0.0, -1.0, 0.0, 0.0 ], [ 0.0, 0.0, 7.0, 0.0 ], [ 0.0, 0.0, 0.0, 0.0 ] ]);
+                for eigen in decompositions(&a, EigenOrder::Descending) {
+                    check(&a, &eigen);
+                    assert_eq!(eigen.eigenvalues().as_ref(), &[ 7.0, 3.0, 0.0, -1.0 ]);
+                    assert_eq!(eigen.eigenvectors()[(2, 0)].abs(), 1.0);
+                }
+
+                let (values, _) = $space::<f64>::new().eigh(&a, EigenOrder::Ascending).map(Eigen::into_parts).unwrap();
+                assert_eq!(values.as_ref(), &[ -1.0, 0.0, 3.0, 7.0 ]);
+            }
+
+            #[test]
+            fn test_methods_agree()
+            {
+                let space = $space::<f64>::new();
+                let ql: SymmetricEigen<$object<f64>, Vec<f64>> = space.eigh(&laplacian(), EigenOrder::Ascending).unwrap();
+                let jacobi: SymmetricEigen<$object<f64>, Vec<f64>> = space.eigh_jacobi(&laplacian(), EigenOrder::Ascending).unwrap();
+                for (l, r) in ql.eigenvalues().iter().zip(jacobi.eigenvalues()) {
+                    assert!(l.abs_diff_eq(r, &1e-12));
+                }
+            }
+
+            #[test]
+            fn test_jacobi_relative_accuracy()
+            {
+                // The small eigenvalue is det / 1e20 = 0.99, far below the 1e4 absolute 
+                // error that tridiagonal QL may make on a matrix with norm 1e20.
+                let a = $object::from_rows([ [ 1e20, 1e9, 0.0, 0.0 ], [ 1e9, 1.0, 0.0, 0.0 ], [ 0.0, 0.0, 2.0, 0.0 ], [ 0.0, 0.0, 0.0, 3.0 ] ]);
+                let eigen: Eigen = $space::<f64>::new().eigh_jacobi(&a, EigenOrder::Ascending).unwrap();
+                let smallest: f64 = eigen.eigenvalues()[0];
+                assert!((smallest / 0.99 - 1.0).abs() < 1e-14, "{}", smallest);
+            }
+
+            #[test]
+            fn test_reads_lower_triangle()
+            {
+                let mut a = a();
+                a[(0, 3)] = 100.0;
+                a[(1, 2)] = f64::NAN;
+                for (eigen, exact) in decompositions(&a, EigenOrder::Ascending).iter().zip(decompositions(&self::a(), EigenOrder::Ascending)) {
+                    assert_eq!(eigen.eigenvalues().as_ref(), exact.eigenvalues().as_ref());
+                }
+            }
+
+            #[test]
+            fn test_no_convergence()
+            {
+                let space = $space::<f64>::new();
+                let mut a = a();
+                a[(2, 1)] = f64::NAN;
+
+                let ql: Result<Eigen, _> = space.eigh(&a, EigenOrder::Ascending);
+                assert!(ql.is_err());
+                let jacobi: Result<Eigen, _> = space.eigh_jacobi(&a, EigenOrder::Ascending);
+                assert_eq!(jacobi.unwrap_err(), vector::NoConvergence { iterations: 50 });
+            }
+        }
+    };
+}
+
+test!(test_row_major, RowMatrix, RowSpace, RowMajor);
+test!(test_column_major, ColumnMatrix, ColumnSpace, ColumnMajor);
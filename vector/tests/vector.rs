@@ -7,10 +7,10 @@ macro_rules! test {
             fn test_addition_mut() 
             {
                 let vector_space = <$space>::new();
-                let mut x = <$object>::from([ 3, 0, -1 ]);
-                let y = <$object>::from([ 10, 1, 2 ]);
+                let mut x = <$object>::try_from([ 3, 0, -1 ]).unwrap();
+                let y = <$object>::try_from([ 10, 1, 2 ]).unwrap();
 
-                let exp: $object = <$object>::from([ 13, 1, 1 ]);
+                let exp: $object = <$object>::try_from([ 13, 1, 1 ]).unwrap();
                 let test: $object = vadd!(vector_space, x, &y);
 
                 assert!( vector_space.eq(&exp, &test) );
@@ -20,10 +20,10 @@ macro_rules! test {
             fn test_multiplication_mut()
             {
                 let vector_space = <$space>::new();
-                let mut x = <$object>::from([ 3, 0, -1 ]);
+                let mut x = <$object>::try_from([ 3, 0, -1 ]).unwrap();
                 let c = 2;
 
-                let exp = <$object>::from([ 6, 0, -2 ]);
+                let exp = <$object>::try_from([ 6, 0, -2 ]).unwrap();
                 let test = vscale!(vector_space, x, &c);
                 assert!( vector_space.eq(&exp, &test), "Expected: {:?}, Got: {:?}", &exp, &test );
             }
@@ -32,9 +32,9 @@ macro_rules! test {
             fn test_commutative_mut()
             {
                 let vector_space = <$space>::new();
-                let mut x1 = <$object>::from([ 3, 1, 5 ]);
+                let mut x1 = <$object>::try_from([ 3, 1, 5 ]).unwrap();
                 let x2: $object = x1.clone();
-                let mut y = <$object>::from([ 6, 2, 7 ]);
+                let mut y = <$object>::try_from([ 6, 2, 7 ]).unwrap();
 
                 let lhs = vadd!(vector_space, x1, &y);
                 let rhs = vadd!(vector_space, y, &x2);
@@ -45,10 +45,10 @@ macro_rules! test {
             fn test_associative_addition_mut()
             {
                 let vector_space = <$space>::new();
-                let mut x1: $object = <$object>::from([ 3, 1, 5 ]);
+                let mut x1: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
                 let x2: $object = x1.clone();
-                let mut y: $object = <$object>::from([ 6, 2, 7 ]);
-                let z = <$object>::from([ 4, 5, 1 ]);
+                let mut y: $object = <$object>::try_from([ 6, 2, 7 ]).unwrap();
+                let z = <$object>::try_from([ 4, 5, 1 ]).unwrap();
 
                 let lhs: $object = vadd!(vector_space, x1, &y, &z);
                 let rhs: $object = vadd!(vector_space, y, &z, &x2);
@@ -59,7 +59,7 @@ macro_rules! test {
             fn test_additive_identity_mut()
             {
                 let vector_space = <$space>::new();
-                let exp: $object = <$object>::from([ 0, 0, 0 ]);
+                let exp: $object = <$object>::try_from([ 0, 0, 0 ]).unwrap();
 
                 let test: $object = vector_space.additive_identity();
                 assert!( vector_space.eq(&exp, &test) );
@@ -69,8 +69,8 @@ macro_rules! test {
             fn test_additive_inverse_mut()
             {
                 let vector_space = <$space>::new();
-                let mut x: $object = <$object>::from([ 3, 1, 5 ]);
-                let exp: $object = <$object>::from([ -3, -1, -5 ]);
+                let mut x: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
+                let exp: $object = <$object>::try_from([ -3, -1, -5 ]).unwrap();
                     
                 vector_space.additive_inv_mut(&mut x);
                 assert!( vector_space.eq(&exp, &x) );
@@ -81,37 +81,171 @@ macro_rules! test {
             {
                 let vector_space = <$space>::new();
                 
-                let mut x: $object = <$object>::from([ 3, 1, 5 ]);
-                let y: $object = <$object>::from([ 6, 2, 7 ]);
-                let z: $object = <$object>::from([ 4, 5, 1 ]);
+                let mut x: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
+                let y: $object = <$object>::try_from([ 6, 2, 7 ]).unwrap();
+                let z: $object = <$object>::try_from([ 4, 5, 1 ]).unwrap();
                 let test: $object = vadd!(vector_space, x, &y, &z);
 
-                let exp: $object = <$object>::from([ 13, 8, 13]);
+                let exp: $object = <$object>::try_from([ 13, 8, 13]).unwrap();
                 assert!( vector_space.eq(&test, &exp) );
             }   
 
             #[test]
+            fn test_vsub()
+            {
+                let vector_space = <$space>::new();
+
+                let x: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
+                let y: $object = <$object>::try_from([ 6, 2, 7 ]).unwrap();
+                let test: $object = vector_space.vsub(&x, &y);
+
+                let exp: $object = <$object>::try_from([ -3, -1, -2 ]).unwrap();
+                assert!( vector_space.eq(&test, &exp) );
+
+                let mut z: $object = test.clone();
+                vector_space.vsub_mut(&mut z, &test);
+                assert!( vector_space.eq(&z, &vector_space.additive_identity()) );
+            }
+
+            #[test]
+            fn test_vhadamard()
+            {
+                let vector_space = <$space>::new();
+
+                let mut x: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
+                let y: $object = <$object>::try_from([ 6, 2, 7 ]).unwrap();
+                let test: $object = vector_space.vhadamard(&x, &y);
+
+                let exp: $object = <$object>::try_from([ 18, 2, 35 ]).unwrap();
+                assert!( vector_space.eq(&test, &exp) );
+
+                vector_space.vhadamard_mut(&mut x, &y);
+                assert!( vector_space.eq(&x, &exp) );
+            }
+
+            #[test]
+            fn test_vhadamard_div()
+            {
+                let vector_space = <$space>::new();
+
+                let mut x: $object = <$object>::try_from([ 18, 3, 35 ]).unwrap();
+                let y: $object = <$object>::try_from([ 6, 2, 7 ]).unwrap();
+                let test: $object = vector_space.vhadamard_div(&x, &y);
+
+                let exp: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
+                assert!( vector_space.eq(&test, &exp) );
+
+                vector_space.vhadamard_div_mut(&mut x, &y);
+                assert!( vector_space.eq(&x, &exp) );
+            }
+
+            #[test]
+            #[allow(clippy::let_with_type_underscore)]
             fn test_vaxpy()
             {
                 let vector_space = <$space>::new();
 
                 let alpha: _ = 2;
-                let x: $object = <$object>::from([ 3, 1, 5 ]);
-                let y: $object = <$object>::from([ 6, 2, 7 ]);
+                let x: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
+                let y: $object = <$object>::try_from([ 6, 2, 7 ]).unwrap();
 
                 let test: $object = vector_space.vaxpy(&alpha, &x, &y);
 
-                let exp: $object = <$object>::from([12, 4, 17]);
+                let exp: $object = <$object>::try_from([12, 4, 17]).unwrap();
                 assert!( vector_space.eq(&test, &exp) );
             }
 
             #[test]
+            fn test_try_from_slice()
+            {
+                let vector_space = <$space>::new();
+
+                let test: $object = <$object>::try_from(&[ 3, 1, 5 ][..]).unwrap();
+                assert!( vector_space.eq(&test, &<$object>::try_from([ 3, 1, 5 ]).unwrap()) );
+
+                let error: DimensionMismatch = <$object>::try_from(&[ 3, 1 ][..]).unwrap_err();
+                assert_eq!( error, DimensionMismatch { expected: 3, found: 2 } );
+            }
+
+            #[test]
+            fn test_checked_ops()
+            {
+                let vector_space = <$space>::new();
+                let x: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
+                let y: $object = <$object>::try_from([ 6, 2, 7 ]).unwrap();
+
+                let test: $object = vector_space.checked_vadd(&x, &y).unwrap();
+                assert!( vector_space.eq(&test, &vector_space.vadd(&x, &y)) );
+
+                let test: $object = vector_space.checked_vaxpy(&2, &x, &y).unwrap();
+                assert!( vector_space.eq(&test, &vector_space.vaxpy(&2, &x, &y)) );
+
+                assert_eq!( vector_space.checked_dotv(&x, &y), Ok(55) );
+
+                let mut output: isize = 1;
+                vector_space.checked_dotv_mut(&x, &y, &mut output).unwrap();
+                assert_eq!( output, 56 );
+            }
+
+            #[test]
+            fn test_asumv()
+            {
+                let vector_space = <$space>::new();
+                let x: $object = <$object>::try_from([ 3, 0, -1 ]).unwrap();
+
+                assert_eq!( vector_space.asumv(&x), 4 );
+            }
+
+            #[test]
+            fn test_iamaxv()
+            {
+                let vector_space = <$space>::new();
+                let x: $object = <$object>::try_from([ 3, 5, -5 ]).unwrap();
+
+                assert_eq!( vector_space.iamaxv(&x), Some(1) );
+            }
+
+            #[test]
+            fn test_swapv()
+            {
+                let vector_space = <$space>::new();
+                let mut x: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
+                let mut y: $object = <$object>::try_from([ 6, 2, 7 ]).unwrap();
+
+                vector_space.swapv(&mut x, &mut y);
+                assert!( vector_space.eq(&x, &<$object>::try_from([ 6, 2, 7 ]).unwrap()) );
+                assert!( vector_space.eq(&y, &<$object>::try_from([ 3, 1, 5 ]).unwrap()) );
+            }
+
+            #[test]
+            fn test_copyv()
+            {
+                let vector_space = <$space>::new();
+                let x: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
+                let mut y: $object = <$object>::try_from([ 6, 2, 7 ]).unwrap();
+
+                vector_space.copyv(&x, &mut y);
+                assert!( vector_space.eq(&x, &y) );
+            }
+
+            #[test]
+            fn test_scalv()
+            {
+                let vector_space = <$space>::new();
+                let mut x: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
+
+                vector_space.scalv(&2, &mut x, 2);
+                assert!( vector_space.eq(&x, &<$object>::try_from([ 6, 1, 10 ]).unwrap()) );
+            }
+
+            #[test]
+            #[allow(clippy::let_with_type_underscore)]
             fn test_dotv()
             {
                 let vector_space = <$space>::new();
 
-                let x: $object = <$object>::from([ 3, 1, 5 ]);
-                let y: $object = <$object>::from([ 6, 2, 7 ]);
+                let x: $object = <$object>::try_from([ 3, 1, 5 ]).unwrap();
+                let y: $object = <$object>::try_from([ 6, 2, 7 ]).unwrap();
 
                 let test: _ = vector_space.dotv(&x, &y);
 
@@ -122,7 +256,113 @@ macro_rules! test {
     };
 }
 
+macro_rules! test_float {
+    ($name:ident, $object:ty, $space:ty, $T:ty) => {
+        mod $name {
+            use super::*;
+
+            const EPSILON: $T = <$T>::EPSILON;
+
+            #[test]
+            fn test_addition_mut() 
+            {
+                let vector_space = <$space>::new();
+                let mut x = <$object>::try_from([ 0.1, 0.2, -1.5 ]).unwrap();
+                let y = <$object>::try_from([ 0.2, 0.1, 1.0 ]).unwrap();
+
+                let exp: $object = <$object>::try_from([ 0.3, 0.3, -0.5 ]).unwrap();
+                let test: $object = vadd!(vector_space, x, &y);
+
+                assert!( vector_space.relative_eq(&exp, &test, &EPSILON, &EPSILON), "Expected: {:?}, Got: {:?}", &exp, &test );
+                assert!( vector_space.ulps_eq(&exp, &test, &0.0, 4) );
+            }
+
+            #[test]
+            fn test_multiplication_mut()
+            {
+                let vector_space = <$space>::new();
+                let mut x = <$object>::try_from([ 0.1, 0.7, -1.0 ]).unwrap();
+                let c = 3.0;
+
+                let exp = <$object>::try_from([ 0.3, 2.1, -3.0 ]).unwrap();
+                let test = vscale!(vector_space, x, &c);
+                assert!( vector_space.relative_eq(&exp, &test, &EPSILON, &(4.0 * EPSILON)), "Expected: {:?}, Got: {:?}", &exp, &test );
+            }
+
+            #[test]
+            fn test_associative_addition_mut()
+            {
+                let vector_space = <$space>::new();
+                let mut x1: $object = <$object>::try_from([ 0.1, 0.7, -3.3 ]).unwrap();
+                let x2: $object = x1.clone();
+                let mut y: $object = <$object>::try_from([ 0.2, 0.5, 1.1 ]).unwrap();
+                let z = <$object>::try_from([ 0.3, 1.0, 2.2 ]).unwrap();
+
+                let lhs: $object = vadd!(vector_space, x1, &y, &z);
+                let rhs: $object = vadd!(vector_space, y, &z, &x2);
+                assert!( vector_space.relative_eq(&lhs, &rhs, &EPSILON, &(4.0 * EPSILON)) );
+            }
+
+            #[test]
+            fn test_additive_inverse_mut()
+            {
+                let vector_space = <$space>::new();
+                let x: $object = <$object>::try_from([ 0.1, -0.2, 0.3 ]).unwrap();
+                let mut y: $object = x.clone();
+
+                vector_space.additive_inv_mut(&mut y);
+                let test: $object = vector_space.vadd(&x, &y);
+                assert!( vector_space.abs_diff_eq(&test, &<$object>::try_from([ 0.0; 3 ]).unwrap(), &0.0) );
+            }
+
+            #[test]
+            fn test_vaxpy()
+            {
+                let vector_space = <$space>::new();
+                let x: $object = <$object>::try_from([ 0.1, 0.2, 0.3 ]).unwrap();
+                let y: $object = <$object>::try_from([ 1.0, 2.0, 3.0 ]).unwrap();
+
+                let test: $object = vector_space.vaxpy(&10.0, &x, &y);
+
+                let exp: $object = <$object>::try_from([ 2.0, 4.0, 6.0 ]).unwrap();
+                assert!( vector_space.relative_eq(&test, &exp, &EPSILON, &EPSILON) );
+                assert!( !vector_space.abs_diff_eq(&test, &vector_space.vscale(&exp, &1.001), &EPSILON) );
+            }
+
+            #[test]
+            fn test_vhadamard_div()
+            {
+                let vector_space = <$space>::new();
+                let x: $object = <$object>::try_from([ 0.3, 1.0, -2.0 ]).unwrap();
+                let y: $object = <$object>::try_from([ 0.1, 4.0, 0.5 ]).unwrap();
+
+                let quotient: $object = vector_space.vhadamard_div(&x, &y);
+                let exp: $object = <$object>::try_from([ 3.0, 0.25, -4.0 ]).unwrap();
+                assert!( vector_space.relative_eq(&quotient, &exp, &EPSILON, &(2.0 * EPSILON)), "Got: {:?}", &quotient );
+
+                let test: $object = vector_space.vhadamard(&quotient, &y);
+                assert!( vector_space.relative_eq(&test, &x, &EPSILON, &(2.0 * EPSILON)), "Got: {:?}", &test );
+            }
+
+            #[test]
+            fn test_dotv()
+            {
+                let vector_space = <$space>::new();
+                let x: $object = <$object>::try_from([ 0.1, 0.2, 0.3 ]).unwrap();
+                let y: $object = <$object>::try_from([ 0.4, 0.5, 0.6 ]).unwrap();
+
+                let test: $T = vector_space.dotv(&x, &y);
+                assert!( test.relative_eq(&0.32, &EPSILON, &(2.0 * EPSILON)), "Got: {:?}", test );
+            }
+        }
+    };
+}
+
+use std::convert::TryFrom;
+
+#[allow(unused_imports)]
 use vector::{vspace, BinOps, Implements};
+use vector::{DimensionMismatch, DynSpace, DynVector};
 
 use algebra::*;
 use algebra::{vadd, vscale};
@@ -171,10 +411,44 @@ test!(test_ndvec, VectorDefault<isize>, VectorSpaceDefault<isize>);
 impl From<[isize; 3]> for VectorDefault<isize>
 {
     fn from(array: [isize; 3]) -> Self {
-        Self::from( array.to_vec() )
+        Self::new( array.to_vec() )
     }
 }
 
+#[test]
+fn test_try_new()
+{
+    assert!( VectorDefault::try_new(vec![ 3, 1, 5 ]).is_ok() );
+    assert_eq!( 
+        VectorDefault::try_new(vec![ 3, 1, 5, 6 ]).unwrap_err(), 
+        DimensionMismatch { expected: 3, found: 4 } 
+    );
+}
+
+#[test]
+fn test_try_from_inner()
+{
+    assert!( VectorDefault::try_from(vec![ 3, 1, 5 ]).is_ok() );
+    assert_eq!( 
+        VectorDefault::try_from(vec![ 3, 1 ]).unwrap_err(), 
+        DimensionMismatch { expected: 3, found: 2 } 
+    );
+}
+
+#[test]
+fn test_try_from_iter()
+{
+    assert!( VectorDefault::try_from_iter(0..3).is_ok() );
+    assert_eq!( 
+        VectorDefault::try_from_iter(0..2).unwrap_err(), 
+        DimensionMismatch { expected: 3, found: 2 } 
+    );
+    assert_eq!( 
+        format!("{}", VectorDefault::try_from_iter(0..2).unwrap_err()), 
+        "expected dimension 3, found 2" 
+    );
+}
+
 impl VAdditiveIdentity for VectorSpaceDefault<isize>
 {
     type Output = VectorDefault<isize>;
@@ -194,3 +468,342 @@ impl VMultiplicativeIdentity for VectorSpaceDefault<isize>
         1
     }
 }
+
+
+vspace! {
+    VectorSpaceFloat {
+        vector: VectorFloat,
+        dimension: 3,
+        using: [T; 3]
+    }
+}
+
+test_float!(test_vspace_f64, VectorFloat<f64>, VectorSpaceFloat<f64>, f64);
+
+vspace! {
+    VectorSpaceFloatDefault {
+        vector: VectorFloatDefault,
+        dimension: 3
+    }
+}
+
+test_float!(test_ndvec_f32, VectorFloatDefault<f32>, VectorSpaceFloatDefault<f32>, f32);
+
+impl From<[f32; 3]> for VectorFloatDefault<f32>
+{
+    fn from(array: [f32; 3]) -> Self {
+        Self::new( array.to_vec() )
+    }
+}
+
+#[test]
+fn test_approx_eq()
+{
+    assert!( 1.0f64.abs_diff_eq(&1.05, &0.1) );
+    assert!( !1.0f64.abs_diff_eq(&1.2, &0.1) );
+    assert!( 1e10f64.relative_eq(&(1e10 + 1.0), &0.0, &1e-9) );
+    assert!( !1e-10f64.relative_eq(&2e-10, &0.0, &1e-9) );
+    assert!( 1e-10f64.relative_eq(&2e-10, &1e-9, &0.0) );
+    assert!( 1.0f32.ulps_eq(&(1.0 + f32::EPSILON), &0.0, 1) );
+    assert!( !1.0f32.ulps_eq(&(1.0 + 2.0 * f32::EPSILON), &0.0, 1) );
+    assert!( (-0.0f64).ulps_eq(&0.0, &0.0, 0) );
+    assert!( !f64::NAN.relative_eq(&f64::NAN, &1.0, &1.0) );
+    assert!( f64::INFINITY.relative_eq(&f64::INFINITY, &0.0, &0.0) );
+    assert!( Complex::new(1.0, 2.0).relative_eq(&Complex::new(1.0, 2.0 + 1e-15), &0.0, &1e-14) );
+}
+
+#[test]
+fn test_vapprox_eq_dimension()
+{
+    let vector_space = DynSpace::<f32>::new(3);
+    let x: DynVector<f32> = vector_space.vector(vec![ 1.0, 2.0, 3.0 ]);
+    let y: DynVector<f32> = DynSpace::<f32>::new(2).vector(vec![ 1.0, 2.0 ]);
+
+    assert!( vector_space.abs_diff_eq(&x, &x, &0.0) );
+    assert!( !vector_space.abs_diff_eq(&x, &y, &f32::INFINITY) );
+    assert!( !vector_space.relative_eq(&x, &y, &f32::INFINITY, &f32::INFINITY) );
+    assert!( !vector_space.ulps_eq(&x, &y, &f32::INFINITY, u32::MAX) );
+}
+
+mod test_blas_level1 {
+    use super::*;
+
+    fn assert_close(test: f64, exp: f64)
+    {
+        assert!( (test - exp).abs() <= 1e-12 * exp.abs().max(1.0), "Expected: {:?}, Got: {:?}", exp, test );
+    }
+
+    #[test]
+    fn test_nrm2v()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let x: VectorFloat<f64> = VectorFloat::new([ 3.0, 0.0, -4.0 ]);
+
+        assert_close( vector_space.nrm2v(&x), 5.0 );
+    }
+
+    #[test]
+    fn test_nrm2v_does_not_overflow()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let x: VectorFloat<f64> = VectorFloat::new([ 3e200, 4e200, 0.0 ]);
+        let y: VectorFloat<f64> = VectorFloat::new([ 3e-200, -4e-200, 0.0 ]);
+
+        assert_close( vector_space.nrm2v(&x), 5e200 );
+        assert_close( vector_space.nrm2v(&y) * 1e200, 5.0 );
+    }
+
+    #[test]
+    fn test_rotg_rotv()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let (mut a, mut b) = (3.0, 4.0);
+
+        let (c, s) = vector_space.rotg(&mut a, &mut b);
+        assert_close( c, 0.6 );
+        assert_close( s, 0.8 );
+        assert_close( a, 5.0 );
+        assert_close( b, 1.0 / 0.6 );
+
+        let mut x: VectorFloat<f64> = VectorFloat::new([ 3.0, 1.0, 0.0 ]);
+        let mut y: VectorFloat<f64> = VectorFloat::new([ 4.0, 0.0, 1.0 ]);
+        vector_space.rotv(&mut x, &mut y, &c, &s);
+
+        for (test, exp) in x.into_iter().zip(&[ 5.0, 0.6, 0.8 ]) {
+            assert_close( *test, *exp );
+        }
+        for (test, exp) in y.into_iter().zip(&[ 0.0, -0.8, 0.6 ]) {
+            assert_close( *test, *exp );
+        }
+    }
+
+    #[test]
+    fn test_rotg_zero()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let (mut a, mut b) = (0.0, 0.0);
+
+        assert_eq!( vector_space.rotg(&mut a, &mut b), (1.0, 0.0) );
+        assert_eq!( (a, b), (0.0, 0.0) );
+    }
+
+    #[test]
+    fn test_rotmg_rotmv()
+    {
+        let vector_space = VectorSpaceFloat::new();
+
+        for &(d1, d2, x1, y1) in &[ (1.0, 1.0, 3.0, 4.0), (2.0, 0.5, 4.0, 1.0), (1e-9, 1e9, 1.0, 2.0) ] {
+            let (mut d1_out, mut d2_out, mut x1_out) = (d1, d2, x1);
+            let param: [f64; 5] = vector_space.rotmg(&mut d1_out, &mut d2_out, &mut x1_out, &y1);
+
+            let mut x: VectorFloat<f64> = VectorFloat::new([ x1, 0.0, 0.0 ]);
+            let mut y: VectorFloat<f64> = VectorFloat::new([ y1, 0.0, 0.0 ]);
+            vector_space.rotmv(&mut x, &mut y, &param);
+
+            assert_close( x[0], x1_out );
+            assert_close( y[0], 0.0 );
+            assert_close( d1_out * x1_out * x1_out, d1 * x1 * x1 + d2 * y1 * y1 );
+        }
+    }
+
+    #[test]
+    fn test_rotmv_identity()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let mut x: VectorFloat<f64> = VectorFloat::new([ 1.0, 2.0, 3.0 ]);
+        let mut y: VectorFloat<f64> = VectorFloat::new([ 4.0, 5.0, 6.0 ]);
+
+        vector_space.rotmv(&mut x, &mut y, &[ -2.0, 9.0, 9.0, 9.0, 9.0 ]);
+        assert!( vector_space.eq(&x, &VectorFloat::new([ 1.0, 2.0, 3.0 ])) );
+        assert!( vector_space.eq(&y, &VectorFloat::new([ 4.0, 5.0, 6.0 ])) );
+    }
+}
+
+mod test_norm {
+    use super::*;
+
+    fn assert_close(test: f64, exp: f64)
+    {
+        assert!( (test - exp).abs() <= 1e-12 * exp.abs().max(1.0), "Expected: {:?}, Got: {:?}", exp, test );
+    }
+
+    #[test]
+    fn test_norms()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let x: VectorFloat<f64> = VectorFloat::new([ 3.0, 0.0, -4.0 ]);
+
+        assert_close( vector_space.norm_l1(&x), 7.0 );
+        assert_close( vector_space.norm_l2(&x), 5.0 );
+        assert_close( vector_space.norm_inf(&x), 4.0 );
+        assert_close( vector_space.norm_p(&x, &1.0), 7.0 );
+        assert_close( vector_space.norm_p(&x, &2.0), 5.0 );
+        assert_close( vector_space.norm_p(&x, &3.0), 91f64.cbrt() );
+        assert_close( vector_space.norm_p(&x, &f64::INFINITY), 4.0 );
+    }
+
+    #[test]
+    fn test_norm_p_does_not_overflow()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let x: VectorFloat<f64> = VectorFloat::new([ 3e200, 4e200, 0.0 ]);
+
+        assert_close( vector_space.norm_p(&x, &2.0) / 1e200, 5.0 );
+    }
+
+    #[test]
+    fn test_norm_inf_nan()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let x: VectorFloat<f64> = VectorFloat::new([ 1.0, f64::NAN, 2.0 ]);
+
+        assert!( vector_space.norm_inf(&x).is_nan() );
+        assert!( vector_space.norm_p(&x, &3.0).is_nan() );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_norm_p_below_one()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        vector_space.norm_p(&VectorFloat::new([ 1.0, 1.0, 1.0 ]), &0.5);
+    }
+
+    #[test]
+    fn test_normalize()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let x: VectorFloat<f64> = VectorFloat::new([ 3.0, 0.0, -4.0 ]);
+
+        let test: VectorFloat<f64> = vector_space.normalize(&x);
+        assert_close( vector_space.norm(&test), 1.0 );
+        assert_close( test[0], 0.6 );
+        assert_close( test[2], -0.8 );
+
+        let mut zero: VectorFloat<f64> = VectorFloat::new([ 0.0; 3 ]);
+        vector_space.normalize_mut(&mut zero);
+        assert!( zero.into_iter().all(|z| *z == 0.0) );
+    }
+
+    #[test]
+    fn test_distance()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let x: VectorFloat<f64> = VectorFloat::new([ 1.0, 2.0, 3.0 ]);
+        let y: VectorFloat<f64> = VectorFloat::new([ 4.0, 6.0, 3.0 ]);
+
+        assert_close( vector_space.distance(&x, &y), 5.0 );
+        assert_close( vector_space.distance(&x, &x), 0.0 );
+    }
+
+    #[test]
+    fn test_inner_product()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let x: VectorFloat<f64> = VectorFloat::new([ 1.0, 0.0, 0.0 ]);
+        let y: VectorFloat<f64> = VectorFloat::new([ 1.0, 1.0, 0.0 ]);
+
+        assert_close( vector_space.inner_product(&x, &y), 1.0 );
+        assert_close( vector_space.angle(&x, &y), std::f64::consts::FRAC_PI_4 );
+        assert_close( vector_space.angle(&x, &x), 0.0 );
+
+        let zero: VectorFloat<f64> = VectorFloat::new([ 0.0, 0.0, 0.0 ]);
+        assert!( vector_space.angle(&x, &zero).is_nan() );
+        assert!( vector_space.angle(&zero, &zero).is_nan() );
+    }
+
+    #[test]
+    fn test_scaled_norm()
+    {
+        let vector_space = VectorSpaceFloat::new();
+        let tiny: VectorFloat<f64> = VectorFloat::new([ 1e-200, 0.0, 0.0 ]);
+        let huge: VectorFloat<f64> = VectorFloat::new([ 0.0, 1e200, 1e200 ]);
+
+        assert_close( vector_space.norm(&tiny) / 1e-200, 1.0 );
+        assert_close( vector_space.normalize(&tiny)[0], 1.0 );
+        assert_close( vector_space.normalize(&huge)[1], std::f64::consts::FRAC_1_SQRT_2 );
+        assert_close( vector_space.distance(&tiny, &VectorFloat::new([ 0.0; 3 ])) / 1e-200, 1.0 );
+        assert_close( vector_space.angle(&tiny, &VectorFloat::new([ 1e-200, 1e-200, 0.0 ])), std::f64::consts::FRAC_PI_4 );
+        assert!( vector_space.angle(&huge, &huge) < 1e-7 );
+    }
+}
+
+vspace! {
+    VectorSpaceLarge {
+        vector: VectorLarge,
+        dimension: 131072,
+        using: Vec<T>
+    }
+}
+
+mod test_large {
+    use super::*;
+
+    const LENGTH: usize = 131072;
+
+    fn ramp(scale: isize) -> VectorLarge<isize> 
+    {
+        (0..LENGTH as isize)
+            .map(|i| scale * (i % 97))
+            .collect()
+    }
+
+    #[test]
+    fn test_addition_mut() 
+    {
+        let vector_space = VectorSpaceLarge::new();
+        let mut x = ramp(1);
+        let y = ramp(2);
+
+        let exp = ramp(3);
+        let test = vadd!(vector_space, x, &y);
+        assert!( vector_space.eq(&exp, &test) );
+    }
+
+    #[test]
+    fn test_multiplication_mut()
+    {
+        let vector_space = VectorSpaceLarge::new();
+        let mut x = ramp(1);
+
+        let exp = ramp(-4);
+        let test = vscale!(vector_space, x, &-4);
+        assert!( vector_space.eq(&exp, &test) );
+    }
+
+    #[test]
+    fn test_additive_inverse_mut()
+    {
+        let vector_space = VectorSpaceLarge::new();
+        let mut x = ramp(5);
+
+        let exp = ramp(-5);
+        vector_space.additive_inv_mut(&mut x);
+        assert!( vector_space.eq(&exp, &x) );
+    }
+
+    #[test]
+    fn test_vaxpy_mut()
+    {
+        let vector_space = VectorSpaceLarge::new();
+        let x = ramp(1);
+        let mut y = ramp(2);
+
+        let exp = ramp(8);
+        vector_space.vaxpy_mut(&6, &x, &mut y);
+        assert!( vector_space.eq(&exp, &y) );
+    }
+
+    #[test]
+    fn test_dotv()
+    {
+        let vector_space = VectorSpaceLarge::new();
+        let x = ramp(1);
+        let y = ramp(2);
+
+        let exp: isize = (0..LENGTH as isize)
+            .map(|i| 2 * (i % 97) * (i % 97))
+            .sum();
+        assert_eq!( vector_space.dotv(&x, &y), exp );
+    }
+}